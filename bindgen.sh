@@ -1 +1 @@
-bindgen wrapper.h --distrust-clang-mangling -- `pkg-config --cflags libgtop-2.0` > src/bindings.rs
+bindgen wrapper.h --distrust-clang-mangling --rustfmt-configuration-file "$PWD/rustfmt.toml" -- `pkg-config --cflags libgtop-2.0` > src/bindings.rs
@@ -6,8 +6,10 @@ use std::path::PathBuf;
 fn main() {
   // jacob@murderbot:~$ pkg-config --libs libgtop-2.0
   // -lgtop-2.0 -lglib-2.0
-  println!("cargo:rustc-link-lib=gtop-2.0");
-  println!("cargo:rustc-link-lib=glib-2.0");
+  if cfg!(not(feature = "procfs")) { // the procfs backend doesn't need them
+    println!("cargo:rustc-link-lib=gtop-2.0");
+    println!("cargo:rustc-link-lib=glib-2.0");
+  }
 
   #[cfg(not(feature = "generate_bindings"))]
   return; // generate the bindings later
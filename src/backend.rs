@@ -0,0 +1,82 @@
+use std::time::Instant;
+use super::cpu_breakdown::CpuTimes;
+use super::snapshot::CoreTimes;
+use super::flags::{Field, CpuFlags, CpuField, MemoryFlags};
+
+/// Where `Cpu` and `Memory` get their raw counters from. `GLibTopHandle` reads them through libgtop (or straight from
+/// /proc when built with the procfs feature) and `ProcFs` always reads /proc. Implement it to feed cpuviz from anywhere
+/// else: a remote agent, a recorded trace, a test double such as `Scripted`...
+///
+/// The counters are read afresh on every call; everything derived from them (usage, deltas, breakdowns) is computed
+/// by `Cpu` and `Memory`, so it comes out the same whichever backend supplied them.
+pub trait Backend: Send + Sync {
+    /// The CPU counters right now
+    fn cpu(&self) -> CpuCounters;
+
+    /// The memory counters right now
+    fn memory(&self) -> MemoryCounters;
+
+    /// The time to stamp readings with. The wall clock, unless the backend replays readings on a clock of its own.
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// Raw CPU counters, in jiffies, as supplied by a `Backend`
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CpuCounters {
+    pub flags:     CpuFlags,       // which fields the backend filled in, fields it didn't are 0
+    pub aggregate: CpuTimes,       // summed over all CPUs
+    pub cores:     Vec<CoreTimes>, // each online logical CPU, sorted by CPU number. Empty if there are no per-CPU counters
+    pub cpu_count: u32,            // logical CPUs the backend knows of, even without per-CPU counters. 0 if it doesn't know
+    pub frequency: u64,
+}
+
+impl CpuCounters {
+    /// Counters for CPUs 0, 1, 2... and their sum, with every field but the frequency marked as filled in
+    pub fn from_cores(cores: &[CpuTimes]) -> CpuCounters {
+        let mut aggregate = CpuTimes::default();
+        for c in cores {
+            aggregate.total   += c.total;
+            aggregate.user    += c.user;
+            aggregate.nice    += c.nice;
+            aggregate.sys     += c.sys;
+            aggregate.idle    += c.idle;
+            aggregate.iowait  += c.iowait;
+            aggregate.irq     += c.irq;
+            aggregate.softirq += c.softirq;
+        }
+        aggregate.steal = if cores.is_empty() { None } else { cores.iter().map(|c| c.steal).sum() };
+        CpuCounters {
+            flags:     CpuFlags::from_bits(!(1 << CpuField::Frequency.bit())),
+            aggregate,
+            cores:     cores.iter().enumerate().map(|(i, &times)| CoreTimes { cpu: i as u32, times }).collect(),
+            cpu_count: cores.len() as u32,
+            frequency: 0,
+        }
+    }
+
+    /// The number of online logical CPUs: one per entry in `cores`, or the backend's `cpu_count` if there are no
+    /// per-CPU counters. Never less than 1.
+    pub fn online_cpus(&self) -> u32 {
+        match self.cores.len() {
+            0 => self.cpu_count.max(1),
+            n => n as u32,
+        }
+    }
+}
+
+/// Raw memory counters, in bytes, as supplied by a `Backend`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MemoryCounters {
+    pub flags:     MemoryFlags, // which fields the backend filled in, fields it didn't are 0
+    pub total:     u64,
+    pub used:      u64,
+    pub free:      u64,
+    pub shared:    u64,
+    pub buffer:    u64,
+    pub cached:    u64,
+    pub user:      u64,
+    pub locked:    u64,
+    pub available: Option<u64>, // the kernel's MemAvailable estimate, if there is one
+}
@@ -775,6 +775,34 @@ pub const GLIBTOP_MEM_CACHED: ::std::os::raw::c_uint = 5;
 pub const GLIBTOP_MEM_USER: ::std::os::raw::c_uint = 6;
 pub const GLIBTOP_MEM_LOCKED: ::std::os::raw::c_uint = 7;
 pub const GLIBTOP_MAX_MEM: ::std::os::raw::c_uint = 8;
+pub const GLIBTOP_SWAP_TOTAL: ::std::os::raw::c_uint = 0;
+pub const GLIBTOP_SWAP_USED: ::std::os::raw::c_uint = 1;
+pub const GLIBTOP_SWAP_FREE: ::std::os::raw::c_uint = 2;
+pub const GLIBTOP_SWAP_PAGEIN: ::std::os::raw::c_uint = 3;
+pub const GLIBTOP_SWAP_PAGEOUT: ::std::os::raw::c_uint = 4;
+pub const GLIBTOP_MAX_SWAP: ::std::os::raw::c_uint = 5;
+pub const GLIBTOP_UPTIME_UPTIME: ::std::os::raw::c_uint = 0;
+pub const GLIBTOP_UPTIME_IDLETIME: ::std::os::raw::c_uint = 1;
+pub const GLIBTOP_UPTIME_BOOT_TIME: ::std::os::raw::c_uint = 2;
+pub const GLIBTOP_MAX_UPTIME: ::std::os::raw::c_uint = 3;
+pub const GLIBTOP_LOADAVG_LOADAVG: ::std::os::raw::c_uint = 0;
+pub const GLIBTOP_LOADAVG_NR_RUNNING: ::std::os::raw::c_uint = 1;
+pub const GLIBTOP_LOADAVG_NR_TASKS: ::std::os::raw::c_uint = 2;
+pub const GLIBTOP_LOADAVG_LAST_PID: ::std::os::raw::c_uint = 3;
+pub const GLIBTOP_MAX_LOADAVG: ::std::os::raw::c_uint = 4;
+pub const GLIBTOP_MOUNTLIST_NUMBER: ::std::os::raw::c_uint = 0;
+pub const GLIBTOP_MOUNTLIST_TOTAL: ::std::os::raw::c_uint = 1;
+pub const GLIBTOP_MOUNTLIST_SIZE: ::std::os::raw::c_uint = 2;
+pub const GLIBTOP_MAX_MOUNTLIST: ::std::os::raw::c_uint = 3;
+pub const GLIBTOP_FSUSAGE_BLOCKS: ::std::os::raw::c_uint = 0;
+pub const GLIBTOP_FSUSAGE_BFREE: ::std::os::raw::c_uint = 1;
+pub const GLIBTOP_FSUSAGE_BAVAIL: ::std::os::raw::c_uint = 2;
+pub const GLIBTOP_FSUSAGE_FILES: ::std::os::raw::c_uint = 3;
+pub const GLIBTOP_FSUSAGE_FFREE: ::std::os::raw::c_uint = 4;
+pub const GLIBTOP_FSUSAGE_BLOCK_SIZE: ::std::os::raw::c_uint = 5;
+pub const GLIBTOP_FSUSAGE_READ: ::std::os::raw::c_uint = 6;
+pub const GLIBTOP_FSUSAGE_WRITE: ::std::os::raw::c_uint = 7;
+pub const GLIBTOP_MAX_FSUSAGE: ::std::os::raw::c_uint = 8;
 pub type __u_char = ::std::os::raw::c_uchar;
 pub type __u_short = ::std::os::raw::c_ushort;
 pub type __u_int = ::std::os::raw::c_uint;
@@ -817,7 +845,7 @@ fn bindgen_test_layout___fsid_t() {
         concat!("Alignment of ", stringify!(__fsid_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<__fsid_t>())).__val as *const _ as usize },
+        ::std::mem::offset_of!(__fsid_t, __val),
         0usize,
         concat!(
             "Offset of field: ",
@@ -905,7 +933,7 @@ fn bindgen_test_layout___sigset_t() {
         concat!("Alignment of ", stringify!(__sigset_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<__sigset_t>())).__val as *const _ as usize },
+        ::std::mem::offset_of!(__sigset_t, __val),
         0usize,
         concat!(
             "Offset of field: ",
@@ -935,7 +963,7 @@ fn bindgen_test_layout_timespec() {
         concat!("Alignment of ", stringify!(timespec))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<timespec>())).tv_sec as *const _ as usize },
+        ::std::mem::offset_of!(timespec, tv_sec),
         0usize,
         concat!(
             "Offset of field: ",
@@ -945,7 +973,7 @@ fn bindgen_test_layout_timespec() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<timespec>())).tv_nsec as *const _ as usize },
+        ::std::mem::offset_of!(timespec, tv_nsec),
         8usize,
         concat!(
             "Offset of field: ",
@@ -974,7 +1002,7 @@ fn bindgen_test_layout_timeval() {
         concat!("Alignment of ", stringify!(timeval))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<timeval>())).tv_sec as *const _ as usize },
+        ::std::mem::offset_of!(timeval, tv_sec),
         0usize,
         concat!(
             "Offset of field: ",
@@ -984,7 +1012,7 @@ fn bindgen_test_layout_timeval() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<timeval>())).tv_usec as *const _ as usize },
+        ::std::mem::offset_of!(timeval, tv_usec),
         8usize,
         concat!(
             "Offset of field: ",
@@ -1014,7 +1042,7 @@ fn bindgen_test_layout_fd_set() {
         concat!("Alignment of ", stringify!(fd_set))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<fd_set>())).__fds_bits as *const _ as usize },
+        ::std::mem::offset_of!(fd_set, __fds_bits),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1078,7 +1106,7 @@ fn bindgen_test_layout_pthread_attr_t() {
         concat!("Alignment of ", stringify!(pthread_attr_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<pthread_attr_t>())).__size as *const _ as usize },
+        ::std::mem::offset_of!(pthread_attr_t, __size),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1088,7 +1116,7 @@ fn bindgen_test_layout_pthread_attr_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<pthread_attr_t>())).__align as *const _ as usize },
+        ::std::mem::offset_of!(pthread_attr_t, __align),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1117,7 +1145,7 @@ fn bindgen_test_layout___pthread_internal_list() {
         concat!("Alignment of ", stringify!(__pthread_internal_list))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<__pthread_internal_list>())).__prev as *const _ as usize },
+        ::std::mem::offset_of!(__pthread_internal_list, __prev),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1127,7 +1155,7 @@ fn bindgen_test_layout___pthread_internal_list() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<__pthread_internal_list>())).__next as *const _ as usize },
+        ::std::mem::offset_of!(__pthread_internal_list, __next),
         8usize,
         concat!(
             "Offset of field: ",
@@ -1174,7 +1202,7 @@ fn bindgen_test_layout_pthread_mutex_t___pthread_mutex_s() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<pthread_mutex_t___pthread_mutex_s>())).__lock as *const _ as usize },
+        ::std::mem::offset_of!(pthread_mutex_t___pthread_mutex_s, __lock),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1184,7 +1212,7 @@ fn bindgen_test_layout_pthread_mutex_t___pthread_mutex_s() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<pthread_mutex_t___pthread_mutex_s>())).__count as *const _ as usize },
+        ::std::mem::offset_of!(pthread_mutex_t___pthread_mutex_s, __count),
         4usize,
         concat!(
             "Offset of field: ",
@@ -1194,7 +1222,7 @@ fn bindgen_test_layout_pthread_mutex_t___pthread_mutex_s() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<pthread_mutex_t___pthread_mutex_s>())).__owner as *const _ as usize },
+        ::std::mem::offset_of!(pthread_mutex_t___pthread_mutex_s, __owner),
         8usize,
         concat!(
             "Offset of field: ",
@@ -1204,7 +1232,7 @@ fn bindgen_test_layout_pthread_mutex_t___pthread_mutex_s() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<pthread_mutex_t___pthread_mutex_s>())).__nusers as *const _ as usize },
+        ::std::mem::offset_of!(pthread_mutex_t___pthread_mutex_s, __nusers),
         12usize,
         concat!(
             "Offset of field: ",
@@ -1214,7 +1242,7 @@ fn bindgen_test_layout_pthread_mutex_t___pthread_mutex_s() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<pthread_mutex_t___pthread_mutex_s>())).__kind as *const _ as usize },
+        ::std::mem::offset_of!(pthread_mutex_t___pthread_mutex_s, __kind),
         16usize,
         concat!(
             "Offset of field: ",
@@ -1224,7 +1252,7 @@ fn bindgen_test_layout_pthread_mutex_t___pthread_mutex_s() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<pthread_mutex_t___pthread_mutex_s>())).__spins as *const _ as usize },
+        ::std::mem::offset_of!(pthread_mutex_t___pthread_mutex_s, __spins),
         20usize,
         concat!(
             "Offset of field: ",
@@ -1234,7 +1262,7 @@ fn bindgen_test_layout_pthread_mutex_t___pthread_mutex_s() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<pthread_mutex_t___pthread_mutex_s>())).__elision as *const _ as usize },
+        ::std::mem::offset_of!(pthread_mutex_t___pthread_mutex_s, __elision),
         22usize,
         concat!(
             "Offset of field: ",
@@ -1244,7 +1272,7 @@ fn bindgen_test_layout_pthread_mutex_t___pthread_mutex_s() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<pthread_mutex_t___pthread_mutex_s>())).__list as *const _ as usize },
+        ::std::mem::offset_of!(pthread_mutex_t___pthread_mutex_s, __list),
         24usize,
         concat!(
             "Offset of field: ",
@@ -1267,7 +1295,7 @@ fn bindgen_test_layout_pthread_mutex_t() {
         concat!("Alignment of ", stringify!(pthread_mutex_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<pthread_mutex_t>())).__data as *const _ as usize },
+        ::std::mem::offset_of!(pthread_mutex_t, __data),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1277,7 +1305,7 @@ fn bindgen_test_layout_pthread_mutex_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<pthread_mutex_t>())).__size as *const _ as usize },
+        ::std::mem::offset_of!(pthread_mutex_t, __size),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1287,7 +1315,7 @@ fn bindgen_test_layout_pthread_mutex_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<pthread_mutex_t>())).__align as *const _ as usize },
+        ::std::mem::offset_of!(pthread_mutex_t, __align),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1317,7 +1345,7 @@ fn bindgen_test_layout_pthread_mutexattr_t() {
         concat!("Alignment of ", stringify!(pthread_mutexattr_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<pthread_mutexattr_t>())).__size as *const _ as usize },
+        ::std::mem::offset_of!(pthread_mutexattr_t, __size),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1327,7 +1355,7 @@ fn bindgen_test_layout_pthread_mutexattr_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<pthread_mutexattr_t>())).__align as *const _ as usize },
+        ::std::mem::offset_of!(pthread_mutexattr_t, __align),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1370,7 +1398,7 @@ fn bindgen_test_layout_pthread_cond_t__bindgen_ty_1() {
         concat!("Alignment of ", stringify!(pthread_cond_t__bindgen_ty_1))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<pthread_cond_t__bindgen_ty_1>())).__lock as *const _ as usize },
+        ::std::mem::offset_of!(pthread_cond_t__bindgen_ty_1, __lock),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1380,7 +1408,7 @@ fn bindgen_test_layout_pthread_cond_t__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<pthread_cond_t__bindgen_ty_1>())).__futex as *const _ as usize },
+        ::std::mem::offset_of!(pthread_cond_t__bindgen_ty_1, __futex),
         4usize,
         concat!(
             "Offset of field: ",
@@ -1390,7 +1418,7 @@ fn bindgen_test_layout_pthread_cond_t__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<pthread_cond_t__bindgen_ty_1>())).__total_seq as *const _ as usize },
+        ::std::mem::offset_of!(pthread_cond_t__bindgen_ty_1, __total_seq),
         8usize,
         concat!(
             "Offset of field: ",
@@ -1400,7 +1428,7 @@ fn bindgen_test_layout_pthread_cond_t__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<pthread_cond_t__bindgen_ty_1>())).__wakeup_seq as *const _ as usize },
+        ::std::mem::offset_of!(pthread_cond_t__bindgen_ty_1, __wakeup_seq),
         16usize,
         concat!(
             "Offset of field: ",
@@ -1410,7 +1438,7 @@ fn bindgen_test_layout_pthread_cond_t__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<pthread_cond_t__bindgen_ty_1>())).__woken_seq as *const _ as usize },
+        ::std::mem::offset_of!(pthread_cond_t__bindgen_ty_1, __woken_seq),
         24usize,
         concat!(
             "Offset of field: ",
@@ -1420,7 +1448,7 @@ fn bindgen_test_layout_pthread_cond_t__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<pthread_cond_t__bindgen_ty_1>())).__mutex as *const _ as usize },
+        ::std::mem::offset_of!(pthread_cond_t__bindgen_ty_1, __mutex),
         32usize,
         concat!(
             "Offset of field: ",
@@ -1430,7 +1458,7 @@ fn bindgen_test_layout_pthread_cond_t__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<pthread_cond_t__bindgen_ty_1>())).__nwaiters as *const _ as usize },
+        ::std::mem::offset_of!(pthread_cond_t__bindgen_ty_1, __nwaiters),
         40usize,
         concat!(
             "Offset of field: ",
@@ -1440,7 +1468,7 @@ fn bindgen_test_layout_pthread_cond_t__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<pthread_cond_t__bindgen_ty_1>())).__broadcast_seq as *const _ as usize },
+        ::std::mem::offset_of!(pthread_cond_t__bindgen_ty_1, __broadcast_seq),
         44usize,
         concat!(
             "Offset of field: ",
@@ -1463,7 +1491,7 @@ fn bindgen_test_layout_pthread_cond_t() {
         concat!("Alignment of ", stringify!(pthread_cond_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<pthread_cond_t>())).__data as *const _ as usize },
+        ::std::mem::offset_of!(pthread_cond_t, __data),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1473,7 +1501,7 @@ fn bindgen_test_layout_pthread_cond_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<pthread_cond_t>())).__size as *const _ as usize },
+        ::std::mem::offset_of!(pthread_cond_t, __size),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1483,7 +1511,7 @@ fn bindgen_test_layout_pthread_cond_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<pthread_cond_t>())).__align as *const _ as usize },
+        ::std::mem::offset_of!(pthread_cond_t, __align),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1513,7 +1541,7 @@ fn bindgen_test_layout_pthread_condattr_t() {
         concat!("Alignment of ", stringify!(pthread_condattr_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<pthread_condattr_t>())).__size as *const _ as usize },
+        ::std::mem::offset_of!(pthread_condattr_t, __size),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1523,7 +1551,7 @@ fn bindgen_test_layout_pthread_condattr_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<pthread_condattr_t>())).__align as *const _ as usize },
+        ::std::mem::offset_of!(pthread_condattr_t, __align),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1572,7 +1600,7 @@ fn bindgen_test_layout_pthread_rwlock_t__bindgen_ty_1() {
         concat!("Alignment of ", stringify!(pthread_rwlock_t__bindgen_ty_1))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<pthread_rwlock_t__bindgen_ty_1>())).__lock as *const _ as usize },
+        ::std::mem::offset_of!(pthread_rwlock_t__bindgen_ty_1, __lock),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1582,7 +1610,7 @@ fn bindgen_test_layout_pthread_rwlock_t__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<pthread_rwlock_t__bindgen_ty_1>())).__nr_readers as *const _ as usize },
+        ::std::mem::offset_of!(pthread_rwlock_t__bindgen_ty_1, __nr_readers),
         4usize,
         concat!(
             "Offset of field: ",
@@ -1592,7 +1620,7 @@ fn bindgen_test_layout_pthread_rwlock_t__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<pthread_rwlock_t__bindgen_ty_1>())).__readers_wakeup as *const _ as usize },
+        ::std::mem::offset_of!(pthread_rwlock_t__bindgen_ty_1, __readers_wakeup),
         8usize,
         concat!(
             "Offset of field: ",
@@ -1602,7 +1630,7 @@ fn bindgen_test_layout_pthread_rwlock_t__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<pthread_rwlock_t__bindgen_ty_1>())).__writer_wakeup as *const _ as usize },
+        ::std::mem::offset_of!(pthread_rwlock_t__bindgen_ty_1, __writer_wakeup),
         12usize,
         concat!(
             "Offset of field: ",
@@ -1612,7 +1640,7 @@ fn bindgen_test_layout_pthread_rwlock_t__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<pthread_rwlock_t__bindgen_ty_1>())).__nr_readers_queued as *const _ as usize },
+        ::std::mem::offset_of!(pthread_rwlock_t__bindgen_ty_1, __nr_readers_queued),
         16usize,
         concat!(
             "Offset of field: ",
@@ -1622,7 +1650,7 @@ fn bindgen_test_layout_pthread_rwlock_t__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<pthread_rwlock_t__bindgen_ty_1>())).__nr_writers_queued as *const _ as usize },
+        ::std::mem::offset_of!(pthread_rwlock_t__bindgen_ty_1, __nr_writers_queued),
         20usize,
         concat!(
             "Offset of field: ",
@@ -1632,7 +1660,7 @@ fn bindgen_test_layout_pthread_rwlock_t__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<pthread_rwlock_t__bindgen_ty_1>())).__writer as *const _ as usize },
+        ::std::mem::offset_of!(pthread_rwlock_t__bindgen_ty_1, __writer),
         24usize,
         concat!(
             "Offset of field: ",
@@ -1642,7 +1670,7 @@ fn bindgen_test_layout_pthread_rwlock_t__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<pthread_rwlock_t__bindgen_ty_1>())).__shared as *const _ as usize },
+        ::std::mem::offset_of!(pthread_rwlock_t__bindgen_ty_1, __shared),
         28usize,
         concat!(
             "Offset of field: ",
@@ -1652,7 +1680,7 @@ fn bindgen_test_layout_pthread_rwlock_t__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<pthread_rwlock_t__bindgen_ty_1>())).__rwelision as *const _ as usize },
+        ::std::mem::offset_of!(pthread_rwlock_t__bindgen_ty_1, __rwelision),
         32usize,
         concat!(
             "Offset of field: ",
@@ -1662,7 +1690,7 @@ fn bindgen_test_layout_pthread_rwlock_t__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<pthread_rwlock_t__bindgen_ty_1>())).__pad1 as *const _ as usize },
+        ::std::mem::offset_of!(pthread_rwlock_t__bindgen_ty_1, __pad1),
         33usize,
         concat!(
             "Offset of field: ",
@@ -1672,7 +1700,7 @@ fn bindgen_test_layout_pthread_rwlock_t__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<pthread_rwlock_t__bindgen_ty_1>())).__pad2 as *const _ as usize },
+        ::std::mem::offset_of!(pthread_rwlock_t__bindgen_ty_1, __pad2),
         40usize,
         concat!(
             "Offset of field: ",
@@ -1682,7 +1710,7 @@ fn bindgen_test_layout_pthread_rwlock_t__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<pthread_rwlock_t__bindgen_ty_1>())).__flags as *const _ as usize },
+        ::std::mem::offset_of!(pthread_rwlock_t__bindgen_ty_1, __flags),
         48usize,
         concat!(
             "Offset of field: ",
@@ -1705,7 +1733,7 @@ fn bindgen_test_layout_pthread_rwlock_t() {
         concat!("Alignment of ", stringify!(pthread_rwlock_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<pthread_rwlock_t>())).__data as *const _ as usize },
+        ::std::mem::offset_of!(pthread_rwlock_t, __data),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1715,7 +1743,7 @@ fn bindgen_test_layout_pthread_rwlock_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<pthread_rwlock_t>())).__size as *const _ as usize },
+        ::std::mem::offset_of!(pthread_rwlock_t, __size),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1725,7 +1753,7 @@ fn bindgen_test_layout_pthread_rwlock_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<pthread_rwlock_t>())).__align as *const _ as usize },
+        ::std::mem::offset_of!(pthread_rwlock_t, __align),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1755,7 +1783,7 @@ fn bindgen_test_layout_pthread_rwlockattr_t() {
         concat!("Alignment of ", stringify!(pthread_rwlockattr_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<pthread_rwlockattr_t>())).__size as *const _ as usize },
+        ::std::mem::offset_of!(pthread_rwlockattr_t, __size),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1765,7 +1793,7 @@ fn bindgen_test_layout_pthread_rwlockattr_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<pthread_rwlockattr_t>())).__align as *const _ as usize },
+        ::std::mem::offset_of!(pthread_rwlockattr_t, __align),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1796,7 +1824,7 @@ fn bindgen_test_layout_pthread_barrier_t() {
         concat!("Alignment of ", stringify!(pthread_barrier_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<pthread_barrier_t>())).__size as *const _ as usize },
+        ::std::mem::offset_of!(pthread_barrier_t, __size),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1806,7 +1834,7 @@ fn bindgen_test_layout_pthread_barrier_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<pthread_barrier_t>())).__align as *const _ as usize },
+        ::std::mem::offset_of!(pthread_barrier_t, __align),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1836,7 +1864,7 @@ fn bindgen_test_layout_pthread_barrierattr_t() {
         concat!("Alignment of ", stringify!(pthread_barrierattr_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<pthread_barrierattr_t>())).__size as *const _ as usize },
+        ::std::mem::offset_of!(pthread_barrierattr_t, __size),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1846,7 +1874,7 @@ fn bindgen_test_layout_pthread_barrierattr_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<pthread_barrierattr_t>())).__align as *const _ as usize },
+        ::std::mem::offset_of!(pthread_barrierattr_t, __align),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1858,6 +1886,7 @@ fn bindgen_test_layout_pthread_barrierattr_t() {
 }
 pub type wchar_t = ::std::os::raw::c_int;
 #[repr(C)]
+#[repr(align(16))]
 #[derive(Debug, Copy, Clone)]
 pub struct max_align_t {
     pub __clang_max_align_nonce1: ::std::os::raw::c_longlong,
@@ -1872,7 +1901,7 @@ fn bindgen_test_layout_max_align_t() {
         concat!("Size of: ", stringify!(max_align_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<max_align_t>())).__clang_max_align_nonce1 as *const _ as usize },
+        ::std::mem::offset_of!(max_align_t, __clang_max_align_nonce1),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1882,7 +1911,7 @@ fn bindgen_test_layout_max_align_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<max_align_t>())).__clang_max_align_nonce2 as *const _ as usize },
+        ::std::mem::offset_of!(max_align_t, __clang_max_align_nonce2),
         16usize,
         concat!(
             "Offset of field: ",
@@ -1934,7 +1963,7 @@ fn bindgen_test_layout_tm() {
         concat!("Alignment of ", stringify!(tm))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<tm>())).tm_sec as *const _ as usize },
+        ::std::mem::offset_of!(tm, tm_sec),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1944,7 +1973,7 @@ fn bindgen_test_layout_tm() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<tm>())).tm_min as *const _ as usize },
+        ::std::mem::offset_of!(tm, tm_min),
         4usize,
         concat!(
             "Offset of field: ",
@@ -1954,7 +1983,7 @@ fn bindgen_test_layout_tm() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<tm>())).tm_hour as *const _ as usize },
+        ::std::mem::offset_of!(tm, tm_hour),
         8usize,
         concat!(
             "Offset of field: ",
@@ -1964,7 +1993,7 @@ fn bindgen_test_layout_tm() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<tm>())).tm_mday as *const _ as usize },
+        ::std::mem::offset_of!(tm, tm_mday),
         12usize,
         concat!(
             "Offset of field: ",
@@ -1974,7 +2003,7 @@ fn bindgen_test_layout_tm() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<tm>())).tm_mon as *const _ as usize },
+        ::std::mem::offset_of!(tm, tm_mon),
         16usize,
         concat!(
             "Offset of field: ",
@@ -1984,7 +2013,7 @@ fn bindgen_test_layout_tm() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<tm>())).tm_year as *const _ as usize },
+        ::std::mem::offset_of!(tm, tm_year),
         20usize,
         concat!(
             "Offset of field: ",
@@ -1994,7 +2023,7 @@ fn bindgen_test_layout_tm() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<tm>())).tm_wday as *const _ as usize },
+        ::std::mem::offset_of!(tm, tm_wday),
         24usize,
         concat!(
             "Offset of field: ",
@@ -2004,7 +2033,7 @@ fn bindgen_test_layout_tm() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<tm>())).tm_yday as *const _ as usize },
+        ::std::mem::offset_of!(tm, tm_yday),
         28usize,
         concat!(
             "Offset of field: ",
@@ -2014,7 +2043,7 @@ fn bindgen_test_layout_tm() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<tm>())).tm_isdst as *const _ as usize },
+        ::std::mem::offset_of!(tm, tm_isdst),
         32usize,
         concat!(
             "Offset of field: ",
@@ -2024,7 +2053,7 @@ fn bindgen_test_layout_tm() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<tm>())).tm_gmtoff as *const _ as usize },
+        ::std::mem::offset_of!(tm, tm_gmtoff),
         40usize,
         concat!(
             "Offset of field: ",
@@ -2034,7 +2063,7 @@ fn bindgen_test_layout_tm() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<tm>())).tm_zone as *const _ as usize },
+        ::std::mem::offset_of!(tm, tm_zone),
         48usize,
         concat!(
             "Offset of field: ",
@@ -2063,7 +2092,7 @@ fn bindgen_test_layout_itimerspec() {
         concat!("Alignment of ", stringify!(itimerspec))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<itimerspec>())).it_interval as *const _ as usize },
+        ::std::mem::offset_of!(itimerspec, it_interval),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2073,7 +2102,7 @@ fn bindgen_test_layout_itimerspec() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<itimerspec>())).it_value as *const _ as usize },
+        ::std::mem::offset_of!(itimerspec, it_value),
         16usize,
         concat!(
             "Offset of field: ",
@@ -2120,7 +2149,7 @@ fn bindgen_test_layout___locale_struct() {
         concat!("Alignment of ", stringify!(__locale_struct))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<__locale_struct>())).__locales as *const _ as usize },
+        ::std::mem::offset_of!(__locale_struct, __locales),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2130,7 +2159,7 @@ fn bindgen_test_layout___locale_struct() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<__locale_struct>())).__ctype_b as *const _ as usize },
+        ::std::mem::offset_of!(__locale_struct, __ctype_b),
         104usize,
         concat!(
             "Offset of field: ",
@@ -2140,7 +2169,7 @@ fn bindgen_test_layout___locale_struct() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<__locale_struct>())).__ctype_tolower as *const _ as usize },
+        ::std::mem::offset_of!(__locale_struct, __ctype_tolower),
         112usize,
         concat!(
             "Offset of field: ",
@@ -2150,7 +2179,7 @@ fn bindgen_test_layout___locale_struct() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<__locale_struct>())).__ctype_toupper as *const _ as usize },
+        ::std::mem::offset_of!(__locale_struct, __ctype_toupper),
         120usize,
         concat!(
             "Offset of field: ",
@@ -2160,7 +2189,7 @@ fn bindgen_test_layout___locale_struct() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<__locale_struct>())).__names as *const _ as usize },
+        ::std::mem::offset_of!(__locale_struct, __names),
         128usize,
         concat!(
             "Offset of field: ",
@@ -2415,7 +2444,7 @@ fn bindgen_test_layout__GFloatIEEE754() {
         concat!("Alignment of ", stringify!(_GFloatIEEE754))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GFloatIEEE754>())).v_float as *const _ as usize },
+        ::std::mem::offset_of!(_GFloatIEEE754, v_float),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2425,7 +2454,7 @@ fn bindgen_test_layout__GFloatIEEE754() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GFloatIEEE754>())).mpn as *const _ as usize },
+        ::std::mem::offset_of!(_GFloatIEEE754, mpn),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2546,7 +2575,7 @@ fn bindgen_test_layout__GDoubleIEEE754() {
         concat!("Alignment of ", stringify!(_GDoubleIEEE754))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GDoubleIEEE754>())).v_double as *const _ as usize },
+        ::std::mem::offset_of!(_GDoubleIEEE754, v_double),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2556,7 +2585,7 @@ fn bindgen_test_layout__GDoubleIEEE754() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GDoubleIEEE754>())).mpn as *const _ as usize },
+        ::std::mem::offset_of!(_GDoubleIEEE754, mpn),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2586,7 +2615,7 @@ fn bindgen_test_layout__GTimeVal() {
         concat!("Alignment of ", stringify!(_GTimeVal))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GTimeVal>())).tv_sec as *const _ as usize },
+        ::std::mem::offset_of!(_GTimeVal, tv_sec),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2596,7 +2625,7 @@ fn bindgen_test_layout__GTimeVal() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GTimeVal>())).tv_usec as *const _ as usize },
+        ::std::mem::offset_of!(_GTimeVal, tv_usec),
         8usize,
         concat!(
             "Offset of field: ",
@@ -2634,7 +2663,7 @@ fn bindgen_test_layout__GArray() {
         concat!("Alignment of ", stringify!(_GArray))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GArray>())).data as *const _ as usize },
+        ::std::mem::offset_of!(_GArray, data),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2644,7 +2673,7 @@ fn bindgen_test_layout__GArray() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GArray>())).len as *const _ as usize },
+        ::std::mem::offset_of!(_GArray, len),
         8usize,
         concat!(
             "Offset of field: ",
@@ -2673,7 +2702,7 @@ fn bindgen_test_layout__GByteArray() {
         concat!("Alignment of ", stringify!(_GByteArray))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GByteArray>())).data as *const _ as usize },
+        ::std::mem::offset_of!(_GByteArray, data),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2683,7 +2712,7 @@ fn bindgen_test_layout__GByteArray() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GByteArray>())).len as *const _ as usize },
+        ::std::mem::offset_of!(_GByteArray, len),
         8usize,
         concat!(
             "Offset of field: ",
@@ -2712,7 +2741,7 @@ fn bindgen_test_layout__GPtrArray() {
         concat!("Alignment of ", stringify!(_GPtrArray))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GPtrArray>())).pdata as *const _ as usize },
+        ::std::mem::offset_of!(_GPtrArray, pdata),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2722,7 +2751,7 @@ fn bindgen_test_layout__GPtrArray() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GPtrArray>())).len as *const _ as usize },
+        ::std::mem::offset_of!(_GPtrArray, len),
         8usize,
         concat!(
             "Offset of field: ",
@@ -2982,7 +3011,7 @@ fn bindgen_test_layout__GError() {
         concat!("Alignment of ", stringify!(_GError))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GError>())).domain as *const _ as usize },
+        ::std::mem::offset_of!(_GError, domain),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2992,7 +3021,7 @@ fn bindgen_test_layout__GError() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GError>())).code as *const _ as usize },
+        ::std::mem::offset_of!(_GError, code),
         4usize,
         concat!(
             "Offset of field: ",
@@ -3002,7 +3031,7 @@ fn bindgen_test_layout__GError() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GError>())).message as *const _ as usize },
+        ::std::mem::offset_of!(_GError, message),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3135,7 +3164,7 @@ fn bindgen_test_layout__GDebugKey() {
         concat!("Alignment of ", stringify!(_GDebugKey))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GDebugKey>())).key as *const _ as usize },
+        ::std::mem::offset_of!(_GDebugKey, key),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3145,7 +3174,7 @@ fn bindgen_test_layout__GDebugKey() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GDebugKey>())).value as *const _ as usize },
+        ::std::mem::offset_of!(_GDebugKey, value),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3234,7 +3263,7 @@ fn bindgen_test_layout__GMutex() {
         concat!("Alignment of ", stringify!(_GMutex))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GMutex>())).p as *const _ as usize },
+        ::std::mem::offset_of!(_GMutex, p),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3244,7 +3273,7 @@ fn bindgen_test_layout__GMutex() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GMutex>())).i as *const _ as usize },
+        ::std::mem::offset_of!(_GMutex, i),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3273,7 +3302,7 @@ fn bindgen_test_layout__GRWLock() {
         concat!("Alignment of ", stringify!(_GRWLock))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GRWLock>())).p as *const _ as usize },
+        ::std::mem::offset_of!(_GRWLock, p),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3283,7 +3312,7 @@ fn bindgen_test_layout__GRWLock() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GRWLock>())).i as *const _ as usize },
+        ::std::mem::offset_of!(_GRWLock, i),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3312,12 +3341,12 @@ fn bindgen_test_layout__GCond() {
         concat!("Alignment of ", stringify!(_GCond))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GCond>())).p as *const _ as usize },
+        ::std::mem::offset_of!(_GCond, p),
         0usize,
         concat!("Offset of field: ", stringify!(_GCond), "::", stringify!(p))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GCond>())).i as *const _ as usize },
+        ::std::mem::offset_of!(_GCond, i),
         8usize,
         concat!("Offset of field: ", stringify!(_GCond), "::", stringify!(i))
     );
@@ -3341,7 +3370,7 @@ fn bindgen_test_layout__GRecMutex() {
         concat!("Alignment of ", stringify!(_GRecMutex))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GRecMutex>())).p as *const _ as usize },
+        ::std::mem::offset_of!(_GRecMutex, p),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3351,7 +3380,7 @@ fn bindgen_test_layout__GRecMutex() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GRecMutex>())).i as *const _ as usize },
+        ::std::mem::offset_of!(_GRecMutex, i),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3381,7 +3410,7 @@ fn bindgen_test_layout__GPrivate() {
         concat!("Alignment of ", stringify!(_GPrivate))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GPrivate>())).p as *const _ as usize },
+        ::std::mem::offset_of!(_GPrivate, p),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3391,7 +3420,7 @@ fn bindgen_test_layout__GPrivate() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GPrivate>())).notify as *const _ as usize },
+        ::std::mem::offset_of!(_GPrivate, notify),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3401,7 +3430,7 @@ fn bindgen_test_layout__GPrivate() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GPrivate>())).future as *const _ as usize },
+        ::std::mem::offset_of!(_GPrivate, future),
         16usize,
         concat!(
             "Offset of field: ",
@@ -3434,7 +3463,7 @@ fn bindgen_test_layout__GOnce() {
         concat!("Alignment of ", stringify!(_GOnce))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GOnce>())).status as *const _ as usize },
+        ::std::mem::offset_of!(_GOnce, status),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3444,7 +3473,7 @@ fn bindgen_test_layout__GOnce() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GOnce>())).retval as *const _ as usize },
+        ::std::mem::offset_of!(_GOnce, retval),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3696,7 +3725,7 @@ fn bindgen_test_layout_sigval() {
         concat!("Alignment of ", stringify!(sigval))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<sigval>())).sival_int as *const _ as usize },
+        ::std::mem::offset_of!(sigval, sival_int),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3706,7 +3735,7 @@ fn bindgen_test_layout_sigval() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<sigval>())).sival_ptr as *const _ as usize },
+        ::std::mem::offset_of!(sigval, sival_ptr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3764,7 +3793,7 @@ fn bindgen_test_layout_siginfo_t__bindgen_ty_1__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<siginfo_t__bindgen_ty_1__bindgen_ty_1>())).si_pid as *const _ as usize },
+        ::std::mem::offset_of!(siginfo_t__bindgen_ty_1__bindgen_ty_1, si_pid),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3774,7 +3803,7 @@ fn bindgen_test_layout_siginfo_t__bindgen_ty_1__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<siginfo_t__bindgen_ty_1__bindgen_ty_1>())).si_uid as *const _ as usize },
+        ::std::mem::offset_of!(siginfo_t__bindgen_ty_1__bindgen_ty_1, si_uid),
         4usize,
         concat!(
             "Offset of field: ",
@@ -3810,7 +3839,7 @@ fn bindgen_test_layout_siginfo_t__bindgen_ty_1__bindgen_ty_2() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<siginfo_t__bindgen_ty_1__bindgen_ty_2>())).si_tid as *const _ as usize },
+        ::std::mem::offset_of!(siginfo_t__bindgen_ty_1__bindgen_ty_2, si_tid),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3820,7 +3849,7 @@ fn bindgen_test_layout_siginfo_t__bindgen_ty_1__bindgen_ty_2() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<siginfo_t__bindgen_ty_1__bindgen_ty_2>())).si_overrun as *const _ as usize },
+        ::std::mem::offset_of!(siginfo_t__bindgen_ty_1__bindgen_ty_2, si_overrun),
         4usize,
         concat!(
             "Offset of field: ",
@@ -3830,7 +3859,7 @@ fn bindgen_test_layout_siginfo_t__bindgen_ty_1__bindgen_ty_2() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<siginfo_t__bindgen_ty_1__bindgen_ty_2>())).si_sigval as *const _ as usize },
+        ::std::mem::offset_of!(siginfo_t__bindgen_ty_1__bindgen_ty_2, si_sigval),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3866,7 +3895,7 @@ fn bindgen_test_layout_siginfo_t__bindgen_ty_1__bindgen_ty_3() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<siginfo_t__bindgen_ty_1__bindgen_ty_3>())).si_pid as *const _ as usize },
+        ::std::mem::offset_of!(siginfo_t__bindgen_ty_1__bindgen_ty_3, si_pid),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3876,7 +3905,7 @@ fn bindgen_test_layout_siginfo_t__bindgen_ty_1__bindgen_ty_3() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<siginfo_t__bindgen_ty_1__bindgen_ty_3>())).si_uid as *const _ as usize },
+        ::std::mem::offset_of!(siginfo_t__bindgen_ty_1__bindgen_ty_3, si_uid),
         4usize,
         concat!(
             "Offset of field: ",
@@ -3886,7 +3915,7 @@ fn bindgen_test_layout_siginfo_t__bindgen_ty_1__bindgen_ty_3() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<siginfo_t__bindgen_ty_1__bindgen_ty_3>())).si_sigval as *const _ as usize },
+        ::std::mem::offset_of!(siginfo_t__bindgen_ty_1__bindgen_ty_3, si_sigval),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3924,7 +3953,7 @@ fn bindgen_test_layout_siginfo_t__bindgen_ty_1__bindgen_ty_4() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<siginfo_t__bindgen_ty_1__bindgen_ty_4>())).si_pid as *const _ as usize },
+        ::std::mem::offset_of!(siginfo_t__bindgen_ty_1__bindgen_ty_4, si_pid),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3934,7 +3963,7 @@ fn bindgen_test_layout_siginfo_t__bindgen_ty_1__bindgen_ty_4() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<siginfo_t__bindgen_ty_1__bindgen_ty_4>())).si_uid as *const _ as usize },
+        ::std::mem::offset_of!(siginfo_t__bindgen_ty_1__bindgen_ty_4, si_uid),
         4usize,
         concat!(
             "Offset of field: ",
@@ -3944,7 +3973,7 @@ fn bindgen_test_layout_siginfo_t__bindgen_ty_1__bindgen_ty_4() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<siginfo_t__bindgen_ty_1__bindgen_ty_4>())).si_status as *const _ as usize },
+        ::std::mem::offset_of!(siginfo_t__bindgen_ty_1__bindgen_ty_4, si_status),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3954,7 +3983,7 @@ fn bindgen_test_layout_siginfo_t__bindgen_ty_1__bindgen_ty_4() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<siginfo_t__bindgen_ty_1__bindgen_ty_4>())).si_utime as *const _ as usize },
+        ::std::mem::offset_of!(siginfo_t__bindgen_ty_1__bindgen_ty_4, si_utime),
         16usize,
         concat!(
             "Offset of field: ",
@@ -3964,7 +3993,7 @@ fn bindgen_test_layout_siginfo_t__bindgen_ty_1__bindgen_ty_4() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<siginfo_t__bindgen_ty_1__bindgen_ty_4>())).si_stime as *const _ as usize },
+        ::std::mem::offset_of!(siginfo_t__bindgen_ty_1__bindgen_ty_4, si_stime),
         24usize,
         concat!(
             "Offset of field: ",
@@ -4006,7 +4035,7 @@ fn bindgen_test_layout_siginfo_t__bindgen_ty_1__bindgen_ty_5__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<siginfo_t__bindgen_ty_1__bindgen_ty_5__bindgen_ty_1>()))._lower as *const _ as usize },
+        ::std::mem::offset_of!(siginfo_t__bindgen_ty_1__bindgen_ty_5__bindgen_ty_1, _lower),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4016,7 +4045,7 @@ fn bindgen_test_layout_siginfo_t__bindgen_ty_1__bindgen_ty_5__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<siginfo_t__bindgen_ty_1__bindgen_ty_5__bindgen_ty_1>()))._upper as *const _ as usize },
+        ::std::mem::offset_of!(siginfo_t__bindgen_ty_1__bindgen_ty_5__bindgen_ty_1, _upper),
         8usize,
         concat!(
             "Offset of field: ",
@@ -4045,7 +4074,7 @@ fn bindgen_test_layout_siginfo_t__bindgen_ty_1__bindgen_ty_5() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<siginfo_t__bindgen_ty_1__bindgen_ty_5>())).si_addr as *const _ as usize },
+        ::std::mem::offset_of!(siginfo_t__bindgen_ty_1__bindgen_ty_5, si_addr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4055,7 +4084,7 @@ fn bindgen_test_layout_siginfo_t__bindgen_ty_1__bindgen_ty_5() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<siginfo_t__bindgen_ty_1__bindgen_ty_5>())).si_addr_lsb as *const _ as usize },
+        ::std::mem::offset_of!(siginfo_t__bindgen_ty_1__bindgen_ty_5, si_addr_lsb),
         8usize,
         concat!(
             "Offset of field: ",
@@ -4065,7 +4094,7 @@ fn bindgen_test_layout_siginfo_t__bindgen_ty_1__bindgen_ty_5() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<siginfo_t__bindgen_ty_1__bindgen_ty_5>())).si_addr_bnd as *const _ as usize },
+        ::std::mem::offset_of!(siginfo_t__bindgen_ty_1__bindgen_ty_5, si_addr_bnd),
         16usize,
         concat!(
             "Offset of field: ",
@@ -4100,7 +4129,7 @@ fn bindgen_test_layout_siginfo_t__bindgen_ty_1__bindgen_ty_6() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<siginfo_t__bindgen_ty_1__bindgen_ty_6>())).si_band as *const _ as usize },
+        ::std::mem::offset_of!(siginfo_t__bindgen_ty_1__bindgen_ty_6, si_band),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4110,7 +4139,7 @@ fn bindgen_test_layout_siginfo_t__bindgen_ty_1__bindgen_ty_6() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<siginfo_t__bindgen_ty_1__bindgen_ty_6>())).si_fd as *const _ as usize },
+        ::std::mem::offset_of!(siginfo_t__bindgen_ty_1__bindgen_ty_6, si_fd),
         8usize,
         concat!(
             "Offset of field: ",
@@ -4146,7 +4175,7 @@ fn bindgen_test_layout_siginfo_t__bindgen_ty_1__bindgen_ty_7() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<siginfo_t__bindgen_ty_1__bindgen_ty_7>()))._call_addr as *const _ as usize },
+        ::std::mem::offset_of!(siginfo_t__bindgen_ty_1__bindgen_ty_7, _call_addr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4156,7 +4185,7 @@ fn bindgen_test_layout_siginfo_t__bindgen_ty_1__bindgen_ty_7() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<siginfo_t__bindgen_ty_1__bindgen_ty_7>()))._syscall as *const _ as usize },
+        ::std::mem::offset_of!(siginfo_t__bindgen_ty_1__bindgen_ty_7, _syscall),
         8usize,
         concat!(
             "Offset of field: ",
@@ -4166,7 +4195,7 @@ fn bindgen_test_layout_siginfo_t__bindgen_ty_1__bindgen_ty_7() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<siginfo_t__bindgen_ty_1__bindgen_ty_7>()))._arch as *const _ as usize },
+        ::std::mem::offset_of!(siginfo_t__bindgen_ty_1__bindgen_ty_7, _arch),
         12usize,
         concat!(
             "Offset of field: ",
@@ -4189,7 +4218,7 @@ fn bindgen_test_layout_siginfo_t__bindgen_ty_1() {
         concat!("Alignment of ", stringify!(siginfo_t__bindgen_ty_1))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<siginfo_t__bindgen_ty_1>()))._pad as *const _ as usize },
+        ::std::mem::offset_of!(siginfo_t__bindgen_ty_1, _pad),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4199,7 +4228,7 @@ fn bindgen_test_layout_siginfo_t__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<siginfo_t__bindgen_ty_1>()))._kill as *const _ as usize },
+        ::std::mem::offset_of!(siginfo_t__bindgen_ty_1, _kill),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4209,7 +4238,7 @@ fn bindgen_test_layout_siginfo_t__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<siginfo_t__bindgen_ty_1>()))._timer as *const _ as usize },
+        ::std::mem::offset_of!(siginfo_t__bindgen_ty_1, _timer),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4219,7 +4248,7 @@ fn bindgen_test_layout_siginfo_t__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<siginfo_t__bindgen_ty_1>()))._rt as *const _ as usize },
+        ::std::mem::offset_of!(siginfo_t__bindgen_ty_1, _rt),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4229,7 +4258,7 @@ fn bindgen_test_layout_siginfo_t__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<siginfo_t__bindgen_ty_1>()))._sigchld as *const _ as usize },
+        ::std::mem::offset_of!(siginfo_t__bindgen_ty_1, _sigchld),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4239,7 +4268,7 @@ fn bindgen_test_layout_siginfo_t__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<siginfo_t__bindgen_ty_1>()))._sigfault as *const _ as usize },
+        ::std::mem::offset_of!(siginfo_t__bindgen_ty_1, _sigfault),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4249,7 +4278,7 @@ fn bindgen_test_layout_siginfo_t__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<siginfo_t__bindgen_ty_1>()))._sigpoll as *const _ as usize },
+        ::std::mem::offset_of!(siginfo_t__bindgen_ty_1, _sigpoll),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4259,7 +4288,7 @@ fn bindgen_test_layout_siginfo_t__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<siginfo_t__bindgen_ty_1>()))._sigsys as *const _ as usize },
+        ::std::mem::offset_of!(siginfo_t__bindgen_ty_1, _sigsys),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4282,7 +4311,7 @@ fn bindgen_test_layout_siginfo_t() {
         concat!("Alignment of ", stringify!(siginfo_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<siginfo_t>())).si_signo as *const _ as usize },
+        ::std::mem::offset_of!(siginfo_t, si_signo),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4292,7 +4321,7 @@ fn bindgen_test_layout_siginfo_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<siginfo_t>())).si_errno as *const _ as usize },
+        ::std::mem::offset_of!(siginfo_t, si_errno),
         4usize,
         concat!(
             "Offset of field: ",
@@ -4302,7 +4331,7 @@ fn bindgen_test_layout_siginfo_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<siginfo_t>())).si_code as *const _ as usize },
+        ::std::mem::offset_of!(siginfo_t, si_code),
         8usize,
         concat!(
             "Offset of field: ",
@@ -4312,7 +4341,7 @@ fn bindgen_test_layout_siginfo_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<siginfo_t>()))._sifields as *const _ as usize },
+        ::std::mem::offset_of!(siginfo_t, _sifields),
         16usize,
         concat!(
             "Offset of field: ",
@@ -4414,7 +4443,7 @@ fn bindgen_test_layout_sigevent__bindgen_ty_1__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<sigevent__bindgen_ty_1__bindgen_ty_1>()))._function as *const _ as usize },
+        ::std::mem::offset_of!(sigevent__bindgen_ty_1__bindgen_ty_1, _function),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4424,7 +4453,7 @@ fn bindgen_test_layout_sigevent__bindgen_ty_1__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<sigevent__bindgen_ty_1__bindgen_ty_1>()))._attribute as *const _ as usize },
+        ::std::mem::offset_of!(sigevent__bindgen_ty_1__bindgen_ty_1, _attribute),
         8usize,
         concat!(
             "Offset of field: ",
@@ -4447,7 +4476,7 @@ fn bindgen_test_layout_sigevent__bindgen_ty_1() {
         concat!("Alignment of ", stringify!(sigevent__bindgen_ty_1))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<sigevent__bindgen_ty_1>()))._pad as *const _ as usize },
+        ::std::mem::offset_of!(sigevent__bindgen_ty_1, _pad),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4457,7 +4486,7 @@ fn bindgen_test_layout_sigevent__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<sigevent__bindgen_ty_1>()))._tid as *const _ as usize },
+        ::std::mem::offset_of!(sigevent__bindgen_ty_1, _tid),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4467,7 +4496,7 @@ fn bindgen_test_layout_sigevent__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<sigevent__bindgen_ty_1>()))._sigev_thread as *const _ as usize },
+        ::std::mem::offset_of!(sigevent__bindgen_ty_1, _sigev_thread),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4490,7 +4519,7 @@ fn bindgen_test_layout_sigevent() {
         concat!("Alignment of ", stringify!(sigevent))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<sigevent>())).sigev_value as *const _ as usize },
+        ::std::mem::offset_of!(sigevent, sigev_value),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4500,7 +4529,7 @@ fn bindgen_test_layout_sigevent() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<sigevent>())).sigev_signo as *const _ as usize },
+        ::std::mem::offset_of!(sigevent, sigev_signo),
         8usize,
         concat!(
             "Offset of field: ",
@@ -4510,7 +4539,7 @@ fn bindgen_test_layout_sigevent() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<sigevent>())).sigev_notify as *const _ as usize },
+        ::std::mem::offset_of!(sigevent, sigev_notify),
         12usize,
         concat!(
             "Offset of field: ",
@@ -4520,7 +4549,7 @@ fn bindgen_test_layout_sigevent() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<sigevent>()))._sigev_un as *const _ as usize },
+        ::std::mem::offset_of!(sigevent, _sigev_un),
         16usize,
         concat!(
             "Offset of field: ",
@@ -4618,7 +4647,7 @@ fn bindgen_test_layout_sigaction__bindgen_ty_1() {
         concat!("Alignment of ", stringify!(sigaction__bindgen_ty_1))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<sigaction__bindgen_ty_1>())).sa_handler as *const _ as usize },
+        ::std::mem::offset_of!(sigaction__bindgen_ty_1, sa_handler),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4628,7 +4657,7 @@ fn bindgen_test_layout_sigaction__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<sigaction__bindgen_ty_1>())).sa_sigaction as *const _ as usize },
+        ::std::mem::offset_of!(sigaction__bindgen_ty_1, sa_sigaction),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4651,7 +4680,7 @@ fn bindgen_test_layout_sigaction() {
         concat!("Alignment of ", stringify!(sigaction))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<sigaction>())).__sigaction_handler as *const _ as usize },
+        ::std::mem::offset_of!(sigaction, __sigaction_handler),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4661,7 +4690,7 @@ fn bindgen_test_layout_sigaction() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<sigaction>())).sa_mask as *const _ as usize },
+        ::std::mem::offset_of!(sigaction, sa_mask),
         8usize,
         concat!(
             "Offset of field: ",
@@ -4671,7 +4700,7 @@ fn bindgen_test_layout_sigaction() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<sigaction>())).sa_flags as *const _ as usize },
+        ::std::mem::offset_of!(sigaction, sa_flags),
         136usize,
         concat!(
             "Offset of field: ",
@@ -4681,7 +4710,7 @@ fn bindgen_test_layout_sigaction() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<sigaction>())).sa_restorer as *const _ as usize },
+        ::std::mem::offset_of!(sigaction, sa_restorer),
         144usize,
         concat!(
             "Offset of field: ",
@@ -4743,7 +4772,7 @@ fn bindgen_test_layout__fpx_sw_bytes() {
         concat!("Alignment of ", stringify!(_fpx_sw_bytes))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_fpx_sw_bytes>())).magic1 as *const _ as usize },
+        ::std::mem::offset_of!(_fpx_sw_bytes, magic1),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4753,7 +4782,7 @@ fn bindgen_test_layout__fpx_sw_bytes() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_fpx_sw_bytes>())).extended_size as *const _ as usize },
+        ::std::mem::offset_of!(_fpx_sw_bytes, extended_size),
         4usize,
         concat!(
             "Offset of field: ",
@@ -4763,7 +4792,7 @@ fn bindgen_test_layout__fpx_sw_bytes() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_fpx_sw_bytes>())).xstate_bv as *const _ as usize },
+        ::std::mem::offset_of!(_fpx_sw_bytes, xstate_bv),
         8usize,
         concat!(
             "Offset of field: ",
@@ -4773,7 +4802,7 @@ fn bindgen_test_layout__fpx_sw_bytes() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_fpx_sw_bytes>())).xstate_size as *const _ as usize },
+        ::std::mem::offset_of!(_fpx_sw_bytes, xstate_size),
         16usize,
         concat!(
             "Offset of field: ",
@@ -4783,7 +4812,7 @@ fn bindgen_test_layout__fpx_sw_bytes() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_fpx_sw_bytes>())).padding as *const _ as usize },
+        ::std::mem::offset_of!(_fpx_sw_bytes, padding),
         20usize,
         concat!(
             "Offset of field: ",
@@ -4812,7 +4841,7 @@ fn bindgen_test_layout__fpreg() {
         concat!("Alignment of ", stringify!(_fpreg))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_fpreg>())).significand as *const _ as usize },
+        ::std::mem::offset_of!(_fpreg, significand),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4822,7 +4851,7 @@ fn bindgen_test_layout__fpreg() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_fpreg>())).exponent as *const _ as usize },
+        ::std::mem::offset_of!(_fpreg, exponent),
         8usize,
         concat!(
             "Offset of field: ",
@@ -4852,7 +4881,7 @@ fn bindgen_test_layout__fpxreg() {
         concat!("Alignment of ", stringify!(_fpxreg))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_fpxreg>())).significand as *const _ as usize },
+        ::std::mem::offset_of!(_fpxreg, significand),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4862,7 +4891,7 @@ fn bindgen_test_layout__fpxreg() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_fpxreg>())).exponent as *const _ as usize },
+        ::std::mem::offset_of!(_fpxreg, exponent),
         8usize,
         concat!(
             "Offset of field: ",
@@ -4872,7 +4901,7 @@ fn bindgen_test_layout__fpxreg() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_fpxreg>())).padding as *const _ as usize },
+        ::std::mem::offset_of!(_fpxreg, padding),
         10usize,
         concat!(
             "Offset of field: ",
@@ -4900,7 +4929,7 @@ fn bindgen_test_layout__xmmreg() {
         concat!("Alignment of ", stringify!(_xmmreg))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_xmmreg>())).element as *const _ as usize },
+        ::std::mem::offset_of!(_xmmreg, element),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4938,7 +4967,7 @@ fn bindgen_test_layout__fpstate() {
         concat!("Alignment of ", stringify!(_fpstate))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_fpstate>())).cwd as *const _ as usize },
+        ::std::mem::offset_of!(_fpstate, cwd),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4948,7 +4977,7 @@ fn bindgen_test_layout__fpstate() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_fpstate>())).swd as *const _ as usize },
+        ::std::mem::offset_of!(_fpstate, swd),
         2usize,
         concat!(
             "Offset of field: ",
@@ -4958,7 +4987,7 @@ fn bindgen_test_layout__fpstate() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_fpstate>())).ftw as *const _ as usize },
+        ::std::mem::offset_of!(_fpstate, ftw),
         4usize,
         concat!(
             "Offset of field: ",
@@ -4968,7 +4997,7 @@ fn bindgen_test_layout__fpstate() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_fpstate>())).fop as *const _ as usize },
+        ::std::mem::offset_of!(_fpstate, fop),
         6usize,
         concat!(
             "Offset of field: ",
@@ -4978,7 +5007,7 @@ fn bindgen_test_layout__fpstate() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_fpstate>())).rip as *const _ as usize },
+        ::std::mem::offset_of!(_fpstate, rip),
         8usize,
         concat!(
             "Offset of field: ",
@@ -4988,7 +5017,7 @@ fn bindgen_test_layout__fpstate() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_fpstate>())).rdp as *const _ as usize },
+        ::std::mem::offset_of!(_fpstate, rdp),
         16usize,
         concat!(
             "Offset of field: ",
@@ -4998,7 +5027,7 @@ fn bindgen_test_layout__fpstate() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_fpstate>())).mxcsr as *const _ as usize },
+        ::std::mem::offset_of!(_fpstate, mxcsr),
         24usize,
         concat!(
             "Offset of field: ",
@@ -5008,7 +5037,7 @@ fn bindgen_test_layout__fpstate() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_fpstate>())).mxcr_mask as *const _ as usize },
+        ::std::mem::offset_of!(_fpstate, mxcr_mask),
         28usize,
         concat!(
             "Offset of field: ",
@@ -5018,7 +5047,7 @@ fn bindgen_test_layout__fpstate() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_fpstate>()))._st as *const _ as usize },
+        ::std::mem::offset_of!(_fpstate, _st),
         32usize,
         concat!(
             "Offset of field: ",
@@ -5028,7 +5057,7 @@ fn bindgen_test_layout__fpstate() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_fpstate>()))._xmm as *const _ as usize },
+        ::std::mem::offset_of!(_fpstate, _xmm),
         160usize,
         concat!(
             "Offset of field: ",
@@ -5038,7 +5067,7 @@ fn bindgen_test_layout__fpstate() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_fpstate>())).padding as *const _ as usize },
+        ::std::mem::offset_of!(_fpstate, padding),
         416usize,
         concat!(
             "Offset of field: ",
@@ -5100,7 +5129,7 @@ fn bindgen_test_layout_sigcontext__bindgen_ty_1() {
         concat!("Alignment of ", stringify!(sigcontext__bindgen_ty_1))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<sigcontext__bindgen_ty_1>())).fpstate as *const _ as usize },
+        ::std::mem::offset_of!(sigcontext__bindgen_ty_1, fpstate),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5110,7 +5139,7 @@ fn bindgen_test_layout_sigcontext__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<sigcontext__bindgen_ty_1>())).__fpstate_word as *const _ as usize },
+        ::std::mem::offset_of!(sigcontext__bindgen_ty_1, __fpstate_word),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5133,7 +5162,7 @@ fn bindgen_test_layout_sigcontext() {
         concat!("Alignment of ", stringify!(sigcontext))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<sigcontext>())).r8 as *const _ as usize },
+        ::std::mem::offset_of!(sigcontext, r8),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5143,7 +5172,7 @@ fn bindgen_test_layout_sigcontext() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<sigcontext>())).r9 as *const _ as usize },
+        ::std::mem::offset_of!(sigcontext, r9),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5153,7 +5182,7 @@ fn bindgen_test_layout_sigcontext() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<sigcontext>())).r10 as *const _ as usize },
+        ::std::mem::offset_of!(sigcontext, r10),
         16usize,
         concat!(
             "Offset of field: ",
@@ -5163,7 +5192,7 @@ fn bindgen_test_layout_sigcontext() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<sigcontext>())).r11 as *const _ as usize },
+        ::std::mem::offset_of!(sigcontext, r11),
         24usize,
         concat!(
             "Offset of field: ",
@@ -5173,7 +5202,7 @@ fn bindgen_test_layout_sigcontext() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<sigcontext>())).r12 as *const _ as usize },
+        ::std::mem::offset_of!(sigcontext, r12),
         32usize,
         concat!(
             "Offset of field: ",
@@ -5183,7 +5212,7 @@ fn bindgen_test_layout_sigcontext() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<sigcontext>())).r13 as *const _ as usize },
+        ::std::mem::offset_of!(sigcontext, r13),
         40usize,
         concat!(
             "Offset of field: ",
@@ -5193,7 +5222,7 @@ fn bindgen_test_layout_sigcontext() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<sigcontext>())).r14 as *const _ as usize },
+        ::std::mem::offset_of!(sigcontext, r14),
         48usize,
         concat!(
             "Offset of field: ",
@@ -5203,7 +5232,7 @@ fn bindgen_test_layout_sigcontext() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<sigcontext>())).r15 as *const _ as usize },
+        ::std::mem::offset_of!(sigcontext, r15),
         56usize,
         concat!(
             "Offset of field: ",
@@ -5213,7 +5242,7 @@ fn bindgen_test_layout_sigcontext() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<sigcontext>())).rdi as *const _ as usize },
+        ::std::mem::offset_of!(sigcontext, rdi),
         64usize,
         concat!(
             "Offset of field: ",
@@ -5223,7 +5252,7 @@ fn bindgen_test_layout_sigcontext() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<sigcontext>())).rsi as *const _ as usize },
+        ::std::mem::offset_of!(sigcontext, rsi),
         72usize,
         concat!(
             "Offset of field: ",
@@ -5233,7 +5262,7 @@ fn bindgen_test_layout_sigcontext() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<sigcontext>())).rbp as *const _ as usize },
+        ::std::mem::offset_of!(sigcontext, rbp),
         80usize,
         concat!(
             "Offset of field: ",
@@ -5243,7 +5272,7 @@ fn bindgen_test_layout_sigcontext() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<sigcontext>())).rbx as *const _ as usize },
+        ::std::mem::offset_of!(sigcontext, rbx),
         88usize,
         concat!(
             "Offset of field: ",
@@ -5253,7 +5282,7 @@ fn bindgen_test_layout_sigcontext() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<sigcontext>())).rdx as *const _ as usize },
+        ::std::mem::offset_of!(sigcontext, rdx),
         96usize,
         concat!(
             "Offset of field: ",
@@ -5263,7 +5292,7 @@ fn bindgen_test_layout_sigcontext() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<sigcontext>())).rax as *const _ as usize },
+        ::std::mem::offset_of!(sigcontext, rax),
         104usize,
         concat!(
             "Offset of field: ",
@@ -5273,7 +5302,7 @@ fn bindgen_test_layout_sigcontext() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<sigcontext>())).rcx as *const _ as usize },
+        ::std::mem::offset_of!(sigcontext, rcx),
         112usize,
         concat!(
             "Offset of field: ",
@@ -5283,7 +5312,7 @@ fn bindgen_test_layout_sigcontext() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<sigcontext>())).rsp as *const _ as usize },
+        ::std::mem::offset_of!(sigcontext, rsp),
         120usize,
         concat!(
             "Offset of field: ",
@@ -5293,7 +5322,7 @@ fn bindgen_test_layout_sigcontext() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<sigcontext>())).rip as *const _ as usize },
+        ::std::mem::offset_of!(sigcontext, rip),
         128usize,
         concat!(
             "Offset of field: ",
@@ -5303,7 +5332,7 @@ fn bindgen_test_layout_sigcontext() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<sigcontext>())).eflags as *const _ as usize },
+        ::std::mem::offset_of!(sigcontext, eflags),
         136usize,
         concat!(
             "Offset of field: ",
@@ -5313,7 +5342,7 @@ fn bindgen_test_layout_sigcontext() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<sigcontext>())).cs as *const _ as usize },
+        ::std::mem::offset_of!(sigcontext, cs),
         144usize,
         concat!(
             "Offset of field: ",
@@ -5323,7 +5352,7 @@ fn bindgen_test_layout_sigcontext() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<sigcontext>())).gs as *const _ as usize },
+        ::std::mem::offset_of!(sigcontext, gs),
         146usize,
         concat!(
             "Offset of field: ",
@@ -5333,7 +5362,7 @@ fn bindgen_test_layout_sigcontext() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<sigcontext>())).fs as *const _ as usize },
+        ::std::mem::offset_of!(sigcontext, fs),
         148usize,
         concat!(
             "Offset of field: ",
@@ -5343,7 +5372,7 @@ fn bindgen_test_layout_sigcontext() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<sigcontext>())).__pad0 as *const _ as usize },
+        ::std::mem::offset_of!(sigcontext, __pad0),
         150usize,
         concat!(
             "Offset of field: ",
@@ -5353,7 +5382,7 @@ fn bindgen_test_layout_sigcontext() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<sigcontext>())).err as *const _ as usize },
+        ::std::mem::offset_of!(sigcontext, err),
         152usize,
         concat!(
             "Offset of field: ",
@@ -5363,7 +5392,7 @@ fn bindgen_test_layout_sigcontext() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<sigcontext>())).trapno as *const _ as usize },
+        ::std::mem::offset_of!(sigcontext, trapno),
         160usize,
         concat!(
             "Offset of field: ",
@@ -5373,7 +5402,7 @@ fn bindgen_test_layout_sigcontext() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<sigcontext>())).oldmask as *const _ as usize },
+        ::std::mem::offset_of!(sigcontext, oldmask),
         168usize,
         concat!(
             "Offset of field: ",
@@ -5383,7 +5412,7 @@ fn bindgen_test_layout_sigcontext() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<sigcontext>())).cr2 as *const _ as usize },
+        ::std::mem::offset_of!(sigcontext, cr2),
         176usize,
         concat!(
             "Offset of field: ",
@@ -5393,7 +5422,7 @@ fn bindgen_test_layout_sigcontext() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<sigcontext>())).__reserved1 as *const _ as usize },
+        ::std::mem::offset_of!(sigcontext, __reserved1),
         192usize,
         concat!(
             "Offset of field: ",
@@ -5423,7 +5452,7 @@ fn bindgen_test_layout__xsave_hdr() {
         concat!("Alignment of ", stringify!(_xsave_hdr))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_xsave_hdr>())).xstate_bv as *const _ as usize },
+        ::std::mem::offset_of!(_xsave_hdr, xstate_bv),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5433,7 +5462,7 @@ fn bindgen_test_layout__xsave_hdr() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_xsave_hdr>())).reserved1 as *const _ as usize },
+        ::std::mem::offset_of!(_xsave_hdr, reserved1),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5443,7 +5472,7 @@ fn bindgen_test_layout__xsave_hdr() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_xsave_hdr>())).reserved2 as *const _ as usize },
+        ::std::mem::offset_of!(_xsave_hdr, reserved2),
         24usize,
         concat!(
             "Offset of field: ",
@@ -5471,7 +5500,7 @@ fn bindgen_test_layout__ymmh_state() {
         concat!("Alignment of ", stringify!(_ymmh_state))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_ymmh_state>())).ymmh_space as *const _ as usize },
+        ::std::mem::offset_of!(_ymmh_state, ymmh_space),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5501,7 +5530,7 @@ fn bindgen_test_layout__xstate() {
         concat!("Alignment of ", stringify!(_xstate))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_xstate>())).fpstate as *const _ as usize },
+        ::std::mem::offset_of!(_xstate, fpstate),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5511,7 +5540,7 @@ fn bindgen_test_layout__xstate() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_xstate>())).xstate_hdr as *const _ as usize },
+        ::std::mem::offset_of!(_xstate, xstate_hdr),
         512usize,
         concat!(
             "Offset of field: ",
@@ -5521,7 +5550,7 @@ fn bindgen_test_layout__xstate() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_xstate>())).ymmh as *const _ as usize },
+        ::std::mem::offset_of!(_xstate, ymmh),
         576usize,
         concat!(
             "Offset of field: ",
@@ -5556,7 +5585,7 @@ fn bindgen_test_layout_sigstack() {
         concat!("Alignment of ", stringify!(sigstack))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<sigstack>())).ss_sp as *const _ as usize },
+        ::std::mem::offset_of!(sigstack, ss_sp),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5566,7 +5595,7 @@ fn bindgen_test_layout_sigstack() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<sigstack>())).ss_onstack as *const _ as usize },
+        ::std::mem::offset_of!(sigstack, ss_onstack),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5599,7 +5628,7 @@ fn bindgen_test_layout_sigaltstack() {
         concat!("Alignment of ", stringify!(sigaltstack))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<sigaltstack>())).ss_sp as *const _ as usize },
+        ::std::mem::offset_of!(sigaltstack, ss_sp),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5609,7 +5638,7 @@ fn bindgen_test_layout_sigaltstack() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<sigaltstack>())).ss_flags as *const _ as usize },
+        ::std::mem::offset_of!(sigaltstack, ss_flags),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5619,7 +5648,7 @@ fn bindgen_test_layout_sigaltstack() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<sigaltstack>())).ss_size as *const _ as usize },
+        ::std::mem::offset_of!(sigaltstack, ss_size),
         16usize,
         concat!(
             "Offset of field: ",
@@ -5652,7 +5681,7 @@ fn bindgen_test_layout__libc_fpxreg() {
         concat!("Alignment of ", stringify!(_libc_fpxreg))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_libc_fpxreg>())).significand as *const _ as usize },
+        ::std::mem::offset_of!(_libc_fpxreg, significand),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5662,7 +5691,7 @@ fn bindgen_test_layout__libc_fpxreg() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_libc_fpxreg>())).exponent as *const _ as usize },
+        ::std::mem::offset_of!(_libc_fpxreg, exponent),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5672,7 +5701,7 @@ fn bindgen_test_layout__libc_fpxreg() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_libc_fpxreg>())).padding as *const _ as usize },
+        ::std::mem::offset_of!(_libc_fpxreg, padding),
         10usize,
         concat!(
             "Offset of field: ",
@@ -5700,7 +5729,7 @@ fn bindgen_test_layout__libc_xmmreg() {
         concat!("Alignment of ", stringify!(_libc_xmmreg))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_libc_xmmreg>())).element as *const _ as usize },
+        ::std::mem::offset_of!(_libc_xmmreg, element),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5738,7 +5767,7 @@ fn bindgen_test_layout__libc_fpstate() {
         concat!("Alignment of ", stringify!(_libc_fpstate))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_libc_fpstate>())).cwd as *const _ as usize },
+        ::std::mem::offset_of!(_libc_fpstate, cwd),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5748,7 +5777,7 @@ fn bindgen_test_layout__libc_fpstate() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_libc_fpstate>())).swd as *const _ as usize },
+        ::std::mem::offset_of!(_libc_fpstate, swd),
         2usize,
         concat!(
             "Offset of field: ",
@@ -5758,7 +5787,7 @@ fn bindgen_test_layout__libc_fpstate() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_libc_fpstate>())).ftw as *const _ as usize },
+        ::std::mem::offset_of!(_libc_fpstate, ftw),
         4usize,
         concat!(
             "Offset of field: ",
@@ -5768,7 +5797,7 @@ fn bindgen_test_layout__libc_fpstate() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_libc_fpstate>())).fop as *const _ as usize },
+        ::std::mem::offset_of!(_libc_fpstate, fop),
         6usize,
         concat!(
             "Offset of field: ",
@@ -5778,7 +5807,7 @@ fn bindgen_test_layout__libc_fpstate() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_libc_fpstate>())).rip as *const _ as usize },
+        ::std::mem::offset_of!(_libc_fpstate, rip),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5788,7 +5817,7 @@ fn bindgen_test_layout__libc_fpstate() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_libc_fpstate>())).rdp as *const _ as usize },
+        ::std::mem::offset_of!(_libc_fpstate, rdp),
         16usize,
         concat!(
             "Offset of field: ",
@@ -5798,7 +5827,7 @@ fn bindgen_test_layout__libc_fpstate() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_libc_fpstate>())).mxcsr as *const _ as usize },
+        ::std::mem::offset_of!(_libc_fpstate, mxcsr),
         24usize,
         concat!(
             "Offset of field: ",
@@ -5808,7 +5837,7 @@ fn bindgen_test_layout__libc_fpstate() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_libc_fpstate>())).mxcr_mask as *const _ as usize },
+        ::std::mem::offset_of!(_libc_fpstate, mxcr_mask),
         28usize,
         concat!(
             "Offset of field: ",
@@ -5818,7 +5847,7 @@ fn bindgen_test_layout__libc_fpstate() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_libc_fpstate>()))._st as *const _ as usize },
+        ::std::mem::offset_of!(_libc_fpstate, _st),
         32usize,
         concat!(
             "Offset of field: ",
@@ -5828,7 +5857,7 @@ fn bindgen_test_layout__libc_fpstate() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_libc_fpstate>()))._xmm as *const _ as usize },
+        ::std::mem::offset_of!(_libc_fpstate, _xmm),
         160usize,
         concat!(
             "Offset of field: ",
@@ -5838,7 +5867,7 @@ fn bindgen_test_layout__libc_fpstate() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_libc_fpstate>())).padding as *const _ as usize },
+        ::std::mem::offset_of!(_libc_fpstate, padding),
         416usize,
         concat!(
             "Offset of field: ",
@@ -5869,7 +5898,7 @@ fn bindgen_test_layout_mcontext_t() {
         concat!("Alignment of ", stringify!(mcontext_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<mcontext_t>())).gregs as *const _ as usize },
+        ::std::mem::offset_of!(mcontext_t, gregs),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5879,7 +5908,7 @@ fn bindgen_test_layout_mcontext_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<mcontext_t>())).fpregs as *const _ as usize },
+        ::std::mem::offset_of!(mcontext_t, fpregs),
         184usize,
         concat!(
             "Offset of field: ",
@@ -5889,7 +5918,7 @@ fn bindgen_test_layout_mcontext_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<mcontext_t>())).__reserved1 as *const _ as usize },
+        ::std::mem::offset_of!(mcontext_t, __reserved1),
         192usize,
         concat!(
             "Offset of field: ",
@@ -5922,7 +5951,7 @@ fn bindgen_test_layout_ucontext() {
         concat!("Alignment of ", stringify!(ucontext))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<ucontext>())).uc_flags as *const _ as usize },
+        ::std::mem::offset_of!(ucontext, uc_flags),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5932,7 +5961,7 @@ fn bindgen_test_layout_ucontext() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<ucontext>())).uc_link as *const _ as usize },
+        ::std::mem::offset_of!(ucontext, uc_link),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5942,7 +5971,7 @@ fn bindgen_test_layout_ucontext() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<ucontext>())).uc_stack as *const _ as usize },
+        ::std::mem::offset_of!(ucontext, uc_stack),
         16usize,
         concat!(
             "Offset of field: ",
@@ -5952,7 +5981,7 @@ fn bindgen_test_layout_ucontext() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<ucontext>())).uc_mcontext as *const _ as usize },
+        ::std::mem::offset_of!(ucontext, uc_mcontext),
         40usize,
         concat!(
             "Offset of field: ",
@@ -5962,7 +5991,7 @@ fn bindgen_test_layout_ucontext() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<ucontext>())).uc_sigmask as *const _ as usize },
+        ::std::mem::offset_of!(ucontext, uc_sigmask),
         296usize,
         concat!(
             "Offset of field: ",
@@ -5972,7 +6001,7 @@ fn bindgen_test_layout_ucontext() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<ucontext>())).__fpregs_mem as *const _ as usize },
+        ::std::mem::offset_of!(ucontext, __fpregs_mem),
         424usize,
         concat!(
             "Offset of field: ",
@@ -7086,7 +7115,7 @@ fn bindgen_test_layout_dirent() {
         concat!("Alignment of ", stringify!(dirent))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<dirent>())).d_ino as *const _ as usize },
+        ::std::mem::offset_of!(dirent, d_ino),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7096,7 +7125,7 @@ fn bindgen_test_layout_dirent() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<dirent>())).d_off as *const _ as usize },
+        ::std::mem::offset_of!(dirent, d_off),
         8usize,
         concat!(
             "Offset of field: ",
@@ -7106,7 +7135,7 @@ fn bindgen_test_layout_dirent() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<dirent>())).d_reclen as *const _ as usize },
+        ::std::mem::offset_of!(dirent, d_reclen),
         16usize,
         concat!(
             "Offset of field: ",
@@ -7116,7 +7145,7 @@ fn bindgen_test_layout_dirent() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<dirent>())).d_type as *const _ as usize },
+        ::std::mem::offset_of!(dirent, d_type),
         18usize,
         concat!(
             "Offset of field: ",
@@ -7126,7 +7155,7 @@ fn bindgen_test_layout_dirent() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<dirent>())).d_name as *const _ as usize },
+        ::std::mem::offset_of!(dirent, d_name),
         19usize,
         concat!(
             "Offset of field: ",
@@ -7438,7 +7467,7 @@ fn bindgen_test_layout__GMemVTable() {
         concat!("Alignment of ", stringify!(_GMemVTable))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GMemVTable>())).malloc as *const _ as usize },
+        ::std::mem::offset_of!(_GMemVTable, malloc),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7448,7 +7477,7 @@ fn bindgen_test_layout__GMemVTable() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GMemVTable>())).realloc as *const _ as usize },
+        ::std::mem::offset_of!(_GMemVTable, realloc),
         8usize,
         concat!(
             "Offset of field: ",
@@ -7458,7 +7487,7 @@ fn bindgen_test_layout__GMemVTable() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GMemVTable>())).free as *const _ as usize },
+        ::std::mem::offset_of!(_GMemVTable, free),
         16usize,
         concat!(
             "Offset of field: ",
@@ -7468,7 +7497,7 @@ fn bindgen_test_layout__GMemVTable() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GMemVTable>())).calloc as *const _ as usize },
+        ::std::mem::offset_of!(_GMemVTable, calloc),
         24usize,
         concat!(
             "Offset of field: ",
@@ -7478,7 +7507,7 @@ fn bindgen_test_layout__GMemVTable() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GMemVTable>())).try_malloc as *const _ as usize },
+        ::std::mem::offset_of!(_GMemVTable, try_malloc),
         32usize,
         concat!(
             "Offset of field: ",
@@ -7488,7 +7517,7 @@ fn bindgen_test_layout__GMemVTable() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GMemVTable>())).try_realloc as *const _ as usize },
+        ::std::mem::offset_of!(_GMemVTable, try_realloc),
         40usize,
         concat!(
             "Offset of field: ",
@@ -7561,7 +7590,7 @@ fn bindgen_test_layout__GNode() {
         concat!("Alignment of ", stringify!(_GNode))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GNode>())).data as *const _ as usize },
+        ::std::mem::offset_of!(_GNode, data),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7571,7 +7600,7 @@ fn bindgen_test_layout__GNode() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GNode>())).next as *const _ as usize },
+        ::std::mem::offset_of!(_GNode, next),
         8usize,
         concat!(
             "Offset of field: ",
@@ -7581,7 +7610,7 @@ fn bindgen_test_layout__GNode() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GNode>())).prev as *const _ as usize },
+        ::std::mem::offset_of!(_GNode, prev),
         16usize,
         concat!(
             "Offset of field: ",
@@ -7591,7 +7620,7 @@ fn bindgen_test_layout__GNode() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GNode>())).parent as *const _ as usize },
+        ::std::mem::offset_of!(_GNode, parent),
         24usize,
         concat!(
             "Offset of field: ",
@@ -7601,7 +7630,7 @@ fn bindgen_test_layout__GNode() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GNode>())).children as *const _ as usize },
+        ::std::mem::offset_of!(_GNode, children),
         32usize,
         concat!(
             "Offset of field: ",
@@ -7717,7 +7746,7 @@ fn bindgen_test_layout__GList() {
         concat!("Alignment of ", stringify!(_GList))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GList>())).data as *const _ as usize },
+        ::std::mem::offset_of!(_GList, data),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7727,7 +7756,7 @@ fn bindgen_test_layout__GList() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GList>())).next as *const _ as usize },
+        ::std::mem::offset_of!(_GList, next),
         8usize,
         concat!(
             "Offset of field: ",
@@ -7737,7 +7766,7 @@ fn bindgen_test_layout__GList() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GList>())).prev as *const _ as usize },
+        ::std::mem::offset_of!(_GList, prev),
         16usize,
         concat!(
             "Offset of field: ",
@@ -7871,7 +7900,7 @@ fn bindgen_test_layout__GHashTableIter() {
         concat!("Alignment of ", stringify!(_GHashTableIter))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GHashTableIter>())).dummy1 as *const _ as usize },
+        ::std::mem::offset_of!(_GHashTableIter, dummy1),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7881,7 +7910,7 @@ fn bindgen_test_layout__GHashTableIter() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GHashTableIter>())).dummy2 as *const _ as usize },
+        ::std::mem::offset_of!(_GHashTableIter, dummy2),
         8usize,
         concat!(
             "Offset of field: ",
@@ -7891,7 +7920,7 @@ fn bindgen_test_layout__GHashTableIter() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GHashTableIter>())).dummy3 as *const _ as usize },
+        ::std::mem::offset_of!(_GHashTableIter, dummy3),
         16usize,
         concat!(
             "Offset of field: ",
@@ -7901,7 +7930,7 @@ fn bindgen_test_layout__GHashTableIter() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GHashTableIter>())).dummy4 as *const _ as usize },
+        ::std::mem::offset_of!(_GHashTableIter, dummy4),
         24usize,
         concat!(
             "Offset of field: ",
@@ -7911,7 +7940,7 @@ fn bindgen_test_layout__GHashTableIter() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GHashTableIter>())).dummy5 as *const _ as usize },
+        ::std::mem::offset_of!(_GHashTableIter, dummy5),
         28usize,
         concat!(
             "Offset of field: ",
@@ -7921,7 +7950,7 @@ fn bindgen_test_layout__GHashTableIter() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GHashTableIter>())).dummy6 as *const _ as usize },
+        ::std::mem::offset_of!(_GHashTableIter, dummy6),
         32usize,
         concat!(
             "Offset of field: ",
@@ -8146,7 +8175,7 @@ fn bindgen_test_layout__GHookList() {
         concat!("Alignment of ", stringify!(_GHookList))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GHookList>())).seq_id as *const _ as usize },
+        ::std::mem::offset_of!(_GHookList, seq_id),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8156,7 +8185,7 @@ fn bindgen_test_layout__GHookList() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GHookList>())).hooks as *const _ as usize },
+        ::std::mem::offset_of!(_GHookList, hooks),
         16usize,
         concat!(
             "Offset of field: ",
@@ -8166,7 +8195,7 @@ fn bindgen_test_layout__GHookList() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GHookList>())).dummy3 as *const _ as usize },
+        ::std::mem::offset_of!(_GHookList, dummy3),
         24usize,
         concat!(
             "Offset of field: ",
@@ -8176,7 +8205,7 @@ fn bindgen_test_layout__GHookList() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GHookList>())).finalize_hook as *const _ as usize },
+        ::std::mem::offset_of!(_GHookList, finalize_hook),
         32usize,
         concat!(
             "Offset of field: ",
@@ -8186,7 +8215,7 @@ fn bindgen_test_layout__GHookList() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GHookList>())).dummy as *const _ as usize },
+        ::std::mem::offset_of!(_GHookList, dummy),
         40usize,
         concat!(
             "Offset of field: ",
@@ -8258,7 +8287,7 @@ fn bindgen_test_layout__GHook() {
         concat!("Alignment of ", stringify!(_GHook))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GHook>())).data as *const _ as usize },
+        ::std::mem::offset_of!(_GHook, data),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8268,7 +8297,7 @@ fn bindgen_test_layout__GHook() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GHook>())).next as *const _ as usize },
+        ::std::mem::offset_of!(_GHook, next),
         8usize,
         concat!(
             "Offset of field: ",
@@ -8278,7 +8307,7 @@ fn bindgen_test_layout__GHook() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GHook>())).prev as *const _ as usize },
+        ::std::mem::offset_of!(_GHook, prev),
         16usize,
         concat!(
             "Offset of field: ",
@@ -8288,7 +8317,7 @@ fn bindgen_test_layout__GHook() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GHook>())).ref_count as *const _ as usize },
+        ::std::mem::offset_of!(_GHook, ref_count),
         24usize,
         concat!(
             "Offset of field: ",
@@ -8298,7 +8327,7 @@ fn bindgen_test_layout__GHook() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GHook>())).hook_id as *const _ as usize },
+        ::std::mem::offset_of!(_GHook, hook_id),
         32usize,
         concat!(
             "Offset of field: ",
@@ -8308,7 +8337,7 @@ fn bindgen_test_layout__GHook() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GHook>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(_GHook, flags),
         40usize,
         concat!(
             "Offset of field: ",
@@ -8318,7 +8347,7 @@ fn bindgen_test_layout__GHook() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GHook>())).func as *const _ as usize },
+        ::std::mem::offset_of!(_GHook, func),
         48usize,
         concat!(
             "Offset of field: ",
@@ -8328,7 +8357,7 @@ fn bindgen_test_layout__GHook() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GHook>())).destroy as *const _ as usize },
+        ::std::mem::offset_of!(_GHook, destroy),
         56usize,
         concat!(
             "Offset of field: ",
@@ -8471,7 +8500,7 @@ fn bindgen_test_layout__GPollFD() {
         concat!("Alignment of ", stringify!(_GPollFD))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GPollFD>())).fd as *const _ as usize },
+        ::std::mem::offset_of!(_GPollFD, fd),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8481,7 +8510,7 @@ fn bindgen_test_layout__GPollFD() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GPollFD>())).events as *const _ as usize },
+        ::std::mem::offset_of!(_GPollFD, events),
         4usize,
         concat!(
             "Offset of field: ",
@@ -8491,7 +8520,7 @@ fn bindgen_test_layout__GPollFD() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GPollFD>())).revents as *const _ as usize },
+        ::std::mem::offset_of!(_GPollFD, revents),
         6usize,
         concat!(
             "Offset of field: ",
@@ -8529,7 +8558,7 @@ fn bindgen_test_layout__GSList() {
         concat!("Alignment of ", stringify!(_GSList))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GSList>())).data as *const _ as usize },
+        ::std::mem::offset_of!(_GSList, data),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8539,7 +8568,7 @@ fn bindgen_test_layout__GSList() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GSList>())).next as *const _ as usize },
+        ::std::mem::offset_of!(_GSList, next),
         8usize,
         concat!(
             "Offset of field: ",
@@ -8783,7 +8812,7 @@ fn bindgen_test_layout__GSource() {
         concat!("Alignment of ", stringify!(_GSource))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GSource>())).callback_data as *const _ as usize },
+        ::std::mem::offset_of!(_GSource, callback_data),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8793,7 +8822,7 @@ fn bindgen_test_layout__GSource() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GSource>())).callback_funcs as *const _ as usize },
+        ::std::mem::offset_of!(_GSource, callback_funcs),
         8usize,
         concat!(
             "Offset of field: ",
@@ -8803,7 +8832,7 @@ fn bindgen_test_layout__GSource() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GSource>())).source_funcs as *const _ as usize },
+        ::std::mem::offset_of!(_GSource, source_funcs),
         16usize,
         concat!(
             "Offset of field: ",
@@ -8813,7 +8842,7 @@ fn bindgen_test_layout__GSource() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GSource>())).ref_count as *const _ as usize },
+        ::std::mem::offset_of!(_GSource, ref_count),
         24usize,
         concat!(
             "Offset of field: ",
@@ -8823,7 +8852,7 @@ fn bindgen_test_layout__GSource() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GSource>())).context as *const _ as usize },
+        ::std::mem::offset_of!(_GSource, context),
         32usize,
         concat!(
             "Offset of field: ",
@@ -8833,7 +8862,7 @@ fn bindgen_test_layout__GSource() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GSource>())).priority as *const _ as usize },
+        ::std::mem::offset_of!(_GSource, priority),
         40usize,
         concat!(
             "Offset of field: ",
@@ -8843,7 +8872,7 @@ fn bindgen_test_layout__GSource() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GSource>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(_GSource, flags),
         44usize,
         concat!(
             "Offset of field: ",
@@ -8853,7 +8882,7 @@ fn bindgen_test_layout__GSource() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GSource>())).source_id as *const _ as usize },
+        ::std::mem::offset_of!(_GSource, source_id),
         48usize,
         concat!(
             "Offset of field: ",
@@ -8863,7 +8892,7 @@ fn bindgen_test_layout__GSource() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GSource>())).poll_fds as *const _ as usize },
+        ::std::mem::offset_of!(_GSource, poll_fds),
         56usize,
         concat!(
             "Offset of field: ",
@@ -8873,7 +8902,7 @@ fn bindgen_test_layout__GSource() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GSource>())).prev as *const _ as usize },
+        ::std::mem::offset_of!(_GSource, prev),
         64usize,
         concat!(
             "Offset of field: ",
@@ -8883,7 +8912,7 @@ fn bindgen_test_layout__GSource() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GSource>())).next as *const _ as usize },
+        ::std::mem::offset_of!(_GSource, next),
         72usize,
         concat!(
             "Offset of field: ",
@@ -8893,7 +8922,7 @@ fn bindgen_test_layout__GSource() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GSource>())).name as *const _ as usize },
+        ::std::mem::offset_of!(_GSource, name),
         80usize,
         concat!(
             "Offset of field: ",
@@ -8903,7 +8932,7 @@ fn bindgen_test_layout__GSource() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GSource>())).priv_ as *const _ as usize },
+        ::std::mem::offset_of!(_GSource, priv_),
         88usize,
         concat!(
             "Offset of field: ",
@@ -8934,7 +8963,7 @@ fn bindgen_test_layout__GSourceCallbackFuncs() {
         concat!("Alignment of ", stringify!(_GSourceCallbackFuncs))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GSourceCallbackFuncs>())).ref_ as *const _ as usize },
+        ::std::mem::offset_of!(_GSourceCallbackFuncs, ref_),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8944,7 +8973,7 @@ fn bindgen_test_layout__GSourceCallbackFuncs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GSourceCallbackFuncs>())).unref as *const _ as usize },
+        ::std::mem::offset_of!(_GSourceCallbackFuncs, unref),
         8usize,
         concat!(
             "Offset of field: ",
@@ -8954,7 +8983,7 @@ fn bindgen_test_layout__GSourceCallbackFuncs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GSourceCallbackFuncs>())).get as *const _ as usize },
+        ::std::mem::offset_of!(_GSourceCallbackFuncs, get),
         16usize,
         concat!(
             "Offset of field: ",
@@ -8992,7 +9021,7 @@ fn bindgen_test_layout__GSourceFuncs() {
         concat!("Alignment of ", stringify!(_GSourceFuncs))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GSourceFuncs>())).prepare as *const _ as usize },
+        ::std::mem::offset_of!(_GSourceFuncs, prepare),
         0usize,
         concat!(
             "Offset of field: ",
@@ -9002,7 +9031,7 @@ fn bindgen_test_layout__GSourceFuncs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GSourceFuncs>())).check as *const _ as usize },
+        ::std::mem::offset_of!(_GSourceFuncs, check),
         8usize,
         concat!(
             "Offset of field: ",
@@ -9012,7 +9041,7 @@ fn bindgen_test_layout__GSourceFuncs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GSourceFuncs>())).dispatch as *const _ as usize },
+        ::std::mem::offset_of!(_GSourceFuncs, dispatch),
         16usize,
         concat!(
             "Offset of field: ",
@@ -9022,7 +9051,7 @@ fn bindgen_test_layout__GSourceFuncs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GSourceFuncs>())).finalize as *const _ as usize },
+        ::std::mem::offset_of!(_GSourceFuncs, finalize),
         24usize,
         concat!(
             "Offset of field: ",
@@ -9032,7 +9061,7 @@ fn bindgen_test_layout__GSourceFuncs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GSourceFuncs>())).closure_callback as *const _ as usize },
+        ::std::mem::offset_of!(_GSourceFuncs, closure_callback),
         32usize,
         concat!(
             "Offset of field: ",
@@ -9042,7 +9071,7 @@ fn bindgen_test_layout__GSourceFuncs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GSourceFuncs>())).closure_marshal as *const _ as usize },
+        ::std::mem::offset_of!(_GSourceFuncs, closure_marshal),
         40usize,
         concat!(
             "Offset of field: ",
@@ -9858,7 +9887,7 @@ fn bindgen_test_layout__GString() {
         concat!("Alignment of ", stringify!(_GString))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GString>())).str as *const _ as usize },
+        ::std::mem::offset_of!(_GString, str),
         0usize,
         concat!(
             "Offset of field: ",
@@ -9868,7 +9897,7 @@ fn bindgen_test_layout__GString() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GString>())).len as *const _ as usize },
+        ::std::mem::offset_of!(_GString, len),
         8usize,
         concat!(
             "Offset of field: ",
@@ -9878,7 +9907,7 @@ fn bindgen_test_layout__GString() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GString>())).allocated_len as *const _ as usize },
+        ::std::mem::offset_of!(_GString, allocated_len),
         16usize,
         concat!(
             "Offset of field: ",
@@ -10063,7 +10092,7 @@ fn bindgen_test_layout__GIOChannel() {
         concat!("Alignment of ", stringify!(_GIOChannel))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GIOChannel>())).ref_count as *const _ as usize },
+        ::std::mem::offset_of!(_GIOChannel, ref_count),
         0usize,
         concat!(
             "Offset of field: ",
@@ -10073,7 +10102,7 @@ fn bindgen_test_layout__GIOChannel() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GIOChannel>())).funcs as *const _ as usize },
+        ::std::mem::offset_of!(_GIOChannel, funcs),
         8usize,
         concat!(
             "Offset of field: ",
@@ -10083,7 +10112,7 @@ fn bindgen_test_layout__GIOChannel() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GIOChannel>())).encoding as *const _ as usize },
+        ::std::mem::offset_of!(_GIOChannel, encoding),
         16usize,
         concat!(
             "Offset of field: ",
@@ -10093,7 +10122,7 @@ fn bindgen_test_layout__GIOChannel() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GIOChannel>())).read_cd as *const _ as usize },
+        ::std::mem::offset_of!(_GIOChannel, read_cd),
         24usize,
         concat!(
             "Offset of field: ",
@@ -10103,7 +10132,7 @@ fn bindgen_test_layout__GIOChannel() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GIOChannel>())).write_cd as *const _ as usize },
+        ::std::mem::offset_of!(_GIOChannel, write_cd),
         32usize,
         concat!(
             "Offset of field: ",
@@ -10113,7 +10142,7 @@ fn bindgen_test_layout__GIOChannel() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GIOChannel>())).line_term as *const _ as usize },
+        ::std::mem::offset_of!(_GIOChannel, line_term),
         40usize,
         concat!(
             "Offset of field: ",
@@ -10123,7 +10152,7 @@ fn bindgen_test_layout__GIOChannel() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GIOChannel>())).line_term_len as *const _ as usize },
+        ::std::mem::offset_of!(_GIOChannel, line_term_len),
         48usize,
         concat!(
             "Offset of field: ",
@@ -10133,7 +10162,7 @@ fn bindgen_test_layout__GIOChannel() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GIOChannel>())).buf_size as *const _ as usize },
+        ::std::mem::offset_of!(_GIOChannel, buf_size),
         56usize,
         concat!(
             "Offset of field: ",
@@ -10143,7 +10172,7 @@ fn bindgen_test_layout__GIOChannel() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GIOChannel>())).read_buf as *const _ as usize },
+        ::std::mem::offset_of!(_GIOChannel, read_buf),
         64usize,
         concat!(
             "Offset of field: ",
@@ -10153,7 +10182,7 @@ fn bindgen_test_layout__GIOChannel() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GIOChannel>())).encoded_read_buf as *const _ as usize },
+        ::std::mem::offset_of!(_GIOChannel, encoded_read_buf),
         72usize,
         concat!(
             "Offset of field: ",
@@ -10163,7 +10192,7 @@ fn bindgen_test_layout__GIOChannel() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GIOChannel>())).write_buf as *const _ as usize },
+        ::std::mem::offset_of!(_GIOChannel, write_buf),
         80usize,
         concat!(
             "Offset of field: ",
@@ -10173,7 +10202,7 @@ fn bindgen_test_layout__GIOChannel() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GIOChannel>())).partial_write_buf as *const _ as usize },
+        ::std::mem::offset_of!(_GIOChannel, partial_write_buf),
         88usize,
         concat!(
             "Offset of field: ",
@@ -10183,7 +10212,7 @@ fn bindgen_test_layout__GIOChannel() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GIOChannel>())).reserved1 as *const _ as usize },
+        ::std::mem::offset_of!(_GIOChannel, reserved1),
         96usize,
         concat!(
             "Offset of field: ",
@@ -10193,7 +10222,7 @@ fn bindgen_test_layout__GIOChannel() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GIOChannel>())).reserved2 as *const _ as usize },
+        ::std::mem::offset_of!(_GIOChannel, reserved2),
         104usize,
         concat!(
             "Offset of field: ",
@@ -10343,7 +10372,7 @@ fn bindgen_test_layout__GIOFuncs() {
         concat!("Alignment of ", stringify!(_GIOFuncs))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GIOFuncs>())).io_read as *const _ as usize },
+        ::std::mem::offset_of!(_GIOFuncs, io_read),
         0usize,
         concat!(
             "Offset of field: ",
@@ -10353,7 +10382,7 @@ fn bindgen_test_layout__GIOFuncs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GIOFuncs>())).io_write as *const _ as usize },
+        ::std::mem::offset_of!(_GIOFuncs, io_write),
         8usize,
         concat!(
             "Offset of field: ",
@@ -10363,7 +10392,7 @@ fn bindgen_test_layout__GIOFuncs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GIOFuncs>())).io_seek as *const _ as usize },
+        ::std::mem::offset_of!(_GIOFuncs, io_seek),
         16usize,
         concat!(
             "Offset of field: ",
@@ -10373,7 +10402,7 @@ fn bindgen_test_layout__GIOFuncs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GIOFuncs>())).io_close as *const _ as usize },
+        ::std::mem::offset_of!(_GIOFuncs, io_close),
         24usize,
         concat!(
             "Offset of field: ",
@@ -10383,7 +10412,7 @@ fn bindgen_test_layout__GIOFuncs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GIOFuncs>())).io_create_watch as *const _ as usize },
+        ::std::mem::offset_of!(_GIOFuncs, io_create_watch),
         32usize,
         concat!(
             "Offset of field: ",
@@ -10393,7 +10422,7 @@ fn bindgen_test_layout__GIOFuncs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GIOFuncs>())).io_free as *const _ as usize },
+        ::std::mem::offset_of!(_GIOFuncs, io_free),
         40usize,
         concat!(
             "Offset of field: ",
@@ -10403,7 +10432,7 @@ fn bindgen_test_layout__GIOFuncs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GIOFuncs>())).io_set_flags as *const _ as usize },
+        ::std::mem::offset_of!(_GIOFuncs, io_set_flags),
         48usize,
         concat!(
             "Offset of field: ",
@@ -10413,7 +10442,7 @@ fn bindgen_test_layout__GIOFuncs() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GIOFuncs>())).io_get_flags as *const _ as usize },
+        ::std::mem::offset_of!(_GIOFuncs, io_get_flags),
         56usize,
         concat!(
             "Offset of field: ",
@@ -11006,7 +11035,7 @@ fn bindgen_test_layout__GMarkupParser() {
         concat!("Alignment of ", stringify!(_GMarkupParser))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GMarkupParser>())).start_element as *const _ as usize },
+        ::std::mem::offset_of!(_GMarkupParser, start_element),
         0usize,
         concat!(
             "Offset of field: ",
@@ -11016,7 +11045,7 @@ fn bindgen_test_layout__GMarkupParser() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GMarkupParser>())).end_element as *const _ as usize },
+        ::std::mem::offset_of!(_GMarkupParser, end_element),
         8usize,
         concat!(
             "Offset of field: ",
@@ -11026,7 +11055,7 @@ fn bindgen_test_layout__GMarkupParser() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GMarkupParser>())).text as *const _ as usize },
+        ::std::mem::offset_of!(_GMarkupParser, text),
         16usize,
         concat!(
             "Offset of field: ",
@@ -11036,7 +11065,7 @@ fn bindgen_test_layout__GMarkupParser() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GMarkupParser>())).passthrough as *const _ as usize },
+        ::std::mem::offset_of!(_GMarkupParser, passthrough),
         24usize,
         concat!(
             "Offset of field: ",
@@ -11046,7 +11075,7 @@ fn bindgen_test_layout__GMarkupParser() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GMarkupParser>())).error as *const _ as usize },
+        ::std::mem::offset_of!(_GMarkupParser, error),
         32usize,
         concat!(
             "Offset of field: ",
@@ -11384,7 +11413,7 @@ fn bindgen_test_layout__GOptionEntry() {
         concat!("Alignment of ", stringify!(_GOptionEntry))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GOptionEntry>())).long_name as *const _ as usize },
+        ::std::mem::offset_of!(_GOptionEntry, long_name),
         0usize,
         concat!(
             "Offset of field: ",
@@ -11394,7 +11423,7 @@ fn bindgen_test_layout__GOptionEntry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GOptionEntry>())).short_name as *const _ as usize },
+        ::std::mem::offset_of!(_GOptionEntry, short_name),
         8usize,
         concat!(
             "Offset of field: ",
@@ -11404,7 +11433,7 @@ fn bindgen_test_layout__GOptionEntry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GOptionEntry>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(_GOptionEntry, flags),
         12usize,
         concat!(
             "Offset of field: ",
@@ -11414,7 +11443,7 @@ fn bindgen_test_layout__GOptionEntry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GOptionEntry>())).arg as *const _ as usize },
+        ::std::mem::offset_of!(_GOptionEntry, arg),
         16usize,
         concat!(
             "Offset of field: ",
@@ -11424,7 +11453,7 @@ fn bindgen_test_layout__GOptionEntry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GOptionEntry>())).arg_data as *const _ as usize },
+        ::std::mem::offset_of!(_GOptionEntry, arg_data),
         24usize,
         concat!(
             "Offset of field: ",
@@ -11434,7 +11463,7 @@ fn bindgen_test_layout__GOptionEntry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GOptionEntry>())).description as *const _ as usize },
+        ::std::mem::offset_of!(_GOptionEntry, description),
         32usize,
         concat!(
             "Offset of field: ",
@@ -11444,7 +11473,7 @@ fn bindgen_test_layout__GOptionEntry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GOptionEntry>())).arg_description as *const _ as usize },
+        ::std::mem::offset_of!(_GOptionEntry, arg_description),
         40usize,
         concat!(
             "Offset of field: ",
@@ -11624,7 +11653,7 @@ fn bindgen_test_layout__GQueue() {
         concat!("Alignment of ", stringify!(_GQueue))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GQueue>())).head as *const _ as usize },
+        ::std::mem::offset_of!(_GQueue, head),
         0usize,
         concat!(
             "Offset of field: ",
@@ -11634,7 +11663,7 @@ fn bindgen_test_layout__GQueue() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GQueue>())).tail as *const _ as usize },
+        ::std::mem::offset_of!(_GQueue, tail),
         8usize,
         concat!(
             "Offset of field: ",
@@ -11644,7 +11673,7 @@ fn bindgen_test_layout__GQueue() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GQueue>())).length as *const _ as usize },
+        ::std::mem::offset_of!(_GQueue, length),
         16usize,
         concat!(
             "Offset of field: ",
@@ -12234,7 +12263,7 @@ fn bindgen_test_layout__GTokenValue() {
         concat!("Alignment of ", stringify!(_GTokenValue))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GTokenValue>())).v_symbol as *const _ as usize },
+        ::std::mem::offset_of!(_GTokenValue, v_symbol),
         0usize,
         concat!(
             "Offset of field: ",
@@ -12244,7 +12273,7 @@ fn bindgen_test_layout__GTokenValue() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GTokenValue>())).v_identifier as *const _ as usize },
+        ::std::mem::offset_of!(_GTokenValue, v_identifier),
         0usize,
         concat!(
             "Offset of field: ",
@@ -12254,7 +12283,7 @@ fn bindgen_test_layout__GTokenValue() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GTokenValue>())).v_binary as *const _ as usize },
+        ::std::mem::offset_of!(_GTokenValue, v_binary),
         0usize,
         concat!(
             "Offset of field: ",
@@ -12264,7 +12293,7 @@ fn bindgen_test_layout__GTokenValue() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GTokenValue>())).v_octal as *const _ as usize },
+        ::std::mem::offset_of!(_GTokenValue, v_octal),
         0usize,
         concat!(
             "Offset of field: ",
@@ -12274,7 +12303,7 @@ fn bindgen_test_layout__GTokenValue() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GTokenValue>())).v_int as *const _ as usize },
+        ::std::mem::offset_of!(_GTokenValue, v_int),
         0usize,
         concat!(
             "Offset of field: ",
@@ -12284,7 +12313,7 @@ fn bindgen_test_layout__GTokenValue() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GTokenValue>())).v_int64 as *const _ as usize },
+        ::std::mem::offset_of!(_GTokenValue, v_int64),
         0usize,
         concat!(
             "Offset of field: ",
@@ -12294,7 +12323,7 @@ fn bindgen_test_layout__GTokenValue() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GTokenValue>())).v_float as *const _ as usize },
+        ::std::mem::offset_of!(_GTokenValue, v_float),
         0usize,
         concat!(
             "Offset of field: ",
@@ -12304,7 +12333,7 @@ fn bindgen_test_layout__GTokenValue() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GTokenValue>())).v_hex as *const _ as usize },
+        ::std::mem::offset_of!(_GTokenValue, v_hex),
         0usize,
         concat!(
             "Offset of field: ",
@@ -12314,7 +12343,7 @@ fn bindgen_test_layout__GTokenValue() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GTokenValue>())).v_string as *const _ as usize },
+        ::std::mem::offset_of!(_GTokenValue, v_string),
         0usize,
         concat!(
             "Offset of field: ",
@@ -12324,7 +12353,7 @@ fn bindgen_test_layout__GTokenValue() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GTokenValue>())).v_comment as *const _ as usize },
+        ::std::mem::offset_of!(_GTokenValue, v_comment),
         0usize,
         concat!(
             "Offset of field: ",
@@ -12334,7 +12363,7 @@ fn bindgen_test_layout__GTokenValue() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GTokenValue>())).v_char as *const _ as usize },
+        ::std::mem::offset_of!(_GTokenValue, v_char),
         0usize,
         concat!(
             "Offset of field: ",
@@ -12344,7 +12373,7 @@ fn bindgen_test_layout__GTokenValue() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GTokenValue>())).v_error as *const _ as usize },
+        ::std::mem::offset_of!(_GTokenValue, v_error),
         0usize,
         concat!(
             "Offset of field: ",
@@ -12377,7 +12406,7 @@ fn bindgen_test_layout__GScannerConfig() {
         concat!("Alignment of ", stringify!(_GScannerConfig))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GScannerConfig>())).cset_skip_characters as *const _ as usize },
+        ::std::mem::offset_of!(_GScannerConfig, cset_skip_characters),
         0usize,
         concat!(
             "Offset of field: ",
@@ -12387,7 +12416,7 @@ fn bindgen_test_layout__GScannerConfig() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GScannerConfig>())).cset_identifier_first as *const _ as usize },
+        ::std::mem::offset_of!(_GScannerConfig, cset_identifier_first),
         8usize,
         concat!(
             "Offset of field: ",
@@ -12397,7 +12426,7 @@ fn bindgen_test_layout__GScannerConfig() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GScannerConfig>())).cset_identifier_nth as *const _ as usize },
+        ::std::mem::offset_of!(_GScannerConfig, cset_identifier_nth),
         16usize,
         concat!(
             "Offset of field: ",
@@ -12407,7 +12436,7 @@ fn bindgen_test_layout__GScannerConfig() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GScannerConfig>())).cpair_comment_single as *const _ as usize },
+        ::std::mem::offset_of!(_GScannerConfig, cpair_comment_single),
         24usize,
         concat!(
             "Offset of field: ",
@@ -12417,7 +12446,7 @@ fn bindgen_test_layout__GScannerConfig() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GScannerConfig>())).padding_dummy as *const _ as usize },
+        ::std::mem::offset_of!(_GScannerConfig, padding_dummy),
         36usize,
         concat!(
             "Offset of field: ",
@@ -12825,7 +12854,7 @@ fn bindgen_test_layout__GScanner() {
         concat!("Alignment of ", stringify!(_GScanner))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GScanner>())).user_data as *const _ as usize },
+        ::std::mem::offset_of!(_GScanner, user_data),
         0usize,
         concat!(
             "Offset of field: ",
@@ -12835,7 +12864,7 @@ fn bindgen_test_layout__GScanner() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GScanner>())).max_parse_errors as *const _ as usize },
+        ::std::mem::offset_of!(_GScanner, max_parse_errors),
         8usize,
         concat!(
             "Offset of field: ",
@@ -12845,7 +12874,7 @@ fn bindgen_test_layout__GScanner() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GScanner>())).parse_errors as *const _ as usize },
+        ::std::mem::offset_of!(_GScanner, parse_errors),
         12usize,
         concat!(
             "Offset of field: ",
@@ -12855,7 +12884,7 @@ fn bindgen_test_layout__GScanner() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GScanner>())).input_name as *const _ as usize },
+        ::std::mem::offset_of!(_GScanner, input_name),
         16usize,
         concat!(
             "Offset of field: ",
@@ -12865,7 +12894,7 @@ fn bindgen_test_layout__GScanner() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GScanner>())).qdata as *const _ as usize },
+        ::std::mem::offset_of!(_GScanner, qdata),
         24usize,
         concat!(
             "Offset of field: ",
@@ -12875,7 +12904,7 @@ fn bindgen_test_layout__GScanner() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GScanner>())).config as *const _ as usize },
+        ::std::mem::offset_of!(_GScanner, config),
         32usize,
         concat!(
             "Offset of field: ",
@@ -12885,7 +12914,7 @@ fn bindgen_test_layout__GScanner() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GScanner>())).token as *const _ as usize },
+        ::std::mem::offset_of!(_GScanner, token),
         40usize,
         concat!(
             "Offset of field: ",
@@ -12895,7 +12924,7 @@ fn bindgen_test_layout__GScanner() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GScanner>())).value as *const _ as usize },
+        ::std::mem::offset_of!(_GScanner, value),
         48usize,
         concat!(
             "Offset of field: ",
@@ -12905,7 +12934,7 @@ fn bindgen_test_layout__GScanner() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GScanner>())).line as *const _ as usize },
+        ::std::mem::offset_of!(_GScanner, line),
         56usize,
         concat!(
             "Offset of field: ",
@@ -12915,7 +12944,7 @@ fn bindgen_test_layout__GScanner() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GScanner>())).position as *const _ as usize },
+        ::std::mem::offset_of!(_GScanner, position),
         60usize,
         concat!(
             "Offset of field: ",
@@ -12925,7 +12954,7 @@ fn bindgen_test_layout__GScanner() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GScanner>())).next_token as *const _ as usize },
+        ::std::mem::offset_of!(_GScanner, next_token),
         64usize,
         concat!(
             "Offset of field: ",
@@ -12935,7 +12964,7 @@ fn bindgen_test_layout__GScanner() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GScanner>())).next_value as *const _ as usize },
+        ::std::mem::offset_of!(_GScanner, next_value),
         72usize,
         concat!(
             "Offset of field: ",
@@ -12945,7 +12974,7 @@ fn bindgen_test_layout__GScanner() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GScanner>())).next_line as *const _ as usize },
+        ::std::mem::offset_of!(_GScanner, next_line),
         80usize,
         concat!(
             "Offset of field: ",
@@ -12955,7 +12984,7 @@ fn bindgen_test_layout__GScanner() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GScanner>())).next_position as *const _ as usize },
+        ::std::mem::offset_of!(_GScanner, next_position),
         84usize,
         concat!(
             "Offset of field: ",
@@ -12965,7 +12994,7 @@ fn bindgen_test_layout__GScanner() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GScanner>())).symbol_table as *const _ as usize },
+        ::std::mem::offset_of!(_GScanner, symbol_table),
         88usize,
         concat!(
             "Offset of field: ",
@@ -12975,7 +13004,7 @@ fn bindgen_test_layout__GScanner() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GScanner>())).input_fd as *const _ as usize },
+        ::std::mem::offset_of!(_GScanner, input_fd),
         96usize,
         concat!(
             "Offset of field: ",
@@ -12985,7 +13014,7 @@ fn bindgen_test_layout__GScanner() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GScanner>())).text as *const _ as usize },
+        ::std::mem::offset_of!(_GScanner, text),
         104usize,
         concat!(
             "Offset of field: ",
@@ -12995,7 +13024,7 @@ fn bindgen_test_layout__GScanner() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GScanner>())).text_end as *const _ as usize },
+        ::std::mem::offset_of!(_GScanner, text_end),
         112usize,
         concat!(
             "Offset of field: ",
@@ -13005,7 +13034,7 @@ fn bindgen_test_layout__GScanner() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GScanner>())).buffer as *const _ as usize },
+        ::std::mem::offset_of!(_GScanner, buffer),
         120usize,
         concat!(
             "Offset of field: ",
@@ -13015,7 +13044,7 @@ fn bindgen_test_layout__GScanner() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GScanner>())).scope_id as *const _ as usize },
+        ::std::mem::offset_of!(_GScanner, scope_id),
         128usize,
         concat!(
             "Offset of field: ",
@@ -13025,7 +13054,7 @@ fn bindgen_test_layout__GScanner() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GScanner>())).msg_handler as *const _ as usize },
+        ::std::mem::offset_of!(_GScanner, msg_handler),
         136usize,
         concat!(
             "Offset of field: ",
@@ -13883,7 +13912,7 @@ fn bindgen_test_layout_GTestConfig() {
         concat!("Alignment of ", stringify!(GTestConfig))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<GTestConfig>())).test_initialized as *const _ as usize },
+        ::std::mem::offset_of!(GTestConfig, test_initialized),
         0usize,
         concat!(
             "Offset of field: ",
@@ -13893,7 +13922,7 @@ fn bindgen_test_layout_GTestConfig() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<GTestConfig>())).test_quick as *const _ as usize },
+        ::std::mem::offset_of!(GTestConfig, test_quick),
         4usize,
         concat!(
             "Offset of field: ",
@@ -13903,7 +13932,7 @@ fn bindgen_test_layout_GTestConfig() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<GTestConfig>())).test_perf as *const _ as usize },
+        ::std::mem::offset_of!(GTestConfig, test_perf),
         8usize,
         concat!(
             "Offset of field: ",
@@ -13913,7 +13942,7 @@ fn bindgen_test_layout_GTestConfig() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<GTestConfig>())).test_verbose as *const _ as usize },
+        ::std::mem::offset_of!(GTestConfig, test_verbose),
         12usize,
         concat!(
             "Offset of field: ",
@@ -13923,7 +13952,7 @@ fn bindgen_test_layout_GTestConfig() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<GTestConfig>())).test_quiet as *const _ as usize },
+        ::std::mem::offset_of!(GTestConfig, test_quiet),
         16usize,
         concat!(
             "Offset of field: ",
@@ -13933,7 +13962,7 @@ fn bindgen_test_layout_GTestConfig() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<GTestConfig>())).test_undefined as *const _ as usize },
+        ::std::mem::offset_of!(GTestConfig, test_undefined),
         20usize,
         concat!(
             "Offset of field: ",
@@ -13981,7 +14010,7 @@ fn bindgen_test_layout_GTestLogMsg() {
         concat!("Alignment of ", stringify!(GTestLogMsg))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<GTestLogMsg>())).log_type as *const _ as usize },
+        ::std::mem::offset_of!(GTestLogMsg, log_type),
         0usize,
         concat!(
             "Offset of field: ",
@@ -13991,7 +14020,7 @@ fn bindgen_test_layout_GTestLogMsg() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<GTestLogMsg>())).n_strings as *const _ as usize },
+        ::std::mem::offset_of!(GTestLogMsg, n_strings),
         4usize,
         concat!(
             "Offset of field: ",
@@ -14001,7 +14030,7 @@ fn bindgen_test_layout_GTestLogMsg() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<GTestLogMsg>())).strings as *const _ as usize },
+        ::std::mem::offset_of!(GTestLogMsg, strings),
         8usize,
         concat!(
             "Offset of field: ",
@@ -14011,7 +14040,7 @@ fn bindgen_test_layout_GTestLogMsg() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<GTestLogMsg>())).n_nums as *const _ as usize },
+        ::std::mem::offset_of!(GTestLogMsg, n_nums),
         16usize,
         concat!(
             "Offset of field: ",
@@ -14021,7 +14050,7 @@ fn bindgen_test_layout_GTestLogMsg() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<GTestLogMsg>())).nums as *const _ as usize },
+        ::std::mem::offset_of!(GTestLogMsg, nums),
         24usize,
         concat!(
             "Offset of field: ",
@@ -14050,7 +14079,7 @@ fn bindgen_test_layout_GTestLogBuffer() {
         concat!("Alignment of ", stringify!(GTestLogBuffer))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<GTestLogBuffer>())).data as *const _ as usize },
+        ::std::mem::offset_of!(GTestLogBuffer, data),
         0usize,
         concat!(
             "Offset of field: ",
@@ -14060,7 +14089,7 @@ fn bindgen_test_layout_GTestLogBuffer() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<GTestLogBuffer>())).msgs as *const _ as usize },
+        ::std::mem::offset_of!(GTestLogBuffer, msgs),
         8usize,
         concat!(
             "Offset of field: ",
@@ -14149,7 +14178,7 @@ fn bindgen_test_layout__GThreadPool() {
         concat!("Alignment of ", stringify!(_GThreadPool))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GThreadPool>())).func as *const _ as usize },
+        ::std::mem::offset_of!(_GThreadPool, func),
         0usize,
         concat!(
             "Offset of field: ",
@@ -14159,7 +14188,7 @@ fn bindgen_test_layout__GThreadPool() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GThreadPool>())).user_data as *const _ as usize },
+        ::std::mem::offset_of!(_GThreadPool, user_data),
         8usize,
         concat!(
             "Offset of field: ",
@@ -14169,7 +14198,7 @@ fn bindgen_test_layout__GThreadPool() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GThreadPool>())).exclusive as *const _ as usize },
+        ::std::mem::offset_of!(_GThreadPool, exclusive),
         16usize,
         concat!(
             "Offset of field: ",
@@ -14288,7 +14317,7 @@ fn bindgen_test_layout__GTrashStack() {
         concat!("Alignment of ", stringify!(_GTrashStack))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GTrashStack>())).next as *const _ as usize },
+        ::std::mem::offset_of!(_GTrashStack, next),
         0usize,
         concat!(
             "Offset of field: ",
@@ -14789,7 +14818,7 @@ fn bindgen_test_layout__GVariantIter() {
         concat!("Alignment of ", stringify!(_GVariantIter))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GVariantIter>())).x as *const _ as usize },
+        ::std::mem::offset_of!(_GVariantIter, x),
         0usize,
         concat!(
             "Offset of field: ",
@@ -14842,7 +14871,7 @@ fn bindgen_test_layout__GVariantBuilder() {
         concat!("Alignment of ", stringify!(_GVariantBuilder))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GVariantBuilder>())).x as *const _ as usize },
+        ::std::mem::offset_of!(_GVariantBuilder, x),
         0usize,
         concat!(
             "Offset of field: ",
@@ -14965,7 +14994,7 @@ fn bindgen_test_layout__GVariantDict() {
         concat!("Alignment of ", stringify!(_GVariantDict))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GVariantDict>())).x as *const _ as usize },
+        ::std::mem::offset_of!(_GVariantDict, x),
         0usize,
         concat!(
             "Offset of field: ",
@@ -15155,7 +15184,7 @@ fn bindgen_test_layout__GCompletion() {
         concat!("Alignment of ", stringify!(_GCompletion))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GCompletion>())).items as *const _ as usize },
+        ::std::mem::offset_of!(_GCompletion, items),
         0usize,
         concat!(
             "Offset of field: ",
@@ -15165,7 +15194,7 @@ fn bindgen_test_layout__GCompletion() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GCompletion>())).func as *const _ as usize },
+        ::std::mem::offset_of!(_GCompletion, func),
         8usize,
         concat!(
             "Offset of field: ",
@@ -15175,7 +15204,7 @@ fn bindgen_test_layout__GCompletion() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GCompletion>())).prefix as *const _ as usize },
+        ::std::mem::offset_of!(_GCompletion, prefix),
         16usize,
         concat!(
             "Offset of field: ",
@@ -15185,7 +15214,7 @@ fn bindgen_test_layout__GCompletion() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GCompletion>())).cache as *const _ as usize },
+        ::std::mem::offset_of!(_GCompletion, cache),
         24usize,
         concat!(
             "Offset of field: ",
@@ -15195,7 +15224,7 @@ fn bindgen_test_layout__GCompletion() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GCompletion>())).strncmp_func as *const _ as usize },
+        ::std::mem::offset_of!(_GCompletion, strncmp_func),
         32usize,
         concat!(
             "Offset of field: ",
@@ -15254,7 +15283,7 @@ fn bindgen_test_layout__GTuples() {
         concat!("Alignment of ", stringify!(_GTuples))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GTuples>())).len as *const _ as usize },
+        ::std::mem::offset_of!(_GTuples, len),
         0usize,
         concat!(
             "Offset of field: ",
@@ -15323,7 +15352,7 @@ fn bindgen_test_layout__GThread() {
         concat!("Alignment of ", stringify!(_GThread))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GThread>())).func as *const _ as usize },
+        ::std::mem::offset_of!(_GThread, func),
         0usize,
         concat!(
             "Offset of field: ",
@@ -15333,7 +15362,7 @@ fn bindgen_test_layout__GThread() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GThread>())).data as *const _ as usize },
+        ::std::mem::offset_of!(_GThread, data),
         8usize,
         concat!(
             "Offset of field: ",
@@ -15343,7 +15372,7 @@ fn bindgen_test_layout__GThread() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GThread>())).joinable as *const _ as usize },
+        ::std::mem::offset_of!(_GThread, joinable),
         16usize,
         concat!(
             "Offset of field: ",
@@ -15353,7 +15382,7 @@ fn bindgen_test_layout__GThread() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GThread>())).priority as *const _ as usize },
+        ::std::mem::offset_of!(_GThread, priority),
         20usize,
         concat!(
             "Offset of field: ",
@@ -15414,7 +15443,7 @@ fn bindgen_test_layout__GThreadFunctions() {
         concat!("Alignment of ", stringify!(_GThreadFunctions))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GThreadFunctions>())).mutex_new as *const _ as usize },
+        ::std::mem::offset_of!(_GThreadFunctions, mutex_new),
         0usize,
         concat!(
             "Offset of field: ",
@@ -15424,7 +15453,7 @@ fn bindgen_test_layout__GThreadFunctions() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GThreadFunctions>())).mutex_lock as *const _ as usize },
+        ::std::mem::offset_of!(_GThreadFunctions, mutex_lock),
         8usize,
         concat!(
             "Offset of field: ",
@@ -15434,7 +15463,7 @@ fn bindgen_test_layout__GThreadFunctions() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GThreadFunctions>())).mutex_trylock as *const _ as usize },
+        ::std::mem::offset_of!(_GThreadFunctions, mutex_trylock),
         16usize,
         concat!(
             "Offset of field: ",
@@ -15444,7 +15473,7 @@ fn bindgen_test_layout__GThreadFunctions() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GThreadFunctions>())).mutex_unlock as *const _ as usize },
+        ::std::mem::offset_of!(_GThreadFunctions, mutex_unlock),
         24usize,
         concat!(
             "Offset of field: ",
@@ -15454,7 +15483,7 @@ fn bindgen_test_layout__GThreadFunctions() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GThreadFunctions>())).mutex_free as *const _ as usize },
+        ::std::mem::offset_of!(_GThreadFunctions, mutex_free),
         32usize,
         concat!(
             "Offset of field: ",
@@ -15464,7 +15493,7 @@ fn bindgen_test_layout__GThreadFunctions() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GThreadFunctions>())).cond_new as *const _ as usize },
+        ::std::mem::offset_of!(_GThreadFunctions, cond_new),
         40usize,
         concat!(
             "Offset of field: ",
@@ -15474,7 +15503,7 @@ fn bindgen_test_layout__GThreadFunctions() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GThreadFunctions>())).cond_signal as *const _ as usize },
+        ::std::mem::offset_of!(_GThreadFunctions, cond_signal),
         48usize,
         concat!(
             "Offset of field: ",
@@ -15484,7 +15513,7 @@ fn bindgen_test_layout__GThreadFunctions() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GThreadFunctions>())).cond_broadcast as *const _ as usize },
+        ::std::mem::offset_of!(_GThreadFunctions, cond_broadcast),
         56usize,
         concat!(
             "Offset of field: ",
@@ -15494,7 +15523,7 @@ fn bindgen_test_layout__GThreadFunctions() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GThreadFunctions>())).cond_wait as *const _ as usize },
+        ::std::mem::offset_of!(_GThreadFunctions, cond_wait),
         64usize,
         concat!(
             "Offset of field: ",
@@ -15504,7 +15533,7 @@ fn bindgen_test_layout__GThreadFunctions() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GThreadFunctions>())).cond_timed_wait as *const _ as usize },
+        ::std::mem::offset_of!(_GThreadFunctions, cond_timed_wait),
         72usize,
         concat!(
             "Offset of field: ",
@@ -15514,7 +15543,7 @@ fn bindgen_test_layout__GThreadFunctions() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GThreadFunctions>())).cond_free as *const _ as usize },
+        ::std::mem::offset_of!(_GThreadFunctions, cond_free),
         80usize,
         concat!(
             "Offset of field: ",
@@ -15524,7 +15553,7 @@ fn bindgen_test_layout__GThreadFunctions() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GThreadFunctions>())).private_new as *const _ as usize },
+        ::std::mem::offset_of!(_GThreadFunctions, private_new),
         88usize,
         concat!(
             "Offset of field: ",
@@ -15534,7 +15563,7 @@ fn bindgen_test_layout__GThreadFunctions() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GThreadFunctions>())).private_get as *const _ as usize },
+        ::std::mem::offset_of!(_GThreadFunctions, private_get),
         96usize,
         concat!(
             "Offset of field: ",
@@ -15544,7 +15573,7 @@ fn bindgen_test_layout__GThreadFunctions() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GThreadFunctions>())).private_set as *const _ as usize },
+        ::std::mem::offset_of!(_GThreadFunctions, private_set),
         104usize,
         concat!(
             "Offset of field: ",
@@ -15554,7 +15583,7 @@ fn bindgen_test_layout__GThreadFunctions() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GThreadFunctions>())).thread_create as *const _ as usize },
+        ::std::mem::offset_of!(_GThreadFunctions, thread_create),
         112usize,
         concat!(
             "Offset of field: ",
@@ -15564,7 +15593,7 @@ fn bindgen_test_layout__GThreadFunctions() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GThreadFunctions>())).thread_yield as *const _ as usize },
+        ::std::mem::offset_of!(_GThreadFunctions, thread_yield),
         120usize,
         concat!(
             "Offset of field: ",
@@ -15574,7 +15603,7 @@ fn bindgen_test_layout__GThreadFunctions() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GThreadFunctions>())).thread_join as *const _ as usize },
+        ::std::mem::offset_of!(_GThreadFunctions, thread_join),
         128usize,
         concat!(
             "Offset of field: ",
@@ -15584,7 +15613,7 @@ fn bindgen_test_layout__GThreadFunctions() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GThreadFunctions>())).thread_exit as *const _ as usize },
+        ::std::mem::offset_of!(_GThreadFunctions, thread_exit),
         136usize,
         concat!(
             "Offset of field: ",
@@ -15594,7 +15623,7 @@ fn bindgen_test_layout__GThreadFunctions() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GThreadFunctions>())).thread_set_priority as *const _ as usize },
+        ::std::mem::offset_of!(_GThreadFunctions, thread_set_priority),
         144usize,
         concat!(
             "Offset of field: ",
@@ -15604,7 +15633,7 @@ fn bindgen_test_layout__GThreadFunctions() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GThreadFunctions>())).thread_self as *const _ as usize },
+        ::std::mem::offset_of!(_GThreadFunctions, thread_self),
         152usize,
         concat!(
             "Offset of field: ",
@@ -15614,7 +15643,7 @@ fn bindgen_test_layout__GThreadFunctions() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GThreadFunctions>())).thread_equal as *const _ as usize },
+        ::std::mem::offset_of!(_GThreadFunctions, thread_equal),
         160usize,
         concat!(
             "Offset of field: ",
@@ -15671,7 +15700,7 @@ fn bindgen_test_layout_sched_param() {
         concat!("Alignment of ", stringify!(sched_param))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<sched_param>())).__sched_priority as *const _ as usize },
+        ::std::mem::offset_of!(sched_param, __sched_priority),
         0usize,
         concat!(
             "Offset of field: ",
@@ -15699,7 +15728,7 @@ fn bindgen_test_layout___sched_param() {
         concat!("Alignment of ", stringify!(__sched_param))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<__sched_param>())).__sched_priority as *const _ as usize },
+        ::std::mem::offset_of!(__sched_param, __sched_priority),
         0usize,
         concat!(
             "Offset of field: ",
@@ -15728,7 +15757,7 @@ fn bindgen_test_layout_cpu_set_t() {
         concat!("Alignment of ", stringify!(cpu_set_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<cpu_set_t>())).__bits as *const _ as usize },
+        ::std::mem::offset_of!(cpu_set_t, __bits),
         0usize,
         concat!(
             "Offset of field: ",
@@ -15828,7 +15857,7 @@ fn bindgen_test_layout__pthread_cleanup_buffer() {
         concat!("Alignment of ", stringify!(_pthread_cleanup_buffer))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_pthread_cleanup_buffer>())).__routine as *const _ as usize },
+        ::std::mem::offset_of!(_pthread_cleanup_buffer, __routine),
         0usize,
         concat!(
             "Offset of field: ",
@@ -15838,7 +15867,7 @@ fn bindgen_test_layout__pthread_cleanup_buffer() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_pthread_cleanup_buffer>())).__arg as *const _ as usize },
+        ::std::mem::offset_of!(_pthread_cleanup_buffer, __arg),
         8usize,
         concat!(
             "Offset of field: ",
@@ -15848,7 +15877,7 @@ fn bindgen_test_layout__pthread_cleanup_buffer() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_pthread_cleanup_buffer>())).__canceltype as *const _ as usize },
+        ::std::mem::offset_of!(_pthread_cleanup_buffer, __canceltype),
         16usize,
         concat!(
             "Offset of field: ",
@@ -15858,7 +15887,7 @@ fn bindgen_test_layout__pthread_cleanup_buffer() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_pthread_cleanup_buffer>())).__prev as *const _ as usize },
+        ::std::mem::offset_of!(_pthread_cleanup_buffer, __prev),
         24usize,
         concat!(
             "Offset of field: ",
@@ -16032,7 +16061,7 @@ fn bindgen_test_layout___pthread_unwind_buf_t__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<__pthread_unwind_buf_t__bindgen_ty_1>())).__cancel_jmp_buf as *const _ as usize },
+        ::std::mem::offset_of!(__pthread_unwind_buf_t__bindgen_ty_1, __cancel_jmp_buf),
         0usize,
         concat!(
             "Offset of field: ",
@@ -16042,7 +16071,7 @@ fn bindgen_test_layout___pthread_unwind_buf_t__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<__pthread_unwind_buf_t__bindgen_ty_1>())).__mask_was_saved as *const _ as usize },
+        ::std::mem::offset_of!(__pthread_unwind_buf_t__bindgen_ty_1, __mask_was_saved),
         64usize,
         concat!(
             "Offset of field: ",
@@ -16065,7 +16094,7 @@ fn bindgen_test_layout___pthread_unwind_buf_t() {
         concat!("Alignment of ", stringify!(__pthread_unwind_buf_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<__pthread_unwind_buf_t>())).__cancel_jmp_buf as *const _ as usize },
+        ::std::mem::offset_of!(__pthread_unwind_buf_t, __cancel_jmp_buf),
         0usize,
         concat!(
             "Offset of field: ",
@@ -16075,7 +16104,7 @@ fn bindgen_test_layout___pthread_unwind_buf_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<__pthread_unwind_buf_t>())).__pad as *const _ as usize },
+        ::std::mem::offset_of!(__pthread_unwind_buf_t, __pad),
         72usize,
         concat!(
             "Offset of field: ",
@@ -16106,7 +16135,7 @@ fn bindgen_test_layout___pthread_cleanup_frame() {
         concat!("Alignment of ", stringify!(__pthread_cleanup_frame))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<__pthread_cleanup_frame>())).__cancel_routine as *const _ as usize },
+        ::std::mem::offset_of!(__pthread_cleanup_frame, __cancel_routine),
         0usize,
         concat!(
             "Offset of field: ",
@@ -16116,7 +16145,7 @@ fn bindgen_test_layout___pthread_cleanup_frame() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<__pthread_cleanup_frame>())).__cancel_arg as *const _ as usize },
+        ::std::mem::offset_of!(__pthread_cleanup_frame, __cancel_arg),
         8usize,
         concat!(
             "Offset of field: ",
@@ -16126,7 +16155,7 @@ fn bindgen_test_layout___pthread_cleanup_frame() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<__pthread_cleanup_frame>())).__do_it as *const _ as usize },
+        ::std::mem::offset_of!(__pthread_cleanup_frame, __do_it),
         16usize,
         concat!(
             "Offset of field: ",
@@ -16136,7 +16165,7 @@ fn bindgen_test_layout___pthread_cleanup_frame() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<__pthread_cleanup_frame>())).__cancel_type as *const _ as usize },
+        ::std::mem::offset_of!(__pthread_cleanup_frame, __cancel_type),
         20usize,
         concat!(
             "Offset of field: ",
@@ -16421,7 +16450,7 @@ fn bindgen_test_layout_GStaticMutex() {
         concat!("Alignment of ", stringify!(GStaticMutex))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<GStaticMutex>())).mutex as *const _ as usize },
+        ::std::mem::offset_of!(GStaticMutex, mutex),
         0usize,
         concat!(
             "Offset of field: ",
@@ -16431,7 +16460,7 @@ fn bindgen_test_layout_GStaticMutex() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<GStaticMutex>())).unused as *const _ as usize },
+        ::std::mem::offset_of!(GStaticMutex, unused),
         8usize,
         concat!(
             "Offset of field: ",
@@ -16478,7 +16507,7 @@ fn bindgen_test_layout__GStaticRecMutex__bindgen_ty_1() {
         concat!("Alignment of ", stringify!(_GStaticRecMutex__bindgen_ty_1))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GStaticRecMutex__bindgen_ty_1>())).owner as *const _ as usize },
+        ::std::mem::offset_of!(_GStaticRecMutex__bindgen_ty_1, owner),
         0usize,
         concat!(
             "Offset of field: ",
@@ -16488,7 +16517,7 @@ fn bindgen_test_layout__GStaticRecMutex__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GStaticRecMutex__bindgen_ty_1>())).dummy as *const _ as usize },
+        ::std::mem::offset_of!(_GStaticRecMutex__bindgen_ty_1, dummy),
         0usize,
         concat!(
             "Offset of field: ",
@@ -16511,7 +16540,7 @@ fn bindgen_test_layout__GStaticRecMutex() {
         concat!("Alignment of ", stringify!(_GStaticRecMutex))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GStaticRecMutex>())).mutex as *const _ as usize },
+        ::std::mem::offset_of!(_GStaticRecMutex, mutex),
         0usize,
         concat!(
             "Offset of field: ",
@@ -16521,7 +16550,7 @@ fn bindgen_test_layout__GStaticRecMutex() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GStaticRecMutex>())).depth as *const _ as usize },
+        ::std::mem::offset_of!(_GStaticRecMutex, depth),
         48usize,
         concat!(
             "Offset of field: ",
@@ -16531,7 +16560,7 @@ fn bindgen_test_layout__GStaticRecMutex() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GStaticRecMutex>())).unused as *const _ as usize },
+        ::std::mem::offset_of!(_GStaticRecMutex, unused),
         56usize,
         concat!(
             "Offset of field: ",
@@ -16587,7 +16616,7 @@ fn bindgen_test_layout__GStaticRWLock() {
         concat!("Alignment of ", stringify!(_GStaticRWLock))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GStaticRWLock>())).mutex as *const _ as usize },
+        ::std::mem::offset_of!(_GStaticRWLock, mutex),
         0usize,
         concat!(
             "Offset of field: ",
@@ -16597,7 +16626,7 @@ fn bindgen_test_layout__GStaticRWLock() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GStaticRWLock>())).read_cond as *const _ as usize },
+        ::std::mem::offset_of!(_GStaticRWLock, read_cond),
         48usize,
         concat!(
             "Offset of field: ",
@@ -16607,7 +16636,7 @@ fn bindgen_test_layout__GStaticRWLock() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GStaticRWLock>())).write_cond as *const _ as usize },
+        ::std::mem::offset_of!(_GStaticRWLock, write_cond),
         56usize,
         concat!(
             "Offset of field: ",
@@ -16617,7 +16646,7 @@ fn bindgen_test_layout__GStaticRWLock() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GStaticRWLock>())).read_counter as *const _ as usize },
+        ::std::mem::offset_of!(_GStaticRWLock, read_counter),
         64usize,
         concat!(
             "Offset of field: ",
@@ -16627,7 +16656,7 @@ fn bindgen_test_layout__GStaticRWLock() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GStaticRWLock>())).have_writer as *const _ as usize },
+        ::std::mem::offset_of!(_GStaticRWLock, have_writer),
         68usize,
         concat!(
             "Offset of field: ",
@@ -16637,7 +16666,7 @@ fn bindgen_test_layout__GStaticRWLock() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GStaticRWLock>())).want_to_read as *const _ as usize },
+        ::std::mem::offset_of!(_GStaticRWLock, want_to_read),
         72usize,
         concat!(
             "Offset of field: ",
@@ -16647,7 +16676,7 @@ fn bindgen_test_layout__GStaticRWLock() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GStaticRWLock>())).want_to_write as *const _ as usize },
+        ::std::mem::offset_of!(_GStaticRWLock, want_to_write),
         76usize,
         concat!(
             "Offset of field: ",
@@ -16703,7 +16732,7 @@ fn bindgen_test_layout__GStaticPrivate() {
         concat!("Alignment of ", stringify!(_GStaticPrivate))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GStaticPrivate>())).index as *const _ as usize },
+        ::std::mem::offset_of!(_GStaticPrivate, index),
         0usize,
         concat!(
             "Offset of field: ",
@@ -16841,7 +16870,7 @@ fn bindgen_test_layout__GTypeClass() {
         concat!("Alignment of ", stringify!(_GTypeClass))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GTypeClass>())).g_type as *const _ as usize },
+        ::std::mem::offset_of!(_GTypeClass, g_type),
         0usize,
         concat!(
             "Offset of field: ",
@@ -16872,7 +16901,7 @@ fn bindgen_test_layout__GTypeInstance() {
         concat!("Alignment of ", stringify!(_GTypeInstance))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GTypeInstance>())).g_class as *const _ as usize },
+        ::std::mem::offset_of!(_GTypeInstance, g_class),
         0usize,
         concat!(
             "Offset of field: ",
@@ -16904,7 +16933,7 @@ fn bindgen_test_layout__GTypeInterface() {
         concat!("Alignment of ", stringify!(_GTypeInterface))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GTypeInterface>())).g_type as *const _ as usize },
+        ::std::mem::offset_of!(_GTypeInterface, g_type),
         0usize,
         concat!(
             "Offset of field: ",
@@ -16914,7 +16943,7 @@ fn bindgen_test_layout__GTypeInterface() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GTypeInterface>())).g_instance_type as *const _ as usize },
+        ::std::mem::offset_of!(_GTypeInterface, g_instance_type),
         8usize,
         concat!(
             "Offset of field: ",
@@ -16953,7 +16982,7 @@ fn bindgen_test_layout__GTypeQuery() {
         concat!("Alignment of ", stringify!(_GTypeQuery))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GTypeQuery>())).type_ as *const _ as usize },
+        ::std::mem::offset_of!(_GTypeQuery, type_),
         0usize,
         concat!(
             "Offset of field: ",
@@ -16963,7 +16992,7 @@ fn bindgen_test_layout__GTypeQuery() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GTypeQuery>())).type_name as *const _ as usize },
+        ::std::mem::offset_of!(_GTypeQuery, type_name),
         8usize,
         concat!(
             "Offset of field: ",
@@ -16973,7 +17002,7 @@ fn bindgen_test_layout__GTypeQuery() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GTypeQuery>())).class_size as *const _ as usize },
+        ::std::mem::offset_of!(_GTypeQuery, class_size),
         16usize,
         concat!(
             "Offset of field: ",
@@ -16983,7 +17012,7 @@ fn bindgen_test_layout__GTypeQuery() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GTypeQuery>())).instance_size as *const _ as usize },
+        ::std::mem::offset_of!(_GTypeQuery, instance_size),
         20usize,
         concat!(
             "Offset of field: ",
@@ -17335,7 +17364,7 @@ fn bindgen_test_layout__GTypeInfo() {
         concat!("Alignment of ", stringify!(_GTypeInfo))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GTypeInfo>())).class_size as *const _ as usize },
+        ::std::mem::offset_of!(_GTypeInfo, class_size),
         0usize,
         concat!(
             "Offset of field: ",
@@ -17345,7 +17374,7 @@ fn bindgen_test_layout__GTypeInfo() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GTypeInfo>())).base_init as *const _ as usize },
+        ::std::mem::offset_of!(_GTypeInfo, base_init),
         8usize,
         concat!(
             "Offset of field: ",
@@ -17355,7 +17384,7 @@ fn bindgen_test_layout__GTypeInfo() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GTypeInfo>())).base_finalize as *const _ as usize },
+        ::std::mem::offset_of!(_GTypeInfo, base_finalize),
         16usize,
         concat!(
             "Offset of field: ",
@@ -17365,7 +17394,7 @@ fn bindgen_test_layout__GTypeInfo() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GTypeInfo>())).class_init as *const _ as usize },
+        ::std::mem::offset_of!(_GTypeInfo, class_init),
         24usize,
         concat!(
             "Offset of field: ",
@@ -17375,7 +17404,7 @@ fn bindgen_test_layout__GTypeInfo() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GTypeInfo>())).class_finalize as *const _ as usize },
+        ::std::mem::offset_of!(_GTypeInfo, class_finalize),
         32usize,
         concat!(
             "Offset of field: ",
@@ -17385,7 +17414,7 @@ fn bindgen_test_layout__GTypeInfo() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GTypeInfo>())).class_data as *const _ as usize },
+        ::std::mem::offset_of!(_GTypeInfo, class_data),
         40usize,
         concat!(
             "Offset of field: ",
@@ -17395,7 +17424,7 @@ fn bindgen_test_layout__GTypeInfo() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GTypeInfo>())).instance_size as *const _ as usize },
+        ::std::mem::offset_of!(_GTypeInfo, instance_size),
         48usize,
         concat!(
             "Offset of field: ",
@@ -17405,7 +17434,7 @@ fn bindgen_test_layout__GTypeInfo() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GTypeInfo>())).n_preallocs as *const _ as usize },
+        ::std::mem::offset_of!(_GTypeInfo, n_preallocs),
         50usize,
         concat!(
             "Offset of field: ",
@@ -17415,7 +17444,7 @@ fn bindgen_test_layout__GTypeInfo() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GTypeInfo>())).instance_init as *const _ as usize },
+        ::std::mem::offset_of!(_GTypeInfo, instance_init),
         56usize,
         concat!(
             "Offset of field: ",
@@ -17425,7 +17454,7 @@ fn bindgen_test_layout__GTypeInfo() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GTypeInfo>())).value_table as *const _ as usize },
+        ::std::mem::offset_of!(_GTypeInfo, value_table),
         64usize,
         concat!(
             "Offset of field: ",
@@ -17458,7 +17487,7 @@ fn bindgen_test_layout__GTypeFundamentalInfo() {
         concat!("Alignment of ", stringify!(_GTypeFundamentalInfo))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GTypeFundamentalInfo>())).type_flags as *const _ as usize },
+        ::std::mem::offset_of!(_GTypeFundamentalInfo, type_flags),
         0usize,
         concat!(
             "Offset of field: ",
@@ -17495,7 +17524,7 @@ fn bindgen_test_layout__GInterfaceInfo() {
         concat!("Alignment of ", stringify!(_GInterfaceInfo))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GInterfaceInfo>())).interface_init as *const _ as usize },
+        ::std::mem::offset_of!(_GInterfaceInfo, interface_init),
         0usize,
         concat!(
             "Offset of field: ",
@@ -17505,7 +17534,7 @@ fn bindgen_test_layout__GInterfaceInfo() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GInterfaceInfo>())).interface_finalize as *const _ as usize },
+        ::std::mem::offset_of!(_GInterfaceInfo, interface_finalize),
         8usize,
         concat!(
             "Offset of field: ",
@@ -17515,7 +17544,7 @@ fn bindgen_test_layout__GInterfaceInfo() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GInterfaceInfo>())).interface_data as *const _ as usize },
+        ::std::mem::offset_of!(_GInterfaceInfo, interface_data),
         16usize,
         concat!(
             "Offset of field: ",
@@ -17711,7 +17740,7 @@ fn bindgen_test_layout__GTypeValueTable() {
         concat!("Alignment of ", stringify!(_GTypeValueTable))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GTypeValueTable>())).value_init as *const _ as usize },
+        ::std::mem::offset_of!(_GTypeValueTable, value_init),
         0usize,
         concat!(
             "Offset of field: ",
@@ -17721,7 +17750,7 @@ fn bindgen_test_layout__GTypeValueTable() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GTypeValueTable>())).value_free as *const _ as usize },
+        ::std::mem::offset_of!(_GTypeValueTable, value_free),
         8usize,
         concat!(
             "Offset of field: ",
@@ -17731,7 +17760,7 @@ fn bindgen_test_layout__GTypeValueTable() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GTypeValueTable>())).value_copy as *const _ as usize },
+        ::std::mem::offset_of!(_GTypeValueTable, value_copy),
         16usize,
         concat!(
             "Offset of field: ",
@@ -17741,7 +17770,7 @@ fn bindgen_test_layout__GTypeValueTable() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GTypeValueTable>())).value_peek_pointer as *const _ as usize },
+        ::std::mem::offset_of!(_GTypeValueTable, value_peek_pointer),
         24usize,
         concat!(
             "Offset of field: ",
@@ -17751,7 +17780,7 @@ fn bindgen_test_layout__GTypeValueTable() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GTypeValueTable>())).collect_format as *const _ as usize },
+        ::std::mem::offset_of!(_GTypeValueTable, collect_format),
         32usize,
         concat!(
             "Offset of field: ",
@@ -17761,7 +17790,7 @@ fn bindgen_test_layout__GTypeValueTable() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GTypeValueTable>())).collect_value as *const _ as usize },
+        ::std::mem::offset_of!(_GTypeValueTable, collect_value),
         40usize,
         concat!(
             "Offset of field: ",
@@ -17771,7 +17800,7 @@ fn bindgen_test_layout__GTypeValueTable() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GTypeValueTable>())).lcopy_format as *const _ as usize },
+        ::std::mem::offset_of!(_GTypeValueTable, lcopy_format),
         48usize,
         concat!(
             "Offset of field: ",
@@ -17781,7 +17810,7 @@ fn bindgen_test_layout__GTypeValueTable() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GTypeValueTable>())).lcopy_value as *const _ as usize },
+        ::std::mem::offset_of!(_GTypeValueTable, lcopy_value),
         56usize,
         concat!(
             "Offset of field: ",
@@ -17978,7 +18007,7 @@ fn bindgen_test_layout__GValue__bindgen_ty_1() {
         concat!("Alignment of ", stringify!(_GValue__bindgen_ty_1))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GValue__bindgen_ty_1>())).v_int as *const _ as usize },
+        ::std::mem::offset_of!(_GValue__bindgen_ty_1, v_int),
         0usize,
         concat!(
             "Offset of field: ",
@@ -17988,7 +18017,7 @@ fn bindgen_test_layout__GValue__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GValue__bindgen_ty_1>())).v_uint as *const _ as usize },
+        ::std::mem::offset_of!(_GValue__bindgen_ty_1, v_uint),
         0usize,
         concat!(
             "Offset of field: ",
@@ -17998,7 +18027,7 @@ fn bindgen_test_layout__GValue__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GValue__bindgen_ty_1>())).v_long as *const _ as usize },
+        ::std::mem::offset_of!(_GValue__bindgen_ty_1, v_long),
         0usize,
         concat!(
             "Offset of field: ",
@@ -18008,7 +18037,7 @@ fn bindgen_test_layout__GValue__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GValue__bindgen_ty_1>())).v_ulong as *const _ as usize },
+        ::std::mem::offset_of!(_GValue__bindgen_ty_1, v_ulong),
         0usize,
         concat!(
             "Offset of field: ",
@@ -18018,7 +18047,7 @@ fn bindgen_test_layout__GValue__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GValue__bindgen_ty_1>())).v_int64 as *const _ as usize },
+        ::std::mem::offset_of!(_GValue__bindgen_ty_1, v_int64),
         0usize,
         concat!(
             "Offset of field: ",
@@ -18028,7 +18057,7 @@ fn bindgen_test_layout__GValue__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GValue__bindgen_ty_1>())).v_uint64 as *const _ as usize },
+        ::std::mem::offset_of!(_GValue__bindgen_ty_1, v_uint64),
         0usize,
         concat!(
             "Offset of field: ",
@@ -18038,7 +18067,7 @@ fn bindgen_test_layout__GValue__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GValue__bindgen_ty_1>())).v_float as *const _ as usize },
+        ::std::mem::offset_of!(_GValue__bindgen_ty_1, v_float),
         0usize,
         concat!(
             "Offset of field: ",
@@ -18048,7 +18077,7 @@ fn bindgen_test_layout__GValue__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GValue__bindgen_ty_1>())).v_double as *const _ as usize },
+        ::std::mem::offset_of!(_GValue__bindgen_ty_1, v_double),
         0usize,
         concat!(
             "Offset of field: ",
@@ -18058,7 +18087,7 @@ fn bindgen_test_layout__GValue__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GValue__bindgen_ty_1>())).v_pointer as *const _ as usize },
+        ::std::mem::offset_of!(_GValue__bindgen_ty_1, v_pointer),
         0usize,
         concat!(
             "Offset of field: ",
@@ -18081,7 +18110,7 @@ fn bindgen_test_layout__GValue() {
         concat!("Alignment of ", stringify!(_GValue))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GValue>())).g_type as *const _ as usize },
+        ::std::mem::offset_of!(_GValue, g_type),
         0usize,
         concat!(
             "Offset of field: ",
@@ -18091,7 +18120,7 @@ fn bindgen_test_layout__GValue() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GValue>())).data as *const _ as usize },
+        ::std::mem::offset_of!(_GValue, data),
         8usize,
         concat!(
             "Offset of field: ",
@@ -18195,7 +18224,7 @@ fn bindgen_test_layout__GParamSpec() {
         concat!("Alignment of ", stringify!(_GParamSpec))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpec>())).g_type_instance as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpec, g_type_instance),
         0usize,
         concat!(
             "Offset of field: ",
@@ -18205,7 +18234,7 @@ fn bindgen_test_layout__GParamSpec() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpec>())).name as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpec, name),
         8usize,
         concat!(
             "Offset of field: ",
@@ -18215,7 +18244,7 @@ fn bindgen_test_layout__GParamSpec() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpec>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpec, flags),
         16usize,
         concat!(
             "Offset of field: ",
@@ -18225,7 +18254,7 @@ fn bindgen_test_layout__GParamSpec() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpec>())).value_type as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpec, value_type),
         24usize,
         concat!(
             "Offset of field: ",
@@ -18235,7 +18264,7 @@ fn bindgen_test_layout__GParamSpec() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpec>())).owner_type as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpec, owner_type),
         32usize,
         concat!(
             "Offset of field: ",
@@ -18245,7 +18274,7 @@ fn bindgen_test_layout__GParamSpec() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpec>()))._nick as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpec, _nick),
         40usize,
         concat!(
             "Offset of field: ",
@@ -18255,7 +18284,7 @@ fn bindgen_test_layout__GParamSpec() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpec>()))._blurb as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpec, _blurb),
         48usize,
         concat!(
             "Offset of field: ",
@@ -18265,7 +18294,7 @@ fn bindgen_test_layout__GParamSpec() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpec>())).qdata as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpec, qdata),
         56usize,
         concat!(
             "Offset of field: ",
@@ -18275,7 +18304,7 @@ fn bindgen_test_layout__GParamSpec() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpec>())).ref_count as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpec, ref_count),
         64usize,
         concat!(
             "Offset of field: ",
@@ -18285,7 +18314,7 @@ fn bindgen_test_layout__GParamSpec() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpec>())).param_id as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpec, param_id),
         68usize,
         concat!(
             "Offset of field: ",
@@ -18337,7 +18366,7 @@ fn bindgen_test_layout__GParamSpecClass() {
         concat!("Alignment of ", stringify!(_GParamSpecClass))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecClass>())).g_type_class as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecClass, g_type_class),
         0usize,
         concat!(
             "Offset of field: ",
@@ -18347,7 +18376,7 @@ fn bindgen_test_layout__GParamSpecClass() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecClass>())).value_type as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecClass, value_type),
         8usize,
         concat!(
             "Offset of field: ",
@@ -18357,7 +18386,7 @@ fn bindgen_test_layout__GParamSpecClass() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecClass>())).finalize as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecClass, finalize),
         16usize,
         concat!(
             "Offset of field: ",
@@ -18367,7 +18396,7 @@ fn bindgen_test_layout__GParamSpecClass() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecClass>())).value_set_default as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecClass, value_set_default),
         24usize,
         concat!(
             "Offset of field: ",
@@ -18377,7 +18406,7 @@ fn bindgen_test_layout__GParamSpecClass() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecClass>())).value_validate as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecClass, value_validate),
         32usize,
         concat!(
             "Offset of field: ",
@@ -18387,7 +18416,7 @@ fn bindgen_test_layout__GParamSpecClass() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecClass>())).values_cmp as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecClass, values_cmp),
         40usize,
         concat!(
             "Offset of field: ",
@@ -18397,7 +18426,7 @@ fn bindgen_test_layout__GParamSpecClass() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecClass>())).dummy as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecClass, dummy),
         48usize,
         concat!(
             "Offset of field: ",
@@ -18432,7 +18461,7 @@ fn bindgen_test_layout__GParameter() {
         concat!("Alignment of ", stringify!(_GParameter))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParameter>())).name as *const _ as usize },
+        ::std::mem::offset_of!(_GParameter, name),
         0usize,
         concat!(
             "Offset of field: ",
@@ -18442,7 +18471,7 @@ fn bindgen_test_layout__GParameter() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParameter>())).value as *const _ as usize },
+        ::std::mem::offset_of!(_GParameter, value),
         8usize,
         concat!(
             "Offset of field: ",
@@ -18578,7 +18607,7 @@ fn bindgen_test_layout__GParamSpecTypeInfo() {
         concat!("Alignment of ", stringify!(_GParamSpecTypeInfo))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecTypeInfo>())).instance_size as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecTypeInfo, instance_size),
         0usize,
         concat!(
             "Offset of field: ",
@@ -18588,7 +18617,7 @@ fn bindgen_test_layout__GParamSpecTypeInfo() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecTypeInfo>())).n_preallocs as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecTypeInfo, n_preallocs),
         2usize,
         concat!(
             "Offset of field: ",
@@ -18598,7 +18627,7 @@ fn bindgen_test_layout__GParamSpecTypeInfo() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecTypeInfo>())).instance_init as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecTypeInfo, instance_init),
         8usize,
         concat!(
             "Offset of field: ",
@@ -18608,7 +18637,7 @@ fn bindgen_test_layout__GParamSpecTypeInfo() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecTypeInfo>())).value_type as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecTypeInfo, value_type),
         16usize,
         concat!(
             "Offset of field: ",
@@ -18618,7 +18647,7 @@ fn bindgen_test_layout__GParamSpecTypeInfo() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecTypeInfo>())).finalize as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecTypeInfo, finalize),
         24usize,
         concat!(
             "Offset of field: ",
@@ -18628,7 +18657,7 @@ fn bindgen_test_layout__GParamSpecTypeInfo() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecTypeInfo>())).value_set_default as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecTypeInfo, value_set_default),
         32usize,
         concat!(
             "Offset of field: ",
@@ -18638,7 +18667,7 @@ fn bindgen_test_layout__GParamSpecTypeInfo() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecTypeInfo>())).value_validate as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecTypeInfo, value_validate),
         40usize,
         concat!(
             "Offset of field: ",
@@ -18648,7 +18677,7 @@ fn bindgen_test_layout__GParamSpecTypeInfo() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecTypeInfo>())).values_cmp as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecTypeInfo, values_cmp),
         48usize,
         concat!(
             "Offset of field: ",
@@ -18793,7 +18822,7 @@ fn bindgen_test_layout__GClosureNotifyData() {
         concat!("Alignment of ", stringify!(_GClosureNotifyData))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GClosureNotifyData>())).data as *const _ as usize },
+        ::std::mem::offset_of!(_GClosureNotifyData, data),
         0usize,
         concat!(
             "Offset of field: ",
@@ -18803,7 +18832,7 @@ fn bindgen_test_layout__GClosureNotifyData() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GClosureNotifyData>())).notify as *const _ as usize },
+        ::std::mem::offset_of!(_GClosureNotifyData, notify),
         8usize,
         concat!(
             "Offset of field: ",
@@ -18850,7 +18879,7 @@ fn bindgen_test_layout__GClosure() {
         concat!("Alignment of ", stringify!(_GClosure))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GClosure>())).marshal as *const _ as usize },
+        ::std::mem::offset_of!(_GClosure, marshal),
         8usize,
         concat!(
             "Offset of field: ",
@@ -18860,7 +18889,7 @@ fn bindgen_test_layout__GClosure() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GClosure>())).data as *const _ as usize },
+        ::std::mem::offset_of!(_GClosure, data),
         16usize,
         concat!(
             "Offset of field: ",
@@ -18870,7 +18899,7 @@ fn bindgen_test_layout__GClosure() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GClosure>())).notifiers as *const _ as usize },
+        ::std::mem::offset_of!(_GClosure, notifiers),
         24usize,
         concat!(
             "Offset of field: ",
@@ -19067,7 +19096,7 @@ fn bindgen_test_layout__GCClosure() {
         concat!("Alignment of ", stringify!(_GCClosure))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GCClosure>())).closure as *const _ as usize },
+        ::std::mem::offset_of!(_GCClosure, closure),
         0usize,
         concat!(
             "Offset of field: ",
@@ -19077,7 +19106,7 @@ fn bindgen_test_layout__GCClosure() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GCClosure>())).callback as *const _ as usize },
+        ::std::mem::offset_of!(_GCClosure, callback),
         32usize,
         concat!(
             "Offset of field: ",
@@ -19735,7 +19764,7 @@ fn bindgen_test_layout__GSignalInvocationHint() {
         concat!("Alignment of ", stringify!(_GSignalInvocationHint))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GSignalInvocationHint>())).signal_id as *const _ as usize },
+        ::std::mem::offset_of!(_GSignalInvocationHint, signal_id),
         0usize,
         concat!(
             "Offset of field: ",
@@ -19745,7 +19774,7 @@ fn bindgen_test_layout__GSignalInvocationHint() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GSignalInvocationHint>())).detail as *const _ as usize },
+        ::std::mem::offset_of!(_GSignalInvocationHint, detail),
         4usize,
         concat!(
             "Offset of field: ",
@@ -19755,7 +19784,7 @@ fn bindgen_test_layout__GSignalInvocationHint() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GSignalInvocationHint>())).run_type as *const _ as usize },
+        ::std::mem::offset_of!(_GSignalInvocationHint, run_type),
         8usize,
         concat!(
             "Offset of field: ",
@@ -19807,7 +19836,7 @@ fn bindgen_test_layout__GSignalQuery() {
         concat!("Alignment of ", stringify!(_GSignalQuery))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GSignalQuery>())).signal_id as *const _ as usize },
+        ::std::mem::offset_of!(_GSignalQuery, signal_id),
         0usize,
         concat!(
             "Offset of field: ",
@@ -19817,7 +19846,7 @@ fn bindgen_test_layout__GSignalQuery() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GSignalQuery>())).signal_name as *const _ as usize },
+        ::std::mem::offset_of!(_GSignalQuery, signal_name),
         8usize,
         concat!(
             "Offset of field: ",
@@ -19827,7 +19856,7 @@ fn bindgen_test_layout__GSignalQuery() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GSignalQuery>())).itype as *const _ as usize },
+        ::std::mem::offset_of!(_GSignalQuery, itype),
         16usize,
         concat!(
             "Offset of field: ",
@@ -19837,7 +19866,7 @@ fn bindgen_test_layout__GSignalQuery() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GSignalQuery>())).signal_flags as *const _ as usize },
+        ::std::mem::offset_of!(_GSignalQuery, signal_flags),
         24usize,
         concat!(
             "Offset of field: ",
@@ -19847,7 +19876,7 @@ fn bindgen_test_layout__GSignalQuery() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GSignalQuery>())).return_type as *const _ as usize },
+        ::std::mem::offset_of!(_GSignalQuery, return_type),
         32usize,
         concat!(
             "Offset of field: ",
@@ -19857,7 +19886,7 @@ fn bindgen_test_layout__GSignalQuery() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GSignalQuery>())).n_params as *const _ as usize },
+        ::std::mem::offset_of!(_GSignalQuery, n_params),
         40usize,
         concat!(
             "Offset of field: ",
@@ -19867,7 +19896,7 @@ fn bindgen_test_layout__GSignalQuery() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GSignalQuery>())).param_types as *const _ as usize },
+        ::std::mem::offset_of!(_GSignalQuery, param_types),
         48usize,
         concat!(
             "Offset of field: ",
@@ -20302,7 +20331,7 @@ fn bindgen_test_layout__GObject() {
         concat!("Alignment of ", stringify!(_GObject))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GObject>())).g_type_instance as *const _ as usize },
+        ::std::mem::offset_of!(_GObject, g_type_instance),
         0usize,
         concat!(
             "Offset of field: ",
@@ -20312,7 +20341,7 @@ fn bindgen_test_layout__GObject() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GObject>())).ref_count as *const _ as usize },
+        ::std::mem::offset_of!(_GObject, ref_count),
         8usize,
         concat!(
             "Offset of field: ",
@@ -20322,7 +20351,7 @@ fn bindgen_test_layout__GObject() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GObject>())).qdata as *const _ as usize },
+        ::std::mem::offset_of!(_GObject, qdata),
         16usize,
         concat!(
             "Offset of field: ",
@@ -20427,7 +20456,7 @@ fn bindgen_test_layout__GObjectClass() {
         concat!("Alignment of ", stringify!(_GObjectClass))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GObjectClass>())).g_type_class as *const _ as usize },
+        ::std::mem::offset_of!(_GObjectClass, g_type_class),
         0usize,
         concat!(
             "Offset of field: ",
@@ -20437,7 +20466,7 @@ fn bindgen_test_layout__GObjectClass() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GObjectClass>())).construct_properties as *const _ as usize },
+        ::std::mem::offset_of!(_GObjectClass, construct_properties),
         8usize,
         concat!(
             "Offset of field: ",
@@ -20447,7 +20476,7 @@ fn bindgen_test_layout__GObjectClass() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GObjectClass>())).constructor as *const _ as usize },
+        ::std::mem::offset_of!(_GObjectClass, constructor),
         16usize,
         concat!(
             "Offset of field: ",
@@ -20457,7 +20486,7 @@ fn bindgen_test_layout__GObjectClass() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GObjectClass>())).set_property as *const _ as usize },
+        ::std::mem::offset_of!(_GObjectClass, set_property),
         24usize,
         concat!(
             "Offset of field: ",
@@ -20467,7 +20496,7 @@ fn bindgen_test_layout__GObjectClass() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GObjectClass>())).get_property as *const _ as usize },
+        ::std::mem::offset_of!(_GObjectClass, get_property),
         32usize,
         concat!(
             "Offset of field: ",
@@ -20477,7 +20506,7 @@ fn bindgen_test_layout__GObjectClass() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GObjectClass>())).dispose as *const _ as usize },
+        ::std::mem::offset_of!(_GObjectClass, dispose),
         40usize,
         concat!(
             "Offset of field: ",
@@ -20487,7 +20516,7 @@ fn bindgen_test_layout__GObjectClass() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GObjectClass>())).finalize as *const _ as usize },
+        ::std::mem::offset_of!(_GObjectClass, finalize),
         48usize,
         concat!(
             "Offset of field: ",
@@ -20497,7 +20526,7 @@ fn bindgen_test_layout__GObjectClass() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GObjectClass>())).dispatch_properties_changed as *const _ as usize },
+        ::std::mem::offset_of!(_GObjectClass, dispatch_properties_changed),
         56usize,
         concat!(
             "Offset of field: ",
@@ -20507,7 +20536,7 @@ fn bindgen_test_layout__GObjectClass() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GObjectClass>())).notify as *const _ as usize },
+        ::std::mem::offset_of!(_GObjectClass, notify),
         64usize,
         concat!(
             "Offset of field: ",
@@ -20517,7 +20546,7 @@ fn bindgen_test_layout__GObjectClass() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GObjectClass>())).constructed as *const _ as usize },
+        ::std::mem::offset_of!(_GObjectClass, constructed),
         72usize,
         concat!(
             "Offset of field: ",
@@ -20527,7 +20556,7 @@ fn bindgen_test_layout__GObjectClass() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GObjectClass>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(_GObjectClass, flags),
         80usize,
         concat!(
             "Offset of field: ",
@@ -20537,7 +20566,7 @@ fn bindgen_test_layout__GObjectClass() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GObjectClass>())).pdummy as *const _ as usize },
+        ::std::mem::offset_of!(_GObjectClass, pdummy),
         88usize,
         concat!(
             "Offset of field: ",
@@ -20573,7 +20602,7 @@ fn bindgen_test_layout__GObjectConstructParam() {
         concat!("Alignment of ", stringify!(_GObjectConstructParam))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GObjectConstructParam>())).pspec as *const _ as usize },
+        ::std::mem::offset_of!(_GObjectConstructParam, pspec),
         0usize,
         concat!(
             "Offset of field: ",
@@ -20583,7 +20612,7 @@ fn bindgen_test_layout__GObjectConstructParam() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GObjectConstructParam>())).value as *const _ as usize },
+        ::std::mem::offset_of!(_GObjectConstructParam, value),
         8usize,
         concat!(
             "Offset of field: ",
@@ -20842,7 +20871,7 @@ fn bindgen_test_layout_GWeakRef__bindgen_ty_1() {
         concat!("Alignment of ", stringify!(GWeakRef__bindgen_ty_1))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<GWeakRef__bindgen_ty_1>())).p as *const _ as usize },
+        ::std::mem::offset_of!(GWeakRef__bindgen_ty_1, p),
         0usize,
         concat!(
             "Offset of field: ",
@@ -20865,7 +20894,7 @@ fn bindgen_test_layout_GWeakRef() {
         concat!("Alignment of ", stringify!(GWeakRef))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<GWeakRef>())).priv_ as *const _ as usize },
+        ::std::mem::offset_of!(GWeakRef, priv_),
         0usize,
         concat!(
             "Offset of field: ",
@@ -21017,7 +21046,7 @@ fn bindgen_test_layout__GEnumClass() {
         concat!("Alignment of ", stringify!(_GEnumClass))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GEnumClass>())).g_type_class as *const _ as usize },
+        ::std::mem::offset_of!(_GEnumClass, g_type_class),
         0usize,
         concat!(
             "Offset of field: ",
@@ -21027,7 +21056,7 @@ fn bindgen_test_layout__GEnumClass() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GEnumClass>())).minimum as *const _ as usize },
+        ::std::mem::offset_of!(_GEnumClass, minimum),
         8usize,
         concat!(
             "Offset of field: ",
@@ -21037,7 +21066,7 @@ fn bindgen_test_layout__GEnumClass() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GEnumClass>())).maximum as *const _ as usize },
+        ::std::mem::offset_of!(_GEnumClass, maximum),
         12usize,
         concat!(
             "Offset of field: ",
@@ -21047,7 +21076,7 @@ fn bindgen_test_layout__GEnumClass() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GEnumClass>())).n_values as *const _ as usize },
+        ::std::mem::offset_of!(_GEnumClass, n_values),
         16usize,
         concat!(
             "Offset of field: ",
@@ -21057,7 +21086,7 @@ fn bindgen_test_layout__GEnumClass() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GEnumClass>())).values as *const _ as usize },
+        ::std::mem::offset_of!(_GEnumClass, values),
         24usize,
         concat!(
             "Offset of field: ",
@@ -21097,7 +21126,7 @@ fn bindgen_test_layout__GFlagsClass() {
         concat!("Alignment of ", stringify!(_GFlagsClass))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GFlagsClass>())).g_type_class as *const _ as usize },
+        ::std::mem::offset_of!(_GFlagsClass, g_type_class),
         0usize,
         concat!(
             "Offset of field: ",
@@ -21107,7 +21136,7 @@ fn bindgen_test_layout__GFlagsClass() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GFlagsClass>())).mask as *const _ as usize },
+        ::std::mem::offset_of!(_GFlagsClass, mask),
         8usize,
         concat!(
             "Offset of field: ",
@@ -21117,7 +21146,7 @@ fn bindgen_test_layout__GFlagsClass() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GFlagsClass>())).n_values as *const _ as usize },
+        ::std::mem::offset_of!(_GFlagsClass, n_values),
         12usize,
         concat!(
             "Offset of field: ",
@@ -21127,7 +21156,7 @@ fn bindgen_test_layout__GFlagsClass() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GFlagsClass>())).values as *const _ as usize },
+        ::std::mem::offset_of!(_GFlagsClass, values),
         16usize,
         concat!(
             "Offset of field: ",
@@ -21164,7 +21193,7 @@ fn bindgen_test_layout__GEnumValue() {
         concat!("Alignment of ", stringify!(_GEnumValue))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GEnumValue>())).value as *const _ as usize },
+        ::std::mem::offset_of!(_GEnumValue, value),
         0usize,
         concat!(
             "Offset of field: ",
@@ -21174,7 +21203,7 @@ fn bindgen_test_layout__GEnumValue() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GEnumValue>())).value_name as *const _ as usize },
+        ::std::mem::offset_of!(_GEnumValue, value_name),
         8usize,
         concat!(
             "Offset of field: ",
@@ -21184,7 +21213,7 @@ fn bindgen_test_layout__GEnumValue() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GEnumValue>())).value_nick as *const _ as usize },
+        ::std::mem::offset_of!(_GEnumValue, value_nick),
         16usize,
         concat!(
             "Offset of field: ",
@@ -21221,7 +21250,7 @@ fn bindgen_test_layout__GFlagsValue() {
         concat!("Alignment of ", stringify!(_GFlagsValue))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GFlagsValue>())).value as *const _ as usize },
+        ::std::mem::offset_of!(_GFlagsValue, value),
         0usize,
         concat!(
             "Offset of field: ",
@@ -21231,7 +21260,7 @@ fn bindgen_test_layout__GFlagsValue() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GFlagsValue>())).value_name as *const _ as usize },
+        ::std::mem::offset_of!(_GFlagsValue, value_name),
         8usize,
         concat!(
             "Offset of field: ",
@@ -21241,7 +21270,7 @@ fn bindgen_test_layout__GFlagsValue() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GFlagsValue>())).value_nick as *const _ as usize },
+        ::std::mem::offset_of!(_GFlagsValue, value_nick),
         16usize,
         concat!(
             "Offset of field: ",
@@ -21344,7 +21373,7 @@ fn bindgen_test_layout__GParamSpecChar() {
         concat!("Alignment of ", stringify!(_GParamSpecChar))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecChar>())).parent_instance as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecChar, parent_instance),
         0usize,
         concat!(
             "Offset of field: ",
@@ -21354,7 +21383,7 @@ fn bindgen_test_layout__GParamSpecChar() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecChar>())).minimum as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecChar, minimum),
         72usize,
         concat!(
             "Offset of field: ",
@@ -21364,7 +21393,7 @@ fn bindgen_test_layout__GParamSpecChar() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecChar>())).maximum as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecChar, maximum),
         73usize,
         concat!(
             "Offset of field: ",
@@ -21374,7 +21403,7 @@ fn bindgen_test_layout__GParamSpecChar() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecChar>())).default_value as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecChar, default_value),
         74usize,
         concat!(
             "Offset of field: ",
@@ -21412,7 +21441,7 @@ fn bindgen_test_layout__GParamSpecUChar() {
         concat!("Alignment of ", stringify!(_GParamSpecUChar))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecUChar>())).parent_instance as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecUChar, parent_instance),
         0usize,
         concat!(
             "Offset of field: ",
@@ -21422,7 +21451,7 @@ fn bindgen_test_layout__GParamSpecUChar() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecUChar>())).minimum as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecUChar, minimum),
         72usize,
         concat!(
             "Offset of field: ",
@@ -21432,7 +21461,7 @@ fn bindgen_test_layout__GParamSpecUChar() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecUChar>())).maximum as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecUChar, maximum),
         73usize,
         concat!(
             "Offset of field: ",
@@ -21442,7 +21471,7 @@ fn bindgen_test_layout__GParamSpecUChar() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecUChar>())).default_value as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecUChar, default_value),
         74usize,
         concat!(
             "Offset of field: ",
@@ -21476,7 +21505,7 @@ fn bindgen_test_layout__GParamSpecBoolean() {
         concat!("Alignment of ", stringify!(_GParamSpecBoolean))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecBoolean>())).parent_instance as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecBoolean, parent_instance),
         0usize,
         concat!(
             "Offset of field: ",
@@ -21486,7 +21515,7 @@ fn bindgen_test_layout__GParamSpecBoolean() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecBoolean>())).default_value as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecBoolean, default_value),
         72usize,
         concat!(
             "Offset of field: ",
@@ -21524,7 +21553,7 @@ fn bindgen_test_layout__GParamSpecInt() {
         concat!("Alignment of ", stringify!(_GParamSpecInt))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecInt>())).parent_instance as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecInt, parent_instance),
         0usize,
         concat!(
             "Offset of field: ",
@@ -21534,7 +21563,7 @@ fn bindgen_test_layout__GParamSpecInt() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecInt>())).minimum as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecInt, minimum),
         72usize,
         concat!(
             "Offset of field: ",
@@ -21544,7 +21573,7 @@ fn bindgen_test_layout__GParamSpecInt() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecInt>())).maximum as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecInt, maximum),
         76usize,
         concat!(
             "Offset of field: ",
@@ -21554,7 +21583,7 @@ fn bindgen_test_layout__GParamSpecInt() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecInt>())).default_value as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecInt, default_value),
         80usize,
         concat!(
             "Offset of field: ",
@@ -21592,7 +21621,7 @@ fn bindgen_test_layout__GParamSpecUInt() {
         concat!("Alignment of ", stringify!(_GParamSpecUInt))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecUInt>())).parent_instance as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecUInt, parent_instance),
         0usize,
         concat!(
             "Offset of field: ",
@@ -21602,7 +21631,7 @@ fn bindgen_test_layout__GParamSpecUInt() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecUInt>())).minimum as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecUInt, minimum),
         72usize,
         concat!(
             "Offset of field: ",
@@ -21612,7 +21641,7 @@ fn bindgen_test_layout__GParamSpecUInt() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecUInt>())).maximum as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecUInt, maximum),
         76usize,
         concat!(
             "Offset of field: ",
@@ -21622,7 +21651,7 @@ fn bindgen_test_layout__GParamSpecUInt() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecUInt>())).default_value as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecUInt, default_value),
         80usize,
         concat!(
             "Offset of field: ",
@@ -21660,7 +21689,7 @@ fn bindgen_test_layout__GParamSpecLong() {
         concat!("Alignment of ", stringify!(_GParamSpecLong))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecLong>())).parent_instance as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecLong, parent_instance),
         0usize,
         concat!(
             "Offset of field: ",
@@ -21670,7 +21699,7 @@ fn bindgen_test_layout__GParamSpecLong() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecLong>())).minimum as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecLong, minimum),
         72usize,
         concat!(
             "Offset of field: ",
@@ -21680,7 +21709,7 @@ fn bindgen_test_layout__GParamSpecLong() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecLong>())).maximum as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecLong, maximum),
         80usize,
         concat!(
             "Offset of field: ",
@@ -21690,7 +21719,7 @@ fn bindgen_test_layout__GParamSpecLong() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecLong>())).default_value as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecLong, default_value),
         88usize,
         concat!(
             "Offset of field: ",
@@ -21728,7 +21757,7 @@ fn bindgen_test_layout__GParamSpecULong() {
         concat!("Alignment of ", stringify!(_GParamSpecULong))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecULong>())).parent_instance as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecULong, parent_instance),
         0usize,
         concat!(
             "Offset of field: ",
@@ -21738,7 +21767,7 @@ fn bindgen_test_layout__GParamSpecULong() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecULong>())).minimum as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecULong, minimum),
         72usize,
         concat!(
             "Offset of field: ",
@@ -21748,7 +21777,7 @@ fn bindgen_test_layout__GParamSpecULong() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecULong>())).maximum as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecULong, maximum),
         80usize,
         concat!(
             "Offset of field: ",
@@ -21758,7 +21787,7 @@ fn bindgen_test_layout__GParamSpecULong() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecULong>())).default_value as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecULong, default_value),
         88usize,
         concat!(
             "Offset of field: ",
@@ -21796,7 +21825,7 @@ fn bindgen_test_layout__GParamSpecInt64() {
         concat!("Alignment of ", stringify!(_GParamSpecInt64))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecInt64>())).parent_instance as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecInt64, parent_instance),
         0usize,
         concat!(
             "Offset of field: ",
@@ -21806,7 +21835,7 @@ fn bindgen_test_layout__GParamSpecInt64() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecInt64>())).minimum as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecInt64, minimum),
         72usize,
         concat!(
             "Offset of field: ",
@@ -21816,7 +21845,7 @@ fn bindgen_test_layout__GParamSpecInt64() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecInt64>())).maximum as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecInt64, maximum),
         80usize,
         concat!(
             "Offset of field: ",
@@ -21826,7 +21855,7 @@ fn bindgen_test_layout__GParamSpecInt64() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecInt64>())).default_value as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecInt64, default_value),
         88usize,
         concat!(
             "Offset of field: ",
@@ -21864,7 +21893,7 @@ fn bindgen_test_layout__GParamSpecUInt64() {
         concat!("Alignment of ", stringify!(_GParamSpecUInt64))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecUInt64>())).parent_instance as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecUInt64, parent_instance),
         0usize,
         concat!(
             "Offset of field: ",
@@ -21874,7 +21903,7 @@ fn bindgen_test_layout__GParamSpecUInt64() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecUInt64>())).minimum as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecUInt64, minimum),
         72usize,
         concat!(
             "Offset of field: ",
@@ -21884,7 +21913,7 @@ fn bindgen_test_layout__GParamSpecUInt64() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecUInt64>())).maximum as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecUInt64, maximum),
         80usize,
         concat!(
             "Offset of field: ",
@@ -21894,7 +21923,7 @@ fn bindgen_test_layout__GParamSpecUInt64() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecUInt64>())).default_value as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecUInt64, default_value),
         88usize,
         concat!(
             "Offset of field: ",
@@ -21928,7 +21957,7 @@ fn bindgen_test_layout__GParamSpecUnichar() {
         concat!("Alignment of ", stringify!(_GParamSpecUnichar))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecUnichar>())).parent_instance as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecUnichar, parent_instance),
         0usize,
         concat!(
             "Offset of field: ",
@@ -21938,7 +21967,7 @@ fn bindgen_test_layout__GParamSpecUnichar() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecUnichar>())).default_value as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecUnichar, default_value),
         72usize,
         concat!(
             "Offset of field: ",
@@ -21975,7 +22004,7 @@ fn bindgen_test_layout__GParamSpecEnum() {
         concat!("Alignment of ", stringify!(_GParamSpecEnum))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecEnum>())).parent_instance as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecEnum, parent_instance),
         0usize,
         concat!(
             "Offset of field: ",
@@ -21985,7 +22014,7 @@ fn bindgen_test_layout__GParamSpecEnum() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecEnum>())).enum_class as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecEnum, enum_class),
         72usize,
         concat!(
             "Offset of field: ",
@@ -21995,7 +22024,7 @@ fn bindgen_test_layout__GParamSpecEnum() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecEnum>())).default_value as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecEnum, default_value),
         80usize,
         concat!(
             "Offset of field: ",
@@ -22032,7 +22061,7 @@ fn bindgen_test_layout__GParamSpecFlags() {
         concat!("Alignment of ", stringify!(_GParamSpecFlags))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecFlags>())).parent_instance as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecFlags, parent_instance),
         0usize,
         concat!(
             "Offset of field: ",
@@ -22042,7 +22071,7 @@ fn bindgen_test_layout__GParamSpecFlags() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecFlags>())).flags_class as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecFlags, flags_class),
         72usize,
         concat!(
             "Offset of field: ",
@@ -22052,7 +22081,7 @@ fn bindgen_test_layout__GParamSpecFlags() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecFlags>())).default_value as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecFlags, default_value),
         80usize,
         concat!(
             "Offset of field: ",
@@ -22093,7 +22122,7 @@ fn bindgen_test_layout__GParamSpecFloat() {
         concat!("Alignment of ", stringify!(_GParamSpecFloat))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecFloat>())).parent_instance as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecFloat, parent_instance),
         0usize,
         concat!(
             "Offset of field: ",
@@ -22103,7 +22132,7 @@ fn bindgen_test_layout__GParamSpecFloat() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecFloat>())).minimum as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecFloat, minimum),
         72usize,
         concat!(
             "Offset of field: ",
@@ -22113,7 +22142,7 @@ fn bindgen_test_layout__GParamSpecFloat() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecFloat>())).maximum as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecFloat, maximum),
         76usize,
         concat!(
             "Offset of field: ",
@@ -22123,7 +22152,7 @@ fn bindgen_test_layout__GParamSpecFloat() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecFloat>())).default_value as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecFloat, default_value),
         80usize,
         concat!(
             "Offset of field: ",
@@ -22133,7 +22162,7 @@ fn bindgen_test_layout__GParamSpecFloat() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecFloat>())).epsilon as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecFloat, epsilon),
         84usize,
         concat!(
             "Offset of field: ",
@@ -22174,7 +22203,7 @@ fn bindgen_test_layout__GParamSpecDouble() {
         concat!("Alignment of ", stringify!(_GParamSpecDouble))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecDouble>())).parent_instance as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecDouble, parent_instance),
         0usize,
         concat!(
             "Offset of field: ",
@@ -22184,7 +22213,7 @@ fn bindgen_test_layout__GParamSpecDouble() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecDouble>())).minimum as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecDouble, minimum),
         72usize,
         concat!(
             "Offset of field: ",
@@ -22194,7 +22223,7 @@ fn bindgen_test_layout__GParamSpecDouble() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecDouble>())).maximum as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecDouble, maximum),
         80usize,
         concat!(
             "Offset of field: ",
@@ -22204,7 +22233,7 @@ fn bindgen_test_layout__GParamSpecDouble() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecDouble>())).default_value as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecDouble, default_value),
         88usize,
         concat!(
             "Offset of field: ",
@@ -22214,7 +22243,7 @@ fn bindgen_test_layout__GParamSpecDouble() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecDouble>())).epsilon as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecDouble, epsilon),
         96usize,
         concat!(
             "Offset of field: ",
@@ -22259,7 +22288,7 @@ fn bindgen_test_layout__GParamSpecString() {
         concat!("Alignment of ", stringify!(_GParamSpecString))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecString>())).parent_instance as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecString, parent_instance),
         0usize,
         concat!(
             "Offset of field: ",
@@ -22269,7 +22298,7 @@ fn bindgen_test_layout__GParamSpecString() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecString>())).default_value as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecString, default_value),
         72usize,
         concat!(
             "Offset of field: ",
@@ -22279,7 +22308,7 @@ fn bindgen_test_layout__GParamSpecString() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecString>())).cset_first as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecString, cset_first),
         80usize,
         concat!(
             "Offset of field: ",
@@ -22289,7 +22318,7 @@ fn bindgen_test_layout__GParamSpecString() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecString>())).cset_nth as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecString, cset_nth),
         88usize,
         concat!(
             "Offset of field: ",
@@ -22299,7 +22328,7 @@ fn bindgen_test_layout__GParamSpecString() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecString>())).substitutor as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecString, substitutor),
         96usize,
         concat!(
             "Offset of field: ",
@@ -22369,7 +22398,7 @@ fn bindgen_test_layout__GParamSpecParam() {
         concat!("Alignment of ", stringify!(_GParamSpecParam))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecParam>())).parent_instance as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecParam, parent_instance),
         0usize,
         concat!(
             "Offset of field: ",
@@ -22401,7 +22430,7 @@ fn bindgen_test_layout__GParamSpecBoxed() {
         concat!("Alignment of ", stringify!(_GParamSpecBoxed))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecBoxed>())).parent_instance as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecBoxed, parent_instance),
         0usize,
         concat!(
             "Offset of field: ",
@@ -22433,7 +22462,7 @@ fn bindgen_test_layout__GParamSpecPointer() {
         concat!("Alignment of ", stringify!(_GParamSpecPointer))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecPointer>())).parent_instance as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecPointer, parent_instance),
         0usize,
         concat!(
             "Offset of field: ",
@@ -22469,7 +22498,7 @@ fn bindgen_test_layout__GParamSpecValueArray() {
         concat!("Alignment of ", stringify!(_GParamSpecValueArray))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecValueArray>())).parent_instance as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecValueArray, parent_instance),
         0usize,
         concat!(
             "Offset of field: ",
@@ -22479,7 +22508,7 @@ fn bindgen_test_layout__GParamSpecValueArray() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecValueArray>())).element_spec as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecValueArray, element_spec),
         72usize,
         concat!(
             "Offset of field: ",
@@ -22489,7 +22518,7 @@ fn bindgen_test_layout__GParamSpecValueArray() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecValueArray>())).fixed_n_elements as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecValueArray, fixed_n_elements),
         80usize,
         concat!(
             "Offset of field: ",
@@ -22521,7 +22550,7 @@ fn bindgen_test_layout__GParamSpecObject() {
         concat!("Alignment of ", stringify!(_GParamSpecObject))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecObject>())).parent_instance as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecObject, parent_instance),
         0usize,
         concat!(
             "Offset of field: ",
@@ -22562,7 +22591,7 @@ fn bindgen_test_layout__GParamSpecOverride() {
         concat!("Alignment of ", stringify!(_GParamSpecOverride))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecOverride>())).parent_instance as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecOverride, parent_instance),
         0usize,
         concat!(
             "Offset of field: ",
@@ -22572,7 +22601,7 @@ fn bindgen_test_layout__GParamSpecOverride() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecOverride>())).overridden as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecOverride, overridden),
         72usize,
         concat!(
             "Offset of field: ",
@@ -22608,7 +22637,7 @@ fn bindgen_test_layout__GParamSpecGType() {
         concat!("Alignment of ", stringify!(_GParamSpecGType))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecGType>())).parent_instance as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecGType, parent_instance),
         0usize,
         concat!(
             "Offset of field: ",
@@ -22618,7 +22647,7 @@ fn bindgen_test_layout__GParamSpecGType() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecGType>())).is_a_type as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecGType, is_a_type),
         72usize,
         concat!(
             "Offset of field: ",
@@ -22657,7 +22686,7 @@ fn bindgen_test_layout__GParamSpecVariant() {
         concat!("Alignment of ", stringify!(_GParamSpecVariant))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecVariant>())).parent_instance as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecVariant, parent_instance),
         0usize,
         concat!(
             "Offset of field: ",
@@ -22667,7 +22696,7 @@ fn bindgen_test_layout__GParamSpecVariant() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecVariant>())).type_ as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecVariant, type_),
         72usize,
         concat!(
             "Offset of field: ",
@@ -22677,7 +22706,7 @@ fn bindgen_test_layout__GParamSpecVariant() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecVariant>())).default_value as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecVariant, default_value),
         80usize,
         concat!(
             "Offset of field: ",
@@ -22687,7 +22716,7 @@ fn bindgen_test_layout__GParamSpecVariant() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GParamSpecVariant>())).padding as *const _ as usize },
+        ::std::mem::offset_of!(_GParamSpecVariant, padding),
         88usize,
         concat!(
             "Offset of field: ",
@@ -22953,7 +22982,7 @@ fn bindgen_test_layout__GTypeModule() {
         concat!("Alignment of ", stringify!(_GTypeModule))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GTypeModule>())).parent_instance as *const _ as usize },
+        ::std::mem::offset_of!(_GTypeModule, parent_instance),
         0usize,
         concat!(
             "Offset of field: ",
@@ -22963,7 +22992,7 @@ fn bindgen_test_layout__GTypeModule() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GTypeModule>())).use_count as *const _ as usize },
+        ::std::mem::offset_of!(_GTypeModule, use_count),
         24usize,
         concat!(
             "Offset of field: ",
@@ -22973,7 +23002,7 @@ fn bindgen_test_layout__GTypeModule() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GTypeModule>())).type_infos as *const _ as usize },
+        ::std::mem::offset_of!(_GTypeModule, type_infos),
         32usize,
         concat!(
             "Offset of field: ",
@@ -22983,7 +23012,7 @@ fn bindgen_test_layout__GTypeModule() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GTypeModule>())).interface_infos as *const _ as usize },
+        ::std::mem::offset_of!(_GTypeModule, interface_infos),
         40usize,
         concat!(
             "Offset of field: ",
@@ -22993,7 +23022,7 @@ fn bindgen_test_layout__GTypeModule() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GTypeModule>())).name as *const _ as usize },
+        ::std::mem::offset_of!(_GTypeModule, name),
         48usize,
         concat!(
             "Offset of field: ",
@@ -23035,7 +23064,7 @@ fn bindgen_test_layout__GTypeModuleClass() {
         concat!("Alignment of ", stringify!(_GTypeModuleClass))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GTypeModuleClass>())).parent_class as *const _ as usize },
+        ::std::mem::offset_of!(_GTypeModuleClass, parent_class),
         0usize,
         concat!(
             "Offset of field: ",
@@ -23045,7 +23074,7 @@ fn bindgen_test_layout__GTypeModuleClass() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GTypeModuleClass>())).load as *const _ as usize },
+        ::std::mem::offset_of!(_GTypeModuleClass, load),
         136usize,
         concat!(
             "Offset of field: ",
@@ -23055,7 +23084,7 @@ fn bindgen_test_layout__GTypeModuleClass() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GTypeModuleClass>())).unload as *const _ as usize },
+        ::std::mem::offset_of!(_GTypeModuleClass, unload),
         144usize,
         concat!(
             "Offset of field: ",
@@ -23065,7 +23094,7 @@ fn bindgen_test_layout__GTypeModuleClass() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GTypeModuleClass>())).reserved1 as *const _ as usize },
+        ::std::mem::offset_of!(_GTypeModuleClass, reserved1),
         152usize,
         concat!(
             "Offset of field: ",
@@ -23075,7 +23104,7 @@ fn bindgen_test_layout__GTypeModuleClass() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GTypeModuleClass>())).reserved2 as *const _ as usize },
+        ::std::mem::offset_of!(_GTypeModuleClass, reserved2),
         160usize,
         concat!(
             "Offset of field: ",
@@ -23085,7 +23114,7 @@ fn bindgen_test_layout__GTypeModuleClass() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GTypeModuleClass>())).reserved3 as *const _ as usize },
+        ::std::mem::offset_of!(_GTypeModuleClass, reserved3),
         168usize,
         concat!(
             "Offset of field: ",
@@ -23095,7 +23124,7 @@ fn bindgen_test_layout__GTypeModuleClass() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GTypeModuleClass>())).reserved4 as *const _ as usize },
+        ::std::mem::offset_of!(_GTypeModuleClass, reserved4),
         176usize,
         concat!(
             "Offset of field: ",
@@ -23213,7 +23242,7 @@ fn bindgen_test_layout__GTypePluginClass() {
         concat!("Alignment of ", stringify!(_GTypePluginClass))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GTypePluginClass>())).base_iface as *const _ as usize },
+        ::std::mem::offset_of!(_GTypePluginClass, base_iface),
         0usize,
         concat!(
             "Offset of field: ",
@@ -23223,7 +23252,7 @@ fn bindgen_test_layout__GTypePluginClass() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GTypePluginClass>())).use_plugin as *const _ as usize },
+        ::std::mem::offset_of!(_GTypePluginClass, use_plugin),
         16usize,
         concat!(
             "Offset of field: ",
@@ -23233,7 +23262,7 @@ fn bindgen_test_layout__GTypePluginClass() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GTypePluginClass>())).unuse_plugin as *const _ as usize },
+        ::std::mem::offset_of!(_GTypePluginClass, unuse_plugin),
         24usize,
         concat!(
             "Offset of field: ",
@@ -23243,7 +23272,7 @@ fn bindgen_test_layout__GTypePluginClass() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GTypePluginClass>())).complete_type_info as *const _ as usize },
+        ::std::mem::offset_of!(_GTypePluginClass, complete_type_info),
         32usize,
         concat!(
             "Offset of field: ",
@@ -23253,7 +23282,7 @@ fn bindgen_test_layout__GTypePluginClass() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GTypePluginClass>())).complete_interface_info as *const _ as usize },
+        ::std::mem::offset_of!(_GTypePluginClass, complete_interface_info),
         40usize,
         concat!(
             "Offset of field: ",
@@ -23314,7 +23343,7 @@ fn bindgen_test_layout__GValueArray() {
         concat!("Alignment of ", stringify!(_GValueArray))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GValueArray>())).n_values as *const _ as usize },
+        ::std::mem::offset_of!(_GValueArray, n_values),
         0usize,
         concat!(
             "Offset of field: ",
@@ -23324,7 +23353,7 @@ fn bindgen_test_layout__GValueArray() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GValueArray>())).values as *const _ as usize },
+        ::std::mem::offset_of!(_GValueArray, values),
         8usize,
         concat!(
             "Offset of field: ",
@@ -23334,7 +23363,7 @@ fn bindgen_test_layout__GValueArray() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_GValueArray>())).n_prealloced as *const _ as usize },
+        ::std::mem::offset_of!(_GValueArray, n_prealloced),
         16usize,
         concat!(
             "Offset of field: ",
@@ -23545,7 +23574,7 @@ fn bindgen_test_layout___mbstate_t__bindgen_ty_1() {
         concat!("Alignment of ", stringify!(__mbstate_t__bindgen_ty_1))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<__mbstate_t__bindgen_ty_1>())).__wch as *const _ as usize },
+        ::std::mem::offset_of!(__mbstate_t__bindgen_ty_1, __wch),
         0usize,
         concat!(
             "Offset of field: ",
@@ -23555,7 +23584,7 @@ fn bindgen_test_layout___mbstate_t__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<__mbstate_t__bindgen_ty_1>())).__wchb as *const _ as usize },
+        ::std::mem::offset_of!(__mbstate_t__bindgen_ty_1, __wchb),
         0usize,
         concat!(
             "Offset of field: ",
@@ -23578,7 +23607,7 @@ fn bindgen_test_layout___mbstate_t() {
         concat!("Alignment of ", stringify!(__mbstate_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<__mbstate_t>())).__count as *const _ as usize },
+        ::std::mem::offset_of!(__mbstate_t, __count),
         0usize,
         concat!(
             "Offset of field: ",
@@ -23588,7 +23617,7 @@ fn bindgen_test_layout___mbstate_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<__mbstate_t>())).__value as *const _ as usize },
+        ::std::mem::offset_of!(__mbstate_t, __value),
         4usize,
         concat!(
             "Offset of field: ",
@@ -23617,7 +23646,7 @@ fn bindgen_test_layout__G_fpos_t() {
         concat!("Alignment of ", stringify!(_G_fpos_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_G_fpos_t>())).__pos as *const _ as usize },
+        ::std::mem::offset_of!(_G_fpos_t, __pos),
         0usize,
         concat!(
             "Offset of field: ",
@@ -23627,7 +23656,7 @@ fn bindgen_test_layout__G_fpos_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_G_fpos_t>())).__state as *const _ as usize },
+        ::std::mem::offset_of!(_G_fpos_t, __state),
         8usize,
         concat!(
             "Offset of field: ",
@@ -23656,7 +23685,7 @@ fn bindgen_test_layout__G_fpos64_t() {
         concat!("Alignment of ", stringify!(_G_fpos64_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_G_fpos64_t>())).__pos as *const _ as usize },
+        ::std::mem::offset_of!(_G_fpos64_t, __pos),
         0usize,
         concat!(
             "Offset of field: ",
@@ -23666,7 +23695,7 @@ fn bindgen_test_layout__G_fpos64_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_G_fpos64_t>())).__state as *const _ as usize },
+        ::std::mem::offset_of!(_G_fpos64_t, __state),
         8usize,
         concat!(
             "Offset of field: ",
@@ -23702,7 +23731,7 @@ fn bindgen_test_layout__IO_marker() {
         concat!("Alignment of ", stringify!(_IO_marker))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_IO_marker>()))._next as *const _ as usize },
+        ::std::mem::offset_of!(_IO_marker, _next),
         0usize,
         concat!(
             "Offset of field: ",
@@ -23712,7 +23741,7 @@ fn bindgen_test_layout__IO_marker() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_IO_marker>()))._sbuf as *const _ as usize },
+        ::std::mem::offset_of!(_IO_marker, _sbuf),
         8usize,
         concat!(
             "Offset of field: ",
@@ -23722,7 +23751,7 @@ fn bindgen_test_layout__IO_marker() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_IO_marker>()))._pos as *const _ as usize },
+        ::std::mem::offset_of!(_IO_marker, _pos),
         16usize,
         concat!(
             "Offset of field: ",
@@ -23783,7 +23812,7 @@ fn bindgen_test_layout__IO_FILE() {
         concat!("Alignment of ", stringify!(_IO_FILE))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_IO_FILE>()))._flags as *const _ as usize },
+        ::std::mem::offset_of!(_IO_FILE, _flags),
         0usize,
         concat!(
             "Offset of field: ",
@@ -23793,7 +23822,7 @@ fn bindgen_test_layout__IO_FILE() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_IO_FILE>()))._IO_read_ptr as *const _ as usize },
+        ::std::mem::offset_of!(_IO_FILE, _IO_read_ptr),
         8usize,
         concat!(
             "Offset of field: ",
@@ -23803,7 +23832,7 @@ fn bindgen_test_layout__IO_FILE() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_IO_FILE>()))._IO_read_end as *const _ as usize },
+        ::std::mem::offset_of!(_IO_FILE, _IO_read_end),
         16usize,
         concat!(
             "Offset of field: ",
@@ -23813,7 +23842,7 @@ fn bindgen_test_layout__IO_FILE() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_IO_FILE>()))._IO_read_base as *const _ as usize },
+        ::std::mem::offset_of!(_IO_FILE, _IO_read_base),
         24usize,
         concat!(
             "Offset of field: ",
@@ -23823,7 +23852,7 @@ fn bindgen_test_layout__IO_FILE() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_IO_FILE>()))._IO_write_base as *const _ as usize },
+        ::std::mem::offset_of!(_IO_FILE, _IO_write_base),
         32usize,
         concat!(
             "Offset of field: ",
@@ -23833,7 +23862,7 @@ fn bindgen_test_layout__IO_FILE() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_IO_FILE>()))._IO_write_ptr as *const _ as usize },
+        ::std::mem::offset_of!(_IO_FILE, _IO_write_ptr),
         40usize,
         concat!(
             "Offset of field: ",
@@ -23843,7 +23872,7 @@ fn bindgen_test_layout__IO_FILE() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_IO_FILE>()))._IO_write_end as *const _ as usize },
+        ::std::mem::offset_of!(_IO_FILE, _IO_write_end),
         48usize,
         concat!(
             "Offset of field: ",
@@ -23853,7 +23882,7 @@ fn bindgen_test_layout__IO_FILE() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_IO_FILE>()))._IO_buf_base as *const _ as usize },
+        ::std::mem::offset_of!(_IO_FILE, _IO_buf_base),
         56usize,
         concat!(
             "Offset of field: ",
@@ -23863,7 +23892,7 @@ fn bindgen_test_layout__IO_FILE() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_IO_FILE>()))._IO_buf_end as *const _ as usize },
+        ::std::mem::offset_of!(_IO_FILE, _IO_buf_end),
         64usize,
         concat!(
             "Offset of field: ",
@@ -23873,7 +23902,7 @@ fn bindgen_test_layout__IO_FILE() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_IO_FILE>()))._IO_save_base as *const _ as usize },
+        ::std::mem::offset_of!(_IO_FILE, _IO_save_base),
         72usize,
         concat!(
             "Offset of field: ",
@@ -23883,7 +23912,7 @@ fn bindgen_test_layout__IO_FILE() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_IO_FILE>()))._IO_backup_base as *const _ as usize },
+        ::std::mem::offset_of!(_IO_FILE, _IO_backup_base),
         80usize,
         concat!(
             "Offset of field: ",
@@ -23893,7 +23922,7 @@ fn bindgen_test_layout__IO_FILE() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_IO_FILE>()))._IO_save_end as *const _ as usize },
+        ::std::mem::offset_of!(_IO_FILE, _IO_save_end),
         88usize,
         concat!(
             "Offset of field: ",
@@ -23903,7 +23932,7 @@ fn bindgen_test_layout__IO_FILE() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_IO_FILE>()))._markers as *const _ as usize },
+        ::std::mem::offset_of!(_IO_FILE, _markers),
         96usize,
         concat!(
             "Offset of field: ",
@@ -23913,7 +23942,7 @@ fn bindgen_test_layout__IO_FILE() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_IO_FILE>()))._chain as *const _ as usize },
+        ::std::mem::offset_of!(_IO_FILE, _chain),
         104usize,
         concat!(
             "Offset of field: ",
@@ -23923,7 +23952,7 @@ fn bindgen_test_layout__IO_FILE() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_IO_FILE>()))._fileno as *const _ as usize },
+        ::std::mem::offset_of!(_IO_FILE, _fileno),
         112usize,
         concat!(
             "Offset of field: ",
@@ -23933,7 +23962,7 @@ fn bindgen_test_layout__IO_FILE() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_IO_FILE>()))._flags2 as *const _ as usize },
+        ::std::mem::offset_of!(_IO_FILE, _flags2),
         116usize,
         concat!(
             "Offset of field: ",
@@ -23943,7 +23972,7 @@ fn bindgen_test_layout__IO_FILE() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_IO_FILE>()))._old_offset as *const _ as usize },
+        ::std::mem::offset_of!(_IO_FILE, _old_offset),
         120usize,
         concat!(
             "Offset of field: ",
@@ -23953,7 +23982,7 @@ fn bindgen_test_layout__IO_FILE() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_IO_FILE>()))._cur_column as *const _ as usize },
+        ::std::mem::offset_of!(_IO_FILE, _cur_column),
         128usize,
         concat!(
             "Offset of field: ",
@@ -23963,7 +23992,7 @@ fn bindgen_test_layout__IO_FILE() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_IO_FILE>()))._vtable_offset as *const _ as usize },
+        ::std::mem::offset_of!(_IO_FILE, _vtable_offset),
         130usize,
         concat!(
             "Offset of field: ",
@@ -23973,7 +24002,7 @@ fn bindgen_test_layout__IO_FILE() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_IO_FILE>()))._shortbuf as *const _ as usize },
+        ::std::mem::offset_of!(_IO_FILE, _shortbuf),
         131usize,
         concat!(
             "Offset of field: ",
@@ -23983,7 +24012,7 @@ fn bindgen_test_layout__IO_FILE() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_IO_FILE>()))._lock as *const _ as usize },
+        ::std::mem::offset_of!(_IO_FILE, _lock),
         136usize,
         concat!(
             "Offset of field: ",
@@ -23993,7 +24022,7 @@ fn bindgen_test_layout__IO_FILE() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_IO_FILE>()))._offset as *const _ as usize },
+        ::std::mem::offset_of!(_IO_FILE, _offset),
         144usize,
         concat!(
             "Offset of field: ",
@@ -24003,7 +24032,7 @@ fn bindgen_test_layout__IO_FILE() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_IO_FILE>())).__pad1 as *const _ as usize },
+        ::std::mem::offset_of!(_IO_FILE, __pad1),
         152usize,
         concat!(
             "Offset of field: ",
@@ -24013,7 +24042,7 @@ fn bindgen_test_layout__IO_FILE() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_IO_FILE>())).__pad2 as *const _ as usize },
+        ::std::mem::offset_of!(_IO_FILE, __pad2),
         160usize,
         concat!(
             "Offset of field: ",
@@ -24023,7 +24052,7 @@ fn bindgen_test_layout__IO_FILE() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_IO_FILE>())).__pad3 as *const _ as usize },
+        ::std::mem::offset_of!(_IO_FILE, __pad3),
         168usize,
         concat!(
             "Offset of field: ",
@@ -24033,7 +24062,7 @@ fn bindgen_test_layout__IO_FILE() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_IO_FILE>())).__pad4 as *const _ as usize },
+        ::std::mem::offset_of!(_IO_FILE, __pad4),
         176usize,
         concat!(
             "Offset of field: ",
@@ -24043,7 +24072,7 @@ fn bindgen_test_layout__IO_FILE() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_IO_FILE>())).__pad5 as *const _ as usize },
+        ::std::mem::offset_of!(_IO_FILE, __pad5),
         184usize,
         concat!(
             "Offset of field: ",
@@ -24053,7 +24082,7 @@ fn bindgen_test_layout__IO_FILE() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_IO_FILE>()))._mode as *const _ as usize },
+        ::std::mem::offset_of!(_IO_FILE, _mode),
         192usize,
         concat!(
             "Offset of field: ",
@@ -24063,7 +24092,7 @@ fn bindgen_test_layout__IO_FILE() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_IO_FILE>()))._unused2 as *const _ as usize },
+        ::std::mem::offset_of!(_IO_FILE, _unused2),
         196usize,
         concat!(
             "Offset of field: ",
@@ -24615,7 +24644,7 @@ fn bindgen_test_layout_wait() {
         concat!("Alignment of ", stringify!(wait))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<wait>())).w_status as *const _ as usize },
+        ::std::mem::offset_of!(wait, w_status),
         0usize,
         concat!(
             "Offset of field: ",
@@ -24625,7 +24654,7 @@ fn bindgen_test_layout_wait() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<wait>())).__wait_terminated as *const _ as usize },
+        ::std::mem::offset_of!(wait, __wait_terminated),
         0usize,
         concat!(
             "Offset of field: ",
@@ -24635,7 +24664,7 @@ fn bindgen_test_layout_wait() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<wait>())).__wait_stopped as *const _ as usize },
+        ::std::mem::offset_of!(wait, __wait_stopped),
         0usize,
         concat!(
             "Offset of field: ",
@@ -24665,7 +24694,7 @@ fn bindgen_test_layout___WAIT_STATUS() {
         concat!("Alignment of ", stringify!(__WAIT_STATUS))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<__WAIT_STATUS>())).__uptr as *const _ as usize },
+        ::std::mem::offset_of!(__WAIT_STATUS, __uptr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -24675,7 +24704,7 @@ fn bindgen_test_layout___WAIT_STATUS() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<__WAIT_STATUS>())).__iptr as *const _ as usize },
+        ::std::mem::offset_of!(__WAIT_STATUS, __iptr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -24704,7 +24733,7 @@ fn bindgen_test_layout_div_t() {
         concat!("Alignment of ", stringify!(div_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<div_t>())).quot as *const _ as usize },
+        ::std::mem::offset_of!(div_t, quot),
         0usize,
         concat!(
             "Offset of field: ",
@@ -24714,7 +24743,7 @@ fn bindgen_test_layout_div_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<div_t>())).rem as *const _ as usize },
+        ::std::mem::offset_of!(div_t, rem),
         4usize,
         concat!(
             "Offset of field: ",
@@ -24743,7 +24772,7 @@ fn bindgen_test_layout_ldiv_t() {
         concat!("Alignment of ", stringify!(ldiv_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<ldiv_t>())).quot as *const _ as usize },
+        ::std::mem::offset_of!(ldiv_t, quot),
         0usize,
         concat!(
             "Offset of field: ",
@@ -24753,7 +24782,7 @@ fn bindgen_test_layout_ldiv_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<ldiv_t>())).rem as *const _ as usize },
+        ::std::mem::offset_of!(ldiv_t, rem),
         8usize,
         concat!(
             "Offset of field: ",
@@ -24782,7 +24811,7 @@ fn bindgen_test_layout_lldiv_t() {
         concat!("Alignment of ", stringify!(lldiv_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<lldiv_t>())).quot as *const _ as usize },
+        ::std::mem::offset_of!(lldiv_t, quot),
         0usize,
         concat!(
             "Offset of field: ",
@@ -24792,7 +24821,7 @@ fn bindgen_test_layout_lldiv_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<lldiv_t>())).rem as *const _ as usize },
+        ::std::mem::offset_of!(lldiv_t, rem),
         8usize,
         concat!(
             "Offset of field: ",
@@ -24914,7 +24943,7 @@ fn bindgen_test_layout_random_data() {
         concat!("Alignment of ", stringify!(random_data))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<random_data>())).fptr as *const _ as usize },
+        ::std::mem::offset_of!(random_data, fptr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -24924,7 +24953,7 @@ fn bindgen_test_layout_random_data() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<random_data>())).rptr as *const _ as usize },
+        ::std::mem::offset_of!(random_data, rptr),
         8usize,
         concat!(
             "Offset of field: ",
@@ -24934,7 +24963,7 @@ fn bindgen_test_layout_random_data() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<random_data>())).state as *const _ as usize },
+        ::std::mem::offset_of!(random_data, state),
         16usize,
         concat!(
             "Offset of field: ",
@@ -24944,7 +24973,7 @@ fn bindgen_test_layout_random_data() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<random_data>())).rand_type as *const _ as usize },
+        ::std::mem::offset_of!(random_data, rand_type),
         24usize,
         concat!(
             "Offset of field: ",
@@ -24954,7 +24983,7 @@ fn bindgen_test_layout_random_data() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<random_data>())).rand_deg as *const _ as usize },
+        ::std::mem::offset_of!(random_data, rand_deg),
         28usize,
         concat!(
             "Offset of field: ",
@@ -24964,7 +24993,7 @@ fn bindgen_test_layout_random_data() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<random_data>())).rand_sep as *const _ as usize },
+        ::std::mem::offset_of!(random_data, rand_sep),
         32usize,
         concat!(
             "Offset of field: ",
@@ -24974,7 +25003,7 @@ fn bindgen_test_layout_random_data() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<random_data>())).end_ptr as *const _ as usize },
+        ::std::mem::offset_of!(random_data, end_ptr),
         40usize,
         concat!(
             "Offset of field: ",
@@ -25059,7 +25088,7 @@ fn bindgen_test_layout_drand48_data() {
         concat!("Alignment of ", stringify!(drand48_data))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<drand48_data>())).__x as *const _ as usize },
+        ::std::mem::offset_of!(drand48_data, __x),
         0usize,
         concat!(
             "Offset of field: ",
@@ -25069,7 +25098,7 @@ fn bindgen_test_layout_drand48_data() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<drand48_data>())).__old_x as *const _ as usize },
+        ::std::mem::offset_of!(drand48_data, __old_x),
         6usize,
         concat!(
             "Offset of field: ",
@@ -25079,7 +25108,7 @@ fn bindgen_test_layout_drand48_data() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<drand48_data>())).__c as *const _ as usize },
+        ::std::mem::offset_of!(drand48_data, __c),
         12usize,
         concat!(
             "Offset of field: ",
@@ -25089,7 +25118,7 @@ fn bindgen_test_layout_drand48_data() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<drand48_data>())).__init as *const _ as usize },
+        ::std::mem::offset_of!(drand48_data, __init),
         14usize,
         concat!(
             "Offset of field: ",
@@ -25099,7 +25128,7 @@ fn bindgen_test_layout_drand48_data() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<drand48_data>())).__a as *const _ as usize },
+        ::std::mem::offset_of!(drand48_data, __a),
         16usize,
         concat!(
             "Offset of field: ",
@@ -25575,7 +25604,7 @@ fn bindgen_test_layout__glibtop_sysdeps() {
         concat!("Alignment of ", stringify!(_glibtop_sysdeps))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_glibtop_sysdeps>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(_glibtop_sysdeps, flags),
         0usize,
         concat!(
             "Offset of field: ",
@@ -25585,7 +25614,7 @@ fn bindgen_test_layout__glibtop_sysdeps() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_glibtop_sysdeps>())).features as *const _ as usize },
+        ::std::mem::offset_of!(_glibtop_sysdeps, features),
         8usize,
         concat!(
             "Offset of field: ",
@@ -25595,7 +25624,7 @@ fn bindgen_test_layout__glibtop_sysdeps() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_glibtop_sysdeps>())).cpu as *const _ as usize },
+        ::std::mem::offset_of!(_glibtop_sysdeps, cpu),
         16usize,
         concat!(
             "Offset of field: ",
@@ -25605,7 +25634,7 @@ fn bindgen_test_layout__glibtop_sysdeps() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_glibtop_sysdeps>())).mem as *const _ as usize },
+        ::std::mem::offset_of!(_glibtop_sysdeps, mem),
         24usize,
         concat!(
             "Offset of field: ",
@@ -25615,7 +25644,7 @@ fn bindgen_test_layout__glibtop_sysdeps() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_glibtop_sysdeps>())).swap as *const _ as usize },
+        ::std::mem::offset_of!(_glibtop_sysdeps, swap),
         32usize,
         concat!(
             "Offset of field: ",
@@ -25625,7 +25654,7 @@ fn bindgen_test_layout__glibtop_sysdeps() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_glibtop_sysdeps>())).uptime as *const _ as usize },
+        ::std::mem::offset_of!(_glibtop_sysdeps, uptime),
         40usize,
         concat!(
             "Offset of field: ",
@@ -25635,7 +25664,7 @@ fn bindgen_test_layout__glibtop_sysdeps() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_glibtop_sysdeps>())).loadavg as *const _ as usize },
+        ::std::mem::offset_of!(_glibtop_sysdeps, loadavg),
         48usize,
         concat!(
             "Offset of field: ",
@@ -25645,7 +25674,7 @@ fn bindgen_test_layout__glibtop_sysdeps() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_glibtop_sysdeps>())).shm_limits as *const _ as usize },
+        ::std::mem::offset_of!(_glibtop_sysdeps, shm_limits),
         56usize,
         concat!(
             "Offset of field: ",
@@ -25655,7 +25684,7 @@ fn bindgen_test_layout__glibtop_sysdeps() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_glibtop_sysdeps>())).msg_limits as *const _ as usize },
+        ::std::mem::offset_of!(_glibtop_sysdeps, msg_limits),
         64usize,
         concat!(
             "Offset of field: ",
@@ -25665,7 +25694,7 @@ fn bindgen_test_layout__glibtop_sysdeps() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_glibtop_sysdeps>())).sem_limits as *const _ as usize },
+        ::std::mem::offset_of!(_glibtop_sysdeps, sem_limits),
         72usize,
         concat!(
             "Offset of field: ",
@@ -25675,7 +25704,7 @@ fn bindgen_test_layout__glibtop_sysdeps() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_glibtop_sysdeps>())).proclist as *const _ as usize },
+        ::std::mem::offset_of!(_glibtop_sysdeps, proclist),
         80usize,
         concat!(
             "Offset of field: ",
@@ -25685,7 +25714,7 @@ fn bindgen_test_layout__glibtop_sysdeps() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_glibtop_sysdeps>())).proc_state as *const _ as usize },
+        ::std::mem::offset_of!(_glibtop_sysdeps, proc_state),
         88usize,
         concat!(
             "Offset of field: ",
@@ -25695,7 +25724,7 @@ fn bindgen_test_layout__glibtop_sysdeps() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_glibtop_sysdeps>())).proc_uid as *const _ as usize },
+        ::std::mem::offset_of!(_glibtop_sysdeps, proc_uid),
         96usize,
         concat!(
             "Offset of field: ",
@@ -25705,7 +25734,7 @@ fn bindgen_test_layout__glibtop_sysdeps() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_glibtop_sysdeps>())).proc_mem as *const _ as usize },
+        ::std::mem::offset_of!(_glibtop_sysdeps, proc_mem),
         104usize,
         concat!(
             "Offset of field: ",
@@ -25715,7 +25744,7 @@ fn bindgen_test_layout__glibtop_sysdeps() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_glibtop_sysdeps>())).proc_time as *const _ as usize },
+        ::std::mem::offset_of!(_glibtop_sysdeps, proc_time),
         112usize,
         concat!(
             "Offset of field: ",
@@ -25725,7 +25754,7 @@ fn bindgen_test_layout__glibtop_sysdeps() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_glibtop_sysdeps>())).proc_signal as *const _ as usize },
+        ::std::mem::offset_of!(_glibtop_sysdeps, proc_signal),
         120usize,
         concat!(
             "Offset of field: ",
@@ -25735,7 +25764,7 @@ fn bindgen_test_layout__glibtop_sysdeps() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_glibtop_sysdeps>())).proc_kernel as *const _ as usize },
+        ::std::mem::offset_of!(_glibtop_sysdeps, proc_kernel),
         128usize,
         concat!(
             "Offset of field: ",
@@ -25745,7 +25774,7 @@ fn bindgen_test_layout__glibtop_sysdeps() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_glibtop_sysdeps>())).proc_segment as *const _ as usize },
+        ::std::mem::offset_of!(_glibtop_sysdeps, proc_segment),
         136usize,
         concat!(
             "Offset of field: ",
@@ -25755,7 +25784,7 @@ fn bindgen_test_layout__glibtop_sysdeps() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_glibtop_sysdeps>())).proc_args as *const _ as usize },
+        ::std::mem::offset_of!(_glibtop_sysdeps, proc_args),
         144usize,
         concat!(
             "Offset of field: ",
@@ -25765,7 +25794,7 @@ fn bindgen_test_layout__glibtop_sysdeps() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_glibtop_sysdeps>())).proc_map as *const _ as usize },
+        ::std::mem::offset_of!(_glibtop_sysdeps, proc_map),
         152usize,
         concat!(
             "Offset of field: ",
@@ -25775,7 +25804,7 @@ fn bindgen_test_layout__glibtop_sysdeps() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_glibtop_sysdeps>())).proc_open_files as *const _ as usize },
+        ::std::mem::offset_of!(_glibtop_sysdeps, proc_open_files),
         160usize,
         concat!(
             "Offset of field: ",
@@ -25785,7 +25814,7 @@ fn bindgen_test_layout__glibtop_sysdeps() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_glibtop_sysdeps>())).mountlist as *const _ as usize },
+        ::std::mem::offset_of!(_glibtop_sysdeps, mountlist),
         168usize,
         concat!(
             "Offset of field: ",
@@ -25795,7 +25824,7 @@ fn bindgen_test_layout__glibtop_sysdeps() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_glibtop_sysdeps>())).fsusage as *const _ as usize },
+        ::std::mem::offset_of!(_glibtop_sysdeps, fsusage),
         176usize,
         concat!(
             "Offset of field: ",
@@ -25805,7 +25834,7 @@ fn bindgen_test_layout__glibtop_sysdeps() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_glibtop_sysdeps>())).netlist as *const _ as usize },
+        ::std::mem::offset_of!(_glibtop_sysdeps, netlist),
         184usize,
         concat!(
             "Offset of field: ",
@@ -25815,7 +25844,7 @@ fn bindgen_test_layout__glibtop_sysdeps() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_glibtop_sysdeps>())).netload as *const _ as usize },
+        ::std::mem::offset_of!(_glibtop_sysdeps, netload),
         192usize,
         concat!(
             "Offset of field: ",
@@ -25825,7 +25854,7 @@ fn bindgen_test_layout__glibtop_sysdeps() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_glibtop_sysdeps>())).ppp as *const _ as usize },
+        ::std::mem::offset_of!(_glibtop_sysdeps, ppp),
         200usize,
         concat!(
             "Offset of field: ",
@@ -25835,7 +25864,7 @@ fn bindgen_test_layout__glibtop_sysdeps() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_glibtop_sysdeps>())).proc_wd as *const _ as usize },
+        ::std::mem::offset_of!(_glibtop_sysdeps, proc_wd),
         208usize,
         concat!(
             "Offset of field: ",
@@ -25845,7 +25874,7 @@ fn bindgen_test_layout__glibtop_sysdeps() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_glibtop_sysdeps>())).proc_affinity as *const _ as usize },
+        ::std::mem::offset_of!(_glibtop_sysdeps, proc_affinity),
         216usize,
         concat!(
             "Offset of field: ",
@@ -25914,7 +25943,7 @@ fn bindgen_test_layout__glibtop() {
         concat!("Alignment of ", stringify!(_glibtop))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_glibtop>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(_glibtop, flags),
         0usize,
         concat!(
             "Offset of field: ",
@@ -25924,7 +25953,7 @@ fn bindgen_test_layout__glibtop() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_glibtop>())).method as *const _ as usize },
+        ::std::mem::offset_of!(_glibtop, method),
         4usize,
         concat!(
             "Offset of field: ",
@@ -25934,7 +25963,7 @@ fn bindgen_test_layout__glibtop() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_glibtop>())).error_method as *const _ as usize },
+        ::std::mem::offset_of!(_glibtop, error_method),
         8usize,
         concat!(
             "Offset of field: ",
@@ -25944,7 +25973,7 @@ fn bindgen_test_layout__glibtop() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_glibtop>())).input as *const _ as usize },
+        ::std::mem::offset_of!(_glibtop, input),
         12usize,
         concat!(
             "Offset of field: ",
@@ -25954,7 +25983,7 @@ fn bindgen_test_layout__glibtop() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_glibtop>())).output as *const _ as usize },
+        ::std::mem::offset_of!(_glibtop, output),
         20usize,
         concat!(
             "Offset of field: ",
@@ -25964,7 +25993,7 @@ fn bindgen_test_layout__glibtop() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_glibtop>())).socket as *const _ as usize },
+        ::std::mem::offset_of!(_glibtop, socket),
         28usize,
         concat!(
             "Offset of field: ",
@@ -25974,7 +26003,7 @@ fn bindgen_test_layout__glibtop() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_glibtop>())).ncpu as *const _ as usize },
+        ::std::mem::offset_of!(_glibtop, ncpu),
         32usize,
         concat!(
             "Offset of field: ",
@@ -25984,7 +26013,7 @@ fn bindgen_test_layout__glibtop() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_glibtop>())).real_ncpu as *const _ as usize },
+        ::std::mem::offset_of!(_glibtop, real_ncpu),
         36usize,
         concat!(
             "Offset of field: ",
@@ -25994,7 +26023,7 @@ fn bindgen_test_layout__glibtop() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_glibtop>())).os_version_code as *const _ as usize },
+        ::std::mem::offset_of!(_glibtop, os_version_code),
         40usize,
         concat!(
             "Offset of field: ",
@@ -26004,7 +26033,7 @@ fn bindgen_test_layout__glibtop() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_glibtop>())).name as *const _ as usize },
+        ::std::mem::offset_of!(_glibtop, name),
         48usize,
         concat!(
             "Offset of field: ",
@@ -26014,7 +26043,7 @@ fn bindgen_test_layout__glibtop() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_glibtop>())).server_command as *const _ as usize },
+        ::std::mem::offset_of!(_glibtop, server_command),
         56usize,
         concat!(
             "Offset of field: ",
@@ -26024,7 +26053,7 @@ fn bindgen_test_layout__glibtop() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_glibtop>())).server_host as *const _ as usize },
+        ::std::mem::offset_of!(_glibtop, server_host),
         64usize,
         concat!(
             "Offset of field: ",
@@ -26034,7 +26063,7 @@ fn bindgen_test_layout__glibtop() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_glibtop>())).server_user as *const _ as usize },
+        ::std::mem::offset_of!(_glibtop, server_user),
         72usize,
         concat!(
             "Offset of field: ",
@@ -26044,7 +26073,7 @@ fn bindgen_test_layout__glibtop() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_glibtop>())).server_rsh as *const _ as usize },
+        ::std::mem::offset_of!(_glibtop, server_rsh),
         80usize,
         concat!(
             "Offset of field: ",
@@ -26054,7 +26083,7 @@ fn bindgen_test_layout__glibtop() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_glibtop>())).features as *const _ as usize },
+        ::std::mem::offset_of!(_glibtop, features),
         88usize,
         concat!(
             "Offset of field: ",
@@ -26064,7 +26093,7 @@ fn bindgen_test_layout__glibtop() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_glibtop>())).server_port as *const _ as usize },
+        ::std::mem::offset_of!(_glibtop, server_port),
         96usize,
         concat!(
             "Offset of field: ",
@@ -26074,7 +26103,7 @@ fn bindgen_test_layout__glibtop() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_glibtop>())).sysdeps as *const _ as usize },
+        ::std::mem::offset_of!(_glibtop, sysdeps),
         104usize,
         concat!(
             "Offset of field: ",
@@ -26084,7 +26113,7 @@ fn bindgen_test_layout__glibtop() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_glibtop>())).required as *const _ as usize },
+        ::std::mem::offset_of!(_glibtop, required),
         328usize,
         concat!(
             "Offset of field: ",
@@ -26094,7 +26123,7 @@ fn bindgen_test_layout__glibtop() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_glibtop>())).pid as *const _ as usize },
+        ::std::mem::offset_of!(_glibtop, pid),
         552usize,
         concat!(
             "Offset of field: ",
@@ -26104,7 +26133,7 @@ fn bindgen_test_layout__glibtop() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_glibtop>())).uid as *const _ as usize },
+        ::std::mem::offset_of!(_glibtop, uid),
         556usize,
         concat!(
             "Offset of field: ",
@@ -26114,7 +26143,7 @@ fn bindgen_test_layout__glibtop() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_glibtop>())).euid as *const _ as usize },
+        ::std::mem::offset_of!(_glibtop, euid),
         560usize,
         concat!(
             "Offset of field: ",
@@ -26124,7 +26153,7 @@ fn bindgen_test_layout__glibtop() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_glibtop>())).gid as *const _ as usize },
+        ::std::mem::offset_of!(_glibtop, gid),
         564usize,
         concat!(
             "Offset of field: ",
@@ -26134,7 +26163,7 @@ fn bindgen_test_layout__glibtop() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_glibtop>())).egid as *const _ as usize },
+        ::std::mem::offset_of!(_glibtop, egid),
         568usize,
         concat!(
             "Offset of field: ",
@@ -26144,7 +26173,7 @@ fn bindgen_test_layout__glibtop() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_glibtop>())).machine as *const _ as usize },
+        ::std::mem::offset_of!(_glibtop, machine),
         576usize,
         concat!(
             "Offset of field: ",
@@ -26227,7 +26256,7 @@ fn bindgen_test_layout__glibtop_cpu() {
         concat!("Alignment of ", stringify!(_glibtop_cpu))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_glibtop_cpu>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(_glibtop_cpu, flags),
         0usize,
         concat!(
             "Offset of field: ",
@@ -26237,7 +26266,7 @@ fn bindgen_test_layout__glibtop_cpu() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_glibtop_cpu>())).total as *const _ as usize },
+        ::std::mem::offset_of!(_glibtop_cpu, total),
         8usize,
         concat!(
             "Offset of field: ",
@@ -26247,7 +26276,7 @@ fn bindgen_test_layout__glibtop_cpu() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_glibtop_cpu>())).user as *const _ as usize },
+        ::std::mem::offset_of!(_glibtop_cpu, user),
         16usize,
         concat!(
             "Offset of field: ",
@@ -26257,7 +26286,7 @@ fn bindgen_test_layout__glibtop_cpu() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_glibtop_cpu>())).nice as *const _ as usize },
+        ::std::mem::offset_of!(_glibtop_cpu, nice),
         24usize,
         concat!(
             "Offset of field: ",
@@ -26267,7 +26296,7 @@ fn bindgen_test_layout__glibtop_cpu() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_glibtop_cpu>())).sys as *const _ as usize },
+        ::std::mem::offset_of!(_glibtop_cpu, sys),
         32usize,
         concat!(
             "Offset of field: ",
@@ -26277,7 +26306,7 @@ fn bindgen_test_layout__glibtop_cpu() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_glibtop_cpu>())).idle as *const _ as usize },
+        ::std::mem::offset_of!(_glibtop_cpu, idle),
         40usize,
         concat!(
             "Offset of field: ",
@@ -26287,7 +26316,7 @@ fn bindgen_test_layout__glibtop_cpu() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_glibtop_cpu>())).iowait as *const _ as usize },
+        ::std::mem::offset_of!(_glibtop_cpu, iowait),
         48usize,
         concat!(
             "Offset of field: ",
@@ -26297,7 +26326,7 @@ fn bindgen_test_layout__glibtop_cpu() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_glibtop_cpu>())).irq as *const _ as usize },
+        ::std::mem::offset_of!(_glibtop_cpu, irq),
         56usize,
         concat!(
             "Offset of field: ",
@@ -26307,7 +26336,7 @@ fn bindgen_test_layout__glibtop_cpu() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_glibtop_cpu>())).softirq as *const _ as usize },
+        ::std::mem::offset_of!(_glibtop_cpu, softirq),
         64usize,
         concat!(
             "Offset of field: ",
@@ -26317,7 +26346,7 @@ fn bindgen_test_layout__glibtop_cpu() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_glibtop_cpu>())).frequency as *const _ as usize },
+        ::std::mem::offset_of!(_glibtop_cpu, frequency),
         72usize,
         concat!(
             "Offset of field: ",
@@ -26327,7 +26356,7 @@ fn bindgen_test_layout__glibtop_cpu() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_glibtop_cpu>())).xcpu_total as *const _ as usize },
+        ::std::mem::offset_of!(_glibtop_cpu, xcpu_total),
         80usize,
         concat!(
             "Offset of field: ",
@@ -26337,7 +26366,7 @@ fn bindgen_test_layout__glibtop_cpu() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_glibtop_cpu>())).xcpu_user as *const _ as usize },
+        ::std::mem::offset_of!(_glibtop_cpu, xcpu_user),
         8272usize,
         concat!(
             "Offset of field: ",
@@ -26347,7 +26376,7 @@ fn bindgen_test_layout__glibtop_cpu() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_glibtop_cpu>())).xcpu_nice as *const _ as usize },
+        ::std::mem::offset_of!(_glibtop_cpu, xcpu_nice),
         16464usize,
         concat!(
             "Offset of field: ",
@@ -26357,7 +26386,7 @@ fn bindgen_test_layout__glibtop_cpu() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_glibtop_cpu>())).xcpu_sys as *const _ as usize },
+        ::std::mem::offset_of!(_glibtop_cpu, xcpu_sys),
         24656usize,
         concat!(
             "Offset of field: ",
@@ -26367,7 +26396,7 @@ fn bindgen_test_layout__glibtop_cpu() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_glibtop_cpu>())).xcpu_idle as *const _ as usize },
+        ::std::mem::offset_of!(_glibtop_cpu, xcpu_idle),
         32848usize,
         concat!(
             "Offset of field: ",
@@ -26377,7 +26406,7 @@ fn bindgen_test_layout__glibtop_cpu() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_glibtop_cpu>())).xcpu_iowait as *const _ as usize },
+        ::std::mem::offset_of!(_glibtop_cpu, xcpu_iowait),
         41040usize,
         concat!(
             "Offset of field: ",
@@ -26387,7 +26416,7 @@ fn bindgen_test_layout__glibtop_cpu() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_glibtop_cpu>())).xcpu_irq as *const _ as usize },
+        ::std::mem::offset_of!(_glibtop_cpu, xcpu_irq),
         49232usize,
         concat!(
             "Offset of field: ",
@@ -26397,7 +26426,7 @@ fn bindgen_test_layout__glibtop_cpu() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_glibtop_cpu>())).xcpu_softirq as *const _ as usize },
+        ::std::mem::offset_of!(_glibtop_cpu, xcpu_softirq),
         57424usize,
         concat!(
             "Offset of field: ",
@@ -26407,7 +26436,7 @@ fn bindgen_test_layout__glibtop_cpu() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_glibtop_cpu>())).xcpu_flags as *const _ as usize },
+        ::std::mem::offset_of!(_glibtop_cpu, xcpu_flags),
         65616usize,
         concat!(
             "Offset of field: ",
@@ -26456,7 +26485,7 @@ fn bindgen_test_layout__glibtop_mem() {
         concat!("Alignment of ", stringify!(_glibtop_mem))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_glibtop_mem>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(_glibtop_mem, flags),
         0usize,
         concat!(
             "Offset of field: ",
@@ -26466,7 +26495,7 @@ fn bindgen_test_layout__glibtop_mem() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_glibtop_mem>())).total as *const _ as usize },
+        ::std::mem::offset_of!(_glibtop_mem, total),
         8usize,
         concat!(
             "Offset of field: ",
@@ -26476,7 +26505,7 @@ fn bindgen_test_layout__glibtop_mem() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_glibtop_mem>())).used as *const _ as usize },
+        ::std::mem::offset_of!(_glibtop_mem, used),
         16usize,
         concat!(
             "Offset of field: ",
@@ -26486,7 +26515,7 @@ fn bindgen_test_layout__glibtop_mem() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_glibtop_mem>())).free as *const _ as usize },
+        ::std::mem::offset_of!(_glibtop_mem, free),
         24usize,
         concat!(
             "Offset of field: ",
@@ -26496,7 +26525,7 @@ fn bindgen_test_layout__glibtop_mem() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_glibtop_mem>())).shared as *const _ as usize },
+        ::std::mem::offset_of!(_glibtop_mem, shared),
         32usize,
         concat!(
             "Offset of field: ",
@@ -26506,7 +26535,7 @@ fn bindgen_test_layout__glibtop_mem() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_glibtop_mem>())).buffer as *const _ as usize },
+        ::std::mem::offset_of!(_glibtop_mem, buffer),
         40usize,
         concat!(
             "Offset of field: ",
@@ -26516,7 +26545,7 @@ fn bindgen_test_layout__glibtop_mem() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_glibtop_mem>())).cached as *const _ as usize },
+        ::std::mem::offset_of!(_glibtop_mem, cached),
         48usize,
         concat!(
             "Offset of field: ",
@@ -26526,7 +26555,7 @@ fn bindgen_test_layout__glibtop_mem() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_glibtop_mem>())).user as *const _ as usize },
+        ::std::mem::offset_of!(_glibtop_mem, user),
         56usize,
         concat!(
             "Offset of field: ",
@@ -26536,7 +26565,7 @@ fn bindgen_test_layout__glibtop_mem() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_glibtop_mem>())).locked as *const _ as usize },
+        ::std::mem::offset_of!(_glibtop_mem, locked),
         64usize,
         concat!(
             "Offset of field: ",
@@ -26558,6 +26587,528 @@ extern "C" {
 extern "C" {
     pub fn glibtop_get_mem_s(server: *mut glibtop, buf: *mut glibtop_mem);
 }
+pub type glibtop_swap = _glibtop_swap;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _glibtop_swap {
+    pub flags: guint64,
+    pub total: guint64,
+    pub used: guint64,
+    pub free: guint64,
+    pub pagein: guint64,
+    pub pageout: guint64,
+}
+#[test]
+fn bindgen_test_layout__glibtop_swap() {
+    assert_eq!(
+        ::std::mem::size_of::<_glibtop_swap>(),
+        48usize,
+        concat!("Size of: ", stringify!(_glibtop_swap))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<_glibtop_swap>(),
+        8usize,
+        concat!("Alignment of ", stringify!(_glibtop_swap))
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(_glibtop_swap, flags),
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_glibtop_swap),
+            "::",
+            stringify!(flags)
+        )
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(_glibtop_swap, total),
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_glibtop_swap),
+            "::",
+            stringify!(total)
+        )
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(_glibtop_swap, used),
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_glibtop_swap),
+            "::",
+            stringify!(used)
+        )
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(_glibtop_swap, free),
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_glibtop_swap),
+            "::",
+            stringify!(free)
+        )
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(_glibtop_swap, pagein),
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_glibtop_swap),
+            "::",
+            stringify!(pagein)
+        )
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(_glibtop_swap, pageout),
+        40usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_glibtop_swap),
+            "::",
+            stringify!(pageout)
+        )
+    );
+}
+extern "C" {
+    pub fn glibtop_get_swap(buf: *mut glibtop_swap);
+}
+extern "C" {
+    pub fn glibtop_get_swap_l(server: *mut glibtop, buf: *mut glibtop_swap);
+}
+extern "C" {
+    pub fn _glibtop_init_swap_s(server: *mut glibtop);
+}
+extern "C" {
+    pub fn glibtop_get_swap_s(server: *mut glibtop, buf: *mut glibtop_swap);
+}
+pub type glibtop_loadavg = _glibtop_loadavg;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _glibtop_loadavg {
+    pub flags: guint64,
+    pub loadavg: [f64; 3usize],
+    pub nr_running: guint64,
+    pub nr_tasks: guint64,
+    pub last_pid: guint64,
+}
+#[test]
+fn bindgen_test_layout__glibtop_loadavg() {
+    assert_eq!(
+        ::std::mem::size_of::<_glibtop_loadavg>(),
+        56usize,
+        concat!("Size of: ", stringify!(_glibtop_loadavg))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<_glibtop_loadavg>(),
+        8usize,
+        concat!("Alignment of ", stringify!(_glibtop_loadavg))
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(_glibtop_loadavg, flags),
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_glibtop_loadavg),
+            "::",
+            stringify!(flags)
+        )
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(_glibtop_loadavg, loadavg),
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_glibtop_loadavg),
+            "::",
+            stringify!(loadavg)
+        )
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(_glibtop_loadavg, nr_running),
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_glibtop_loadavg),
+            "::",
+            stringify!(nr_running)
+        )
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(_glibtop_loadavg, nr_tasks),
+        40usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_glibtop_loadavg),
+            "::",
+            stringify!(nr_tasks)
+        )
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(_glibtop_loadavg, last_pid),
+        48usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_glibtop_loadavg),
+            "::",
+            stringify!(last_pid)
+        )
+    );
+}
+extern "C" {
+    pub fn glibtop_get_loadavg(buf: *mut glibtop_loadavg);
+}
+extern "C" {
+    pub fn glibtop_get_loadavg_l(server: *mut glibtop, buf: *mut glibtop_loadavg);
+}
+extern "C" {
+    pub fn _glibtop_init_loadavg_s(server: *mut glibtop);
+}
+extern "C" {
+    pub fn glibtop_get_loadavg_s(server: *mut glibtop, buf: *mut glibtop_loadavg);
+}
+pub type glibtop_uptime = _glibtop_uptime;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _glibtop_uptime {
+    pub flags: guint64,
+    pub uptime: f64,
+    pub idletime: f64,
+    pub boot_time: guint64,
+}
+#[test]
+fn bindgen_test_layout__glibtop_uptime() {
+    assert_eq!(
+        ::std::mem::size_of::<_glibtop_uptime>(),
+        32usize,
+        concat!("Size of: ", stringify!(_glibtop_uptime))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<_glibtop_uptime>(),
+        8usize,
+        concat!("Alignment of ", stringify!(_glibtop_uptime))
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(_glibtop_uptime, flags),
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_glibtop_uptime),
+            "::",
+            stringify!(flags)
+        )
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(_glibtop_uptime, uptime),
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_glibtop_uptime),
+            "::",
+            stringify!(uptime)
+        )
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(_glibtop_uptime, idletime),
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_glibtop_uptime),
+            "::",
+            stringify!(idletime)
+        )
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(_glibtop_uptime, boot_time),
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_glibtop_uptime),
+            "::",
+            stringify!(boot_time)
+        )
+    );
+}
+extern "C" {
+    pub fn glibtop_get_uptime(buf: *mut glibtop_uptime);
+}
+extern "C" {
+    pub fn glibtop_get_uptime_l(server: *mut glibtop, buf: *mut glibtop_uptime);
+}
+extern "C" {
+    pub fn _glibtop_init_uptime_s(server: *mut glibtop);
+}
+extern "C" {
+    pub fn glibtop_get_uptime_s(server: *mut glibtop, buf: *mut glibtop_uptime);
+}
+pub type glibtop_mountentry = _glibtop_mountentry;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _glibtop_mountentry {
+    pub dev: guint64,
+    pub devname: [::std::os::raw::c_char; 80usize],
+    pub mountdir: [::std::os::raw::c_char; 80usize],
+    pub type_: [::std::os::raw::c_char; 80usize],
+}
+#[test]
+fn bindgen_test_layout__glibtop_mountentry() {
+    assert_eq!(
+        ::std::mem::size_of::<_glibtop_mountentry>(),
+        248usize,
+        concat!("Size of: ", stringify!(_glibtop_mountentry))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<_glibtop_mountentry>(),
+        8usize,
+        concat!("Alignment of ", stringify!(_glibtop_mountentry))
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(_glibtop_mountentry, dev),
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_glibtop_mountentry),
+            "::",
+            stringify!(dev)
+        )
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(_glibtop_mountentry, devname),
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_glibtop_mountentry),
+            "::",
+            stringify!(devname)
+        )
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(_glibtop_mountentry, mountdir),
+        88usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_glibtop_mountentry),
+            "::",
+            stringify!(mountdir)
+        )
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(_glibtop_mountentry, type_),
+        168usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_glibtop_mountentry),
+            "::",
+            stringify!(type_)
+        )
+    );
+}
+pub type glibtop_mountlist = _glibtop_mountlist;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _glibtop_mountlist {
+    pub flags: guint64,
+    pub number: guint64,
+    pub total: guint64,
+    pub size: guint64,
+}
+#[test]
+fn bindgen_test_layout__glibtop_mountlist() {
+    assert_eq!(
+        ::std::mem::size_of::<_glibtop_mountlist>(),
+        32usize,
+        concat!("Size of: ", stringify!(_glibtop_mountlist))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<_glibtop_mountlist>(),
+        8usize,
+        concat!("Alignment of ", stringify!(_glibtop_mountlist))
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(_glibtop_mountlist, flags),
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_glibtop_mountlist),
+            "::",
+            stringify!(flags)
+        )
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(_glibtop_mountlist, number),
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_glibtop_mountlist),
+            "::",
+            stringify!(number)
+        )
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(_glibtop_mountlist, total),
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_glibtop_mountlist),
+            "::",
+            stringify!(total)
+        )
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(_glibtop_mountlist, size),
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_glibtop_mountlist),
+            "::",
+            stringify!(size)
+        )
+    );
+}
+extern "C" {
+    pub fn glibtop_get_mountlist(buf: *mut glibtop_mountlist, all_fs: ::std::os::raw::c_int) -> *mut glibtop_mountentry;
+}
+extern "C" {
+    pub fn glibtop_get_mountlist_l(
+        server: *mut glibtop,
+        buf: *mut glibtop_mountlist,
+        all_fs: ::std::os::raw::c_int,
+    ) -> *mut glibtop_mountentry;
+}
+extern "C" {
+    pub fn glibtop_get_mountlist_s(
+        server: *mut glibtop,
+        buf: *mut glibtop_mountlist,
+        all_fs: ::std::os::raw::c_int,
+    ) -> *mut glibtop_mountentry;
+}
+pub type glibtop_fsusage = _glibtop_fsusage;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _glibtop_fsusage {
+    pub flags: guint64,
+    pub blocks: guint64,
+    pub bfree: guint64,
+    pub bavail: guint64,
+    pub files: guint64,
+    pub ffree: guint64,
+    pub block_size: guint32,
+    pub read: guint64,
+    pub write: guint64,
+}
+#[test]
+fn bindgen_test_layout__glibtop_fsusage() {
+    assert_eq!(
+        ::std::mem::size_of::<_glibtop_fsusage>(),
+        72usize,
+        concat!("Size of: ", stringify!(_glibtop_fsusage))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<_glibtop_fsusage>(),
+        8usize,
+        concat!("Alignment of ", stringify!(_glibtop_fsusage))
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(_glibtop_fsusage, flags),
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_glibtop_fsusage),
+            "::",
+            stringify!(flags)
+        )
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(_glibtop_fsusage, blocks),
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_glibtop_fsusage),
+            "::",
+            stringify!(blocks)
+        )
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(_glibtop_fsusage, bfree),
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_glibtop_fsusage),
+            "::",
+            stringify!(bfree)
+        )
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(_glibtop_fsusage, bavail),
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_glibtop_fsusage),
+            "::",
+            stringify!(bavail)
+        )
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(_glibtop_fsusage, files),
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_glibtop_fsusage),
+            "::",
+            stringify!(files)
+        )
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(_glibtop_fsusage, ffree),
+        40usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_glibtop_fsusage),
+            "::",
+            stringify!(ffree)
+        )
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(_glibtop_fsusage, block_size),
+        48usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_glibtop_fsusage),
+            "::",
+            stringify!(block_size)
+        )
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(_glibtop_fsusage, read),
+        56usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_glibtop_fsusage),
+            "::",
+            stringify!(read)
+        )
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(_glibtop_fsusage, write),
+        64usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_glibtop_fsusage),
+            "::",
+            stringify!(write)
+        )
+    );
+}
+extern "C" {
+    pub fn glibtop_get_fsusage(buf: *mut glibtop_fsusage, mount_dir: *const ::std::os::raw::c_char);
+}
+extern "C" {
+    pub fn glibtop_get_fsusage_l(server: *mut glibtop, buf: *mut glibtop_fsusage, mount_dir: *const ::std::os::raw::c_char);
+}
+extern "C" {
+    pub fn glibtop_get_fsusage_s(server: *mut glibtop, buf: *mut glibtop_fsusage, mount_dir: *const ::std::os::raw::c_char);
+}
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct __locale_data {
@@ -26585,7 +27136,7 @@ fn bindgen_test_layout___va_list_tag() {
         concat!("Alignment of ", stringify!(__va_list_tag))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<__va_list_tag>())).gp_offset as *const _ as usize },
+        ::std::mem::offset_of!(__va_list_tag, gp_offset),
         0usize,
         concat!(
             "Offset of field: ",
@@ -26595,7 +27146,7 @@ fn bindgen_test_layout___va_list_tag() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<__va_list_tag>())).fp_offset as *const _ as usize },
+        ::std::mem::offset_of!(__va_list_tag, fp_offset),
         4usize,
         concat!(
             "Offset of field: ",
@@ -26605,7 +27156,7 @@ fn bindgen_test_layout___va_list_tag() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<__va_list_tag>())).overflow_arg_area as *const _ as usize },
+        ::std::mem::offset_of!(__va_list_tag, overflow_arg_area),
         8usize,
         concat!(
             "Offset of field: ",
@@ -26615,7 +27166,7 @@ fn bindgen_test_layout___va_list_tag() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<__va_list_tag>())).reg_save_area as *const _ as usize },
+        ::std::mem::offset_of!(__va_list_tag, reg_save_area),
         16usize,
         concat!(
             "Offset of field: ",
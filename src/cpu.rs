@@ -1,20 +1,26 @@
-#![allow(warnings)]
-
-use std::time::{Instant, Duration};
-use std::mem;
+use std::fmt;
+use std::error::Error;
 use std::sync::Arc;
-use super::gtop;
+use std::path::PathBuf;
 use super::GLibTopHandle;
+use super::procfs::ProcFs;
+use super::backend::{Backend, CpuCounters};
 use super::percent_usage::PercentUsage; // trait
+use super::cpu_breakdown::CpuBreakdown;
+use super::cpu_frequency::CpuFrequency;
+use super::snapshot::{CpuSnapshot, CpuDelta};
+use super::flags::{CpuFlags, CpuField};
 
-const N_CPUS: u32 = 4;
-
-/// Keep the state of the CPU: Holds a handle to a glibtop_cpu struct, a recording of the last time a measurement was taken,
-/// and an `Option<Arc<GLibTopHandle>>` to determine when to call `glibtop_init()` and `glibtop_close()`.
+/// Keep the state of the CPU: the `Backend` the counters are read from, and the counters and usage from the last measurement.
 pub struct Cpu {
-    last_time:      Instant,
-    cpu_handle:     gtop::glibtop_cpu,
-    glibtop_handle: Option<Arc<GLibTopHandle>> // if None, glibtop will be opened and closed manually in new() and drop()
+    backend:        Arc<dyn Backend>,
+    counters:       CpuCounters,       // as last read from the backend
+    last:           CpuSnapshot,       // counters at the last measurement, the baseline for the next one
+    delta:          CpuDelta,          // usage over the last good measurement
+    per_core_usage: Vec<f64>,          // busy fraction of each logical CPU over the last measurement
+    cpu_count:      u32,               // online CPUs as of the last measurement
+    usage:          f64,               // busy fraction over the last good measurement
+    regressions:    u64,               // measurements discarded because the counters went backwards
 }
 
 impl Cpu {
@@ -23,103 +29,342 @@ impl Cpu {
         true // we always have a cpu
     }
 
-    /// Instantiate a new Cpu. Calls `glibtop_init()` and `glibtop_close()` when dropped
+    /// Instantiate a new Cpu with its own `GLibTopHandle`. Calls `glibtop_init()` and `glibtop_close()` when dropped
     pub fn new() -> Cpu {
+        Cpu::with_handle(Arc::new(GLibTopHandle::new()))
+    }
+
+    /// Instantiate a new Cpu reading `root`/stat, e.g. the host's /proc mounted at `/host/proc` inside a container.
+    /// Shorthand for `Cpu::with_handle(Arc::new(ProcFs::with_root(root)))`
+    pub fn with_root<P: Into<PathBuf>>(root: P) -> Cpu {
+        Cpu::with_handle(Arc::new(ProcFs::with_root(root)))
+    }
+
+    /// Create a new object reading from any `Backend`, e.g. a shared `GLibTopHandle` or a `ProcFs`.
+    /// For a `GLibTopHandle`, `glibtop_close()` is called when all references to the handle are dropped accross the
+    /// entire scope of the program.
+    pub fn with_handle(h: Arc<dyn Backend>) -> Cpu {
+        let counters = h.cpu(); // because the first thing measure() does is compare against
+                                // the previous value, we need to read the counters explicitly here.
         let mut cpu = Cpu {
-            last_time:      Instant::now(),
-            cpu_handle:     unsafe { mem::zeroed() },
-            glibtop_handle: None,
+            last:           CpuSnapshot::from_counters(h.now(), &counters),
+            counters,
+            backend:        h,
+            delta:          CpuDelta::default(),
+            per_core_usage: Vec::new(),
+            cpu_count:      0,
+            usage:          0.0,
+            regressions:    0,
         };
-        unsafe {
-            gtop::glibtop_init();
-            gtop::glibtop_get_cpu(&mut cpu.cpu_handle); // because the first thing measure() does is store
-        }                                                      // the previous value, we need to call glibtop_get_cpu explicitly here.
-        cpu.measure(); // inital measurement
+        let _ = cpu.measure(); // inital measurement
         return cpu
     }
 
-    /// Create a new object with a handle to a glibtop object.
-    /// `glibtop_close()` is called when all references to the handle are dropped accross the entire scope of the program.
-    pub fn with_handle(h: Arc<GLibTopHandle>) -> Cpu {
-        let mut cpu = Cpu {
-            last_time:      Instant::now(),
-            cpu_handle:     unsafe { mem::zeroed() },
-            glibtop_handle: Some(h),
+    /// Take a snapshot of the CPU counters. Two snapshots can be compared with `CpuSnapshot::delta()` to get the usage
+    /// over any window. Does not affect what `Cpu::measure()` compares against.
+    pub fn snapshot(&mut self) -> CpuSnapshot {
+        CpuSnapshot::from_counters(self.backend.now(), &self.backend.cpu())
+    }
+
+    /// Return the number of busy jiffies and the total number of jiffies elapsed since the last measurement, summed over all CPUs.
+    /// Their ratio is the CPU usage over that interval, independent of the kernel tick rate and of how long we actually slept.
+    ///
+    /// If the kernel's counters went backwards (CPU hot-unplug, suspend/resume, a counter reset...) the interval is discarded,
+    /// `Err(CounterRegression)` is returned and the next measurement is taken relative to the new counter values.
+    /// A single CPU's counters going backwards only zeroes that CPU's entries for the interval.
+    pub fn measure(&mut self) -> Result<(u64, u64), CounterRegression> { // returns busy jiffies, total jiffies
+        self.counters = self.backend.cpu();
+        let now = CpuSnapshot::from_counters(self.backend.now(), &self.counters);
+        self.cpu_count = self.counters.online_cpus(); // recount every time, CPUs can be hot-plugged
+
+        let delta = now.delta(&self.last);
+        self.last = now; // the new values become the baseline for the next measurement, even after a regression
+        let delta = match delta {
+            Ok(d)  => d,
+            Err(e) => {
+                self.regressions += 1;
+                return Err(e)
+            }
         };
-        unsafe {
-            gtop::glibtop_get_cpu(&mut cpu.cpu_handle); // because the first thing measure() does is store
-        }                                                      // the previous value, we need to call glibtop_get_cpu explicitly here.
-        cpu.measure(); // inital measurement
-        return cpu
+
+        self.per_core_usage = delta.per_core().iter().map(|b| b.busy()).collect();
+        if delta.total() != 0 {
+            self.usage = delta.usage();
+        }
+        let jiffies = (delta.busy(), delta.total());
+        self.delta = delta;
+        Ok(jiffies)
+    }
+
+    /// The CPU usage between the last two calls to `Cpu::measure()`
+    pub fn last_delta(&self) -> &CpuDelta {
+        &self.delta
+    }
+
+    /// The number of measurements discarded so far because the kernel's counters went backwards.
+    pub fn regression_count(&self) -> u64 {
+        self.regressions
     }
 
-    /// Return the number of jiffies and the amount of time elapsed since the last measurement.
-    pub fn measure(&mut self) -> (u64, Duration) { // returns numer of jiffies, time since last measurement
-        let now = Instant::now();
-        let last_jiffies = self.total() - self.idle();
-        unsafe {
-            gtop::glibtop_get_cpu(&mut self.cpu_handle); // update number of jiffies
+    /// The fraction of time (0.0 to 1.0) each logical CPU spent busy between the last two calls to `Cpu::measure()`.
+    /// Index `i` holds CPU `i`. Useful for spotting a single pegged core that the aggregate number hides.
+    /// Empty if libgtop does not report per-CPU counters on this platform.
+    pub fn per_core_usage(&self) -> &[f64] {
+        &self.per_core_usage
+    }
+
+    /// The busy fraction scaled by how fast each CPU was clocked, 0.0 to 1.0: a core 50% busy at full clock counts as 0.5,
+    /// one 50% busy but throttled to half its maximum clock counts as 0.25. CPUs `freq` has no maximum for count at full clock.
+    /// Compare with `Cpu::percent_usage()` to tell real load from throttling or power saving.
+    pub fn frequency_weighted_usage(&self, freq: &CpuFrequency) -> f64 {
+        if self.per_core_usage.is_empty() { // no per-CPU counters, scale the aggregate by the average clock
+            return self.usage * freq.average_scale().unwrap_or(1.0)
         }
-        let time_diff = now - self.last_time;
-        let jiffy_diff = self.total() - self.idle() - last_jiffies;
-        self.last_time = now; // update time. jiffies already updated by call to glibtop_get_cpu
+        let weighted: f64 = self.per_core_usage.iter().enumerate().map(|(i, usage)| {
+            usage * freq.core(i as u32).and_then(|c| c.scale()).unwrap_or(1.0)
+        }).sum();
+        weighted / (self.cpu_count as f64)
+    }
+
+    /// The fraction of time spent in each state (user, system, iowait...) over all CPUs between the last two calls
+    /// to `Cpu::measure()`.
+    pub fn breakdown(&self) -> CpuBreakdown {
+        self.delta.breakdown()
+    }
+
+    /// Like `Cpu::breakdown()`, for each logical CPU. Index `i` holds CPU `i`.
+    pub fn per_core_breakdown(&self) -> &[CpuBreakdown] {
+        self.delta.per_core()
+    }
+
+    /// The number of online logical CPUs, as of the last call to `Cpu::measure()`.
+    pub fn cpu_count(&self) -> u32 {
+        self.cpu_count
+    }
 
-        (jiffy_diff, time_diff)
+    /// Which counters the backend filled in on this platform.
+    pub fn flags(&self) -> CpuFlags {
+        self.counters.flags
     }
 
-    /// Returns the last reported value from the glibtop_cpu struct. Only updates when Cpu::measure() is called.
-    pub fn total(&self) -> u64 {
-        self.cpu_handle.total
+    /// Returns the last reported counter, or `None` if the backend does not provide it on this platform.
+    /// Only updates when Cpu::measure() is called.
+    pub fn total(&self) -> Option<u64> {
+        self.flags().get(CpuField::Total, self.counters.aggregate.total)
     }
-    pub fn flags(&self) -> u64 {
-        self.cpu_handle.flags
+    pub fn user(&self) -> Option<u64> {
+        self.flags().get(CpuField::User, self.counters.aggregate.user)
     }
-    pub fn user(&self) -> u64 {
-        self.cpu_handle.user
+    pub fn nice(&self) -> Option<u64> {
+        self.flags().get(CpuField::Nice, self.counters.aggregate.nice)
     }
-    pub fn nice(&self) -> u64 {
-        self.cpu_handle.nice
+    pub fn sys(&self) -> Option<u64> {
+        self.flags().get(CpuField::Sys, self.counters.aggregate.sys)
     }
-    pub fn sys(&self) -> u64 {
-        self.cpu_handle.sys
+    pub fn idle(&self) -> Option<u64> {
+        self.flags().get(CpuField::Idle, self.counters.aggregate.idle)
     }
-    pub fn idle(&self) -> u64 {
-        self.cpu_handle.idle
+    pub fn iowait(&self) -> Option<u64> {
+        self.flags().get(CpuField::Iowait, self.counters.aggregate.iowait)
     }
-    pub fn iowait(&self) -> u64 {
-        self.cpu_handle.iowait
+    pub fn irq(&self) -> Option<u64> {
+        self.flags().get(CpuField::Irq, self.counters.aggregate.irq)
     }
-    pub fn irq(&self) -> u64 {
-        self.cpu_handle.irq
+    pub fn softirq(&self) -> Option<u64> {
+        self.flags().get(CpuField::Softirq, self.counters.aggregate.softirq)
     }
-    pub fn frequency(&self) -> u64 {
-        self.cpu_handle.frequency
+    pub fn frequency(&self) -> Option<u64> {
+        self.flags().get(CpuField::Frequency, self.counters.frequency)
+    }
+
+    /// Jiffies a hypervisor ran something else on this machine's virtual CPUs, or `None` if the backend doesn't report
+    /// it (libgtop doesn't). Not part of `Cpu::total()`. Only updates when Cpu::measure() is called.
+    pub fn steal(&self) -> Option<u64> {
+        self.counters.aggregate.steal
     }
 }
 
 impl PercentUsage for Cpu {
     fn percent_usage(&mut self) -> f64 {
-        let (j, d) = self.measure(); // Elapsed jiffies and elapsed time
-        let j = j as f64;
-        let d = float_seconds(d);
-        j/(100.0 * (N_CPUS as f64) * d) // Dividing by d gives an answer between 0 and 400.
-    }                                   // Divide by 100*N_CPUS to bring it between 0 and 1.0
+        let _ = self.measure(); // on a counter regression, report the last good value
+        self.usage
+    }
 }
 
-impl Drop for Cpu {
-    /// If the cpu struct was instantated with Cpu::new(), this will call `glibtop_close()`.
-    fn drop(&mut self) {
-        if self.glibtop_handle.is_none() { // opening and closing glibtop manually
-            unsafe {
-                gtop::glibtop_close();
-            }
-        }
+/// The kernel's CPU counters went backwards between two measurements, so the interval was discarded.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CounterRegression;
+
+impl fmt::Display for CounterRegression {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "CPU counters went backwards, measurement discarded")
     }
 }
 
-// convert std::time::Duration to a floating point number of seconds
-fn float_seconds(t: Duration) -> f64 {
-    let s = t.as_secs();      // number of seconds
-    let n = t.subsec_nanos(); // nanoseconds
-    (s as f64) + ((n as f64) * 1e-9)
+impl Error for CounterRegression {}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::time::Duration;
+    use super::*;
+    use super::super::backend::CpuCounters;
+    use super::super::cpu_breakdown::CpuTimes;
+    use super::super::scripted::Scripted;
+
+    // a CPU that has been busy for `busy` jiffies and idle for `idle`
+    fn times(busy: u64, idle: u64) -> CpuTimes {
+        CpuTimes { total: busy + idle, user: busy, idle, ..CpuTimes::default() }
+    }
+
+    // a Cpu reading `first` at creation, with the script left to queue the rest
+    fn scripted(first: &[CpuTimes]) -> (Arc<Scripted>, Cpu) {
+        let script = Arc::new(Scripted::new());
+        script.push_cpu(CpuCounters::from_cores(first));
+        let cpu = Cpu::with_handle(script.clone());
+        (script, cpu)
+    }
+
+    #[test]
+    fn usage_is_busy_over_total_jiffies() {
+        let (script, mut cpu) = scripted(&[times(100, 900)]);
+        script.push_cpu(CpuCounters::from_cores(&[times(130, 970)])); // 30 busy out of 100
+        assert_eq!(cpu.measure(), Ok((30, 100)));
+        assert_eq!(cpu.last_delta().usage(), 0.3);
+        assert_eq!(cpu.percent_usage(), 0.3); // the script ran dry, no ticks: the last good value stays
+        assert_eq!(cpu.last_delta().total(), 0);
+    }
+
+    #[test]
+    fn per_core_usage_and_count() {
+        let (script, mut cpu) = scripted(&[times(0, 0), times(0, 0)]);
+        script.push_cpu(CpuCounters::from_cores(&[times(100, 0), times(25, 75)]));
+        cpu.measure().unwrap();
+        assert_eq!(cpu.cpu_count(), 2);
+        assert_eq!(cpu.per_core_usage(), &[1.0, 0.25]);
+        assert_eq!(cpu.percent_usage(), 0.625);
+    }
+
+    #[test]
+    fn backend_chosen_at_runtime() {
+        let backends: Vec<Arc<dyn Backend>> = vec![
+            Arc::new(ProcFs::with_root(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/vm/proc"))),
+            Arc::new(Scripted::new()),
+        ];
+        let counts: Vec<u32> = backends.into_iter().map(|b| Cpu::with_handle(b).cpu_count()).collect();
+        assert_eq!(counts, vec![1, 1]); // the fixture's one CPU, and the fallback for a script with no cores
+    }
+
+    // cpu0 is clocked at half its maximum, cpu1 at full clock
+    const LAPTOP: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/laptop/sys");
+
+    #[test]
+    fn usage_weighted_by_clock() {
+        let (script, mut cpu) = scripted(&[times(0, 0), times(0, 0)]);
+        script.push_cpu(CpuCounters::from_cores(&[times(100, 0), times(50, 50)]));
+        cpu.measure().unwrap();
+        let freq = CpuFrequency::with_root(LAPTOP);
+        assert_eq!(cpu.frequency_weighted_usage(&freq), (1.0 * 0.5 + 0.5 * 1.0) / 2.0);
+    }
+
+    #[test]
+    fn usage_weighted_by_clock_with_an_offline_cpu() {
+        let with_gap = |a, b| {
+            let mut c = CpuCounters::from_cores(&[a, b]);
+            c.cores[1].cpu = 2; // cpu1 is offline
+            c
+        };
+        let script = Arc::new(Scripted::new());
+        script.push_cpu(with_gap(times(0, 0), times(0, 0)));
+        let mut cpu = Cpu::with_handle(script.clone());
+        script.push_cpu(with_gap(times(100, 0), times(50, 50)));
+        cpu.measure().unwrap();
+        assert_eq!((cpu.per_core_usage().len(), cpu.cpu_count()), (3, 2));
+        let freq = CpuFrequency::with_root(LAPTOP); // has no cpu2, which counts at full clock
+        assert_eq!(cpu.frequency_weighted_usage(&freq), (1.0 * 0.5 + 0.5 * 1.0) / 2.0); // the empty slot isn't a CPU
+    }
+
+    #[test]
+    fn usage_weighted_by_the_average_clock_without_per_cpu_counters() {
+        let aggregate_only = |busy, idle| CpuCounters { aggregate: times(busy, idle), ..CpuCounters::from_cores(&[]) };
+        let script = Arc::new(Scripted::new());
+        script.push_cpu(aggregate_only(0, 0));
+        let mut cpu = Cpu::with_handle(script.clone());
+        script.push_cpu(aggregate_only(50, 50));
+        cpu.measure().unwrap();
+        assert!(cpu.per_core_usage().is_empty());
+        assert_eq!(cpu.frequency_weighted_usage(&CpuFrequency::with_root(LAPTOP)), 0.5 * 0.75);
+    }
+
+    #[test]
+    fn cpus_counted_by_the_backend_without_per_cpu_counters() {
+        let aggregate_only = CpuCounters { aggregate: times(50, 50), cpu_count: 4, ..CpuCounters::from_cores(&[]) };
+        let script = Arc::new(Scripted::new());
+        script.push_cpu(aggregate_only);
+        let cpu = Cpu::with_handle(script);
+        assert!(cpu.per_core_usage().is_empty());
+        assert_eq!(cpu.cpu_count(), 4); // what libgtop reports as ncpu + 1, not 1
+    }
+
+    #[test]
+    fn snapshot_leaves_the_measured_counters_alone() {
+        let (script, mut cpu) = scripted(&[times(100, 900)]);
+        script.push_cpu(CpuCounters::from_cores(&[times(130, 970)]));
+        let snapshot = cpu.snapshot();
+        assert_eq!(snapshot.aggregate().total, 1100);
+        assert_eq!((cpu.total(), cpu.user()), (Some(1000), Some(100))); // still what measure() read
+        assert_eq!(cpu.measure(), Ok((30, 100))); // against its own last reading, not the snapshot
+    }
+
+    #[test]
+    fn elapsed_time_comes_from_the_backend_clock() {
+        let (script, mut cpu) = scripted(&[times(0, 0)]);
+        script.push_cpu(CpuCounters::from_cores(&[times(50, 150)]));
+        script.advance(Duration::from_secs(2));
+        cpu.measure().unwrap();
+        assert_eq!(cpu.last_delta().elapsed(), Duration::from_secs(2));
+    }
+
+    #[test]
+    fn counter_wraparound_discards_the_interval() {
+        let (script, mut cpu) = scripted(&[times(u64::MAX - 2000, 1000)]);
+        script.push_cpu(CpuCounters::from_cores(&[times(90, 1010)])); // busy wrapped past 0
+        script.push_cpu(CpuCounters::from_cores(&[times(100, 1100)]));
+
+        assert_eq!(cpu.measure(), Err(CounterRegression));
+        assert_eq!(cpu.regression_count(), 1);
+        assert_eq!(cpu.percent_usage(), 0.1); // measured from the wrapped values, not across the wrap
+        assert_eq!(cpu.regression_count(), 1);
+    }
+
+    #[test]
+    fn one_core_regressing_only_zeroes_that_core() {
+        let (script, mut cpu) = scripted(&[times(100, 100), times(100, 100)]);
+        let mut next = CpuCounters::from_cores(&[times(200, 200), times(150, 150)]);
+        next.cores[1].times = times(10, 10); // a counter reset on CPU 1 alone
+        next.aggregate = times(300, 300);
+        script.push_cpu(next);
+
+        cpu.measure().unwrap();
+        assert_eq!(cpu.per_core_usage(), &[0.5, 0.0]);
+    }
+
+    #[test]
+    fn hot_unplugged_cpu_reads_as_zero() {
+        let (script, mut cpu) = scripted(&[times(0, 0), times(0, 0), times(0, 0)]);
+        let mut next = CpuCounters::from_cores(&[times(50, 50), times(0, 0), times(100, 0)]);
+        next.cores.remove(1); // CPU 1 went offline
+        script.push_cpu(next);
+
+        cpu.measure().unwrap();
+        assert_eq!(cpu.cpu_count(), 2);
+        assert_eq!(cpu.per_core_usage(), &[0.5, 0.0, 1.0]);
+    }
+
+    #[test]
+    fn missing_fields_are_none() {
+        let (_, cpu) = scripted(&[times(10, 10)]);
+        assert_eq!(cpu.user(), Some(10));
+        assert_eq!(cpu.frequency(), None);
+    }
 }
@@ -0,0 +1,150 @@
+/// Raw jiffy counters for a single CPU, or summed over all CPUs, as reported by a `Backend`.
+/// The counters only ever grow, so they are meaningful relative to an earlier reading.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CpuTimes {
+    pub total:   u64,
+    pub user:    u64,
+    pub nice:    u64,
+    pub sys:     u64,
+    pub idle:    u64,
+    pub iowait:  u64,
+    pub irq:     u64,
+    pub softirq: u64,
+    pub steal:   Option<u64>, // time a hypervisor ran something else on this virtual CPU, left out of `total` as
+                              // libgtop does. None if the backend doesn't report it
+}
+
+impl CpuTimes {
+    /// Jiffies spent doing anything but idling
+    pub fn busy(&self) -> u64 {
+        self.total.saturating_sub(self.idle)
+    }
+
+    /// Did any counter go backwards since `earlier`? This happens on CPU hot-unplug, suspend/resume,
+    /// container migration or counter resets, and means the two readings can't be compared.
+    pub fn regressed_since(&self, earlier: &CpuTimes) -> bool {
+        self.total   < earlier.total  ||
+        self.user    < earlier.user   ||
+        self.nice    < earlier.nice   ||
+        self.sys     < earlier.sys    ||
+        self.idle    < earlier.idle   ||
+        self.iowait  < earlier.iowait ||
+        self.irq     < earlier.irq    ||
+        self.softirq < earlier.softirq ||
+        match (self.steal, earlier.steal) {
+            (Some(now), Some(then)) => now < then,
+            _                       => false,
+        }
+    }
+
+    /// How the time between `earlier` and `self` was spent. `None` if the counters regressed in between.
+    pub fn breakdown_since(&self, earlier: &CpuTimes) -> Option<CpuBreakdown> {
+        if self.regressed_since(earlier) {
+            return None
+        }
+        let total = self.total - earlier.total;
+        let steal = match (self.steal, earlier.steal) {
+            (Some(now), Some(then)) => Some(fraction(now - then, total + now - then)),
+            _                       => None,
+        };
+        if total == 0 {
+            return Some(CpuBreakdown { steal, ..CpuBreakdown::default() }) // no ticks in between, or all stolen
+        }
+        let user    = self.user - earlier.user;
+        let nice    = self.nice - earlier.nice;
+        let sys     = self.sys - earlier.sys;
+        let idle    = self.idle - earlier.idle;
+        let iowait  = self.iowait - earlier.iowait;
+        let irq     = self.irq - earlier.irq;
+        let softirq = self.softirq - earlier.softirq;
+
+        let t = total as f64;
+        Some(CpuBreakdown {
+            user:    (user as f64) / t,
+            nice:    (nice as f64) / t,
+            system:  (sys as f64) / t,
+            idle:    (idle as f64) / t,
+            iowait:  (iowait as f64) / t,
+            irq:     (irq as f64) / t,
+            softirq: (softirq as f64) / t,
+            steal,
+        })
+    }
+}
+
+/// The fraction of an interval (0.0 to 1.0) a CPU spent in each state. The fields but `steal` add up to 1.0,
+/// unless the CPU got no time at all, in which case they are all 0.0.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct CpuBreakdown {
+    pub user:    f64,
+    pub nice:    f64,
+    pub system:  f64,
+    pub idle:    f64,
+    pub iowait:  f64,
+    pub irq:     f64,
+    pub softirq: f64,
+    pub steal:   Option<f64>, // the fraction of the wall time the hypervisor gave this CPU's host time to other guests,
+                              // like top's "st". The other fields only cover the time the CPU did get
+}
+
+impl CpuBreakdown {
+    /// The fraction of time spent doing anything but idling. Waiting on I/O counts as busy, as it does in `Cpu::measure()`.
+    pub fn busy(&self) -> f64 {
+        if (CpuBreakdown { steal: None, ..*self }) == CpuBreakdown::default() {
+            return 0.0
+        }
+        1.0 - self.idle
+    }
+}
+
+// `part` / `whole`, 0 if both are
+fn fraction(part: u64, whole: u64) -> f64 {
+    if whole == 0 {
+        return 0.0
+    }
+    (part as f64) / (whole as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn breakdown_fractions() {
+        let earlier = CpuTimes::default();
+        let now = CpuTimes { total: 100, user: 40, sys: 10, idle: 30, iowait: 10, irq: 10, ..CpuTimes::default() };
+        let b = now.breakdown_since(&earlier).unwrap();
+        assert_eq!((b.user, b.system, b.idle, b.iowait, b.irq), (0.4, 0.1, 0.3, 0.1, 0.1));
+        assert_eq!(b.busy(), 0.7);
+    }
+
+    #[test]
+    fn steal_is_a_fraction_of_the_wall_time() {
+        let earlier = CpuTimes { steal: Some(0), ..CpuTimes::default() };
+        let now = CpuTimes { total: 75, user: 75, steal: Some(25), ..CpuTimes::default() };
+        let b = now.breakdown_since(&earlier).unwrap();
+        assert_eq!(b.steal, Some(0.25)); // 25 out of 100 jiffies went to other guests
+        assert_eq!(b.user, 1.0);          // and this one was busy the whole time it got
+        assert_eq!(b.busy(), 1.0);
+
+        let all_stolen = CpuTimes { steal: Some(10), ..CpuTimes::default() };
+        assert_eq!(all_stolen.breakdown_since(&earlier).unwrap().steal, Some(1.0));
+        assert_eq!(CpuTimes::default().breakdown_since(&CpuTimes::default()).unwrap().steal, None); // not reported
+    }
+
+    #[test]
+    fn no_ticks_is_all_zeroes() {
+        let t = CpuTimes { total: 100, idle: 100, ..CpuTimes::default() };
+        let b = t.breakdown_since(&t).unwrap();
+        assert_eq!(b, CpuBreakdown::default());
+        assert_eq!(b.busy(), 0.0);
+    }
+
+    #[test]
+    fn any_counter_going_backwards_is_a_regression() {
+        let earlier = CpuTimes { total: 100, softirq: 5, ..CpuTimes::default() };
+        let now = CpuTimes { total: 200, softirq: 4, ..CpuTimes::default() };
+        assert!(now.regressed_since(&earlier));
+        assert_eq!(now.breakdown_since(&earlier), None);
+    }
+}
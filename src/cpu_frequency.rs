@@ -0,0 +1,162 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use super::util::{SYSFS, read_string};
+
+/// The clock of one logical CPU, in Hz
+#[derive(Clone, Debug, PartialEq)]
+pub struct CoreFrequency {
+    pub cpu:      u32,            // the CPU number, as in /sys/devices/system/cpu/cpuN
+    pub current:  u64,
+    pub min:      Option<u64>,    // the lowest clock the hardware supports
+    pub max:      Option<u64>,    // the highest clock the hardware supports, including turbo where the driver reports it
+    pub governor: Option<String>, // the cpufreq scaling governor, e.g. powersave or performance
+}
+
+impl CoreFrequency {
+    /// The current clock as a fraction of the maximum, 0.0 to 1.0. Turbo clocks above the reported maximum count as 1.0.
+    pub fn scale(&self) -> Option<f64> {
+        match self.max {
+            Some(max) if max > 0 => Some(((self.current as f64) / (max as f64)).min(1.0)),
+            _                    => None,
+        }
+    }
+}
+
+/// The clock of each logical CPU, from /sys/devices/system/cpu/cpu*/cpufreq.
+/// Unlike `Cpu::frequency()`, which libgtop often leaves at zero, this is per-core and includes the scaling limits.
+/// The sysfs root can be moved, to read a fixture tree or the host's /sys from inside a container.
+pub struct CpuFrequency {
+    root:  PathBuf,
+    cores: Vec<CoreFrequency>,
+}
+
+impl CpuFrequency {
+    /// Does this machine expose cpufreq? Many virtual machines and containers don't.
+    pub fn is_available() -> bool {
+        CpuFrequency::is_available_at(SYSFS)
+    }
+
+    /// Does the /sys at `root` expose cpufreq, e.g. the host's at `/host/sys`?
+    pub fn is_available_at<P: AsRef<Path>>(root: P) -> bool {
+        cpufreq_dirs(root.as_ref()).iter().any(|(_, dir)| current_khz(dir).is_some())
+    }
+
+    /// Read the CPU clocks under /sys
+    pub fn new() -> CpuFrequency {
+        CpuFrequency::with_root(SYSFS)
+    }
+
+    /// Read the CPU clocks under `root` instead of /sys, e.g. `/host/sys`
+    pub fn with_root<P: Into<PathBuf>>(root: P) -> CpuFrequency {
+        let mut f = CpuFrequency {
+            root:  root.into(),
+            cores: Vec::new(),
+        };
+        f.measure();
+        return f
+    }
+
+    /// Re-read the clock of every CPU. CPUs without a readable current clock, e.g. offline ones, are skipped.
+    pub fn measure(&mut self) {
+        self.cores = cpufreq_dirs(&self.root).into_iter().filter_map(|(cpu, dir)| {
+            Some(CoreFrequency {
+                cpu,
+                current:  current_khz(&dir)? * 1000,
+                min:      read_khz(&dir, "cpuinfo_min_freq").or_else(|| read_khz(&dir, "scaling_min_freq")).map(|f| f * 1000),
+                max:      read_khz(&dir, "cpuinfo_max_freq").or_else(|| read_khz(&dir, "scaling_max_freq")).map(|f| f * 1000),
+                governor: read_string(&dir.join("scaling_governor")),
+            })
+        }).collect();
+    }
+
+    /// The clock of each CPU that reports one, sorted by CPU number
+    pub fn cores(&self) -> &[CoreFrequency] {
+        &self.cores
+    }
+
+    /// The clock of logical CPU `cpu`, if it reports one
+    pub fn core(&self, cpu: u32) -> Option<&CoreFrequency> {
+        self.cores.binary_search_by_key(&cpu, |c| c.cpu).ok().map(|i| &self.cores[i])
+    }
+
+    /// The average current clock over all CPUs, in Hz
+    pub fn average(&self) -> Option<u64> {
+        if self.cores.is_empty() {
+            return None
+        }
+        Some(self.cores.iter().map(|c| c.current).sum::<u64>() / (self.cores.len() as u64))
+    }
+
+    /// The average of `CoreFrequency::scale()` over the CPUs that report a maximum
+    pub fn average_scale(&self) -> Option<f64> {
+        let scales: Vec<f64> = self.cores.iter().filter_map(|c| c.scale()).collect();
+        if scales.is_empty() { None } else { Some(scales.iter().sum::<f64>() / (scales.len() as f64)) }
+    }
+}
+
+// (N, cpuN/cpufreq) for every CPU, sorted by CPU number
+fn cpufreq_dirs(root: &Path) -> Vec<(u32, PathBuf)> {
+    let mut dirs: Vec<(u32, PathBuf)> = match fs::read_dir(root.join("devices/system/cpu")) {
+        Ok(e)  => e.filter_map(|e| {
+            let e = e.ok()?;
+            let name = e.file_name().into_string().ok()?;
+            if !name.starts_with("cpu") {
+                return None
+            }
+            Some((name[3..].parse().ok()?, e.path().join("cpufreq"))) // skips cpufreq/, cpuidle/...
+        }).collect(),
+        Err(_) => Vec::new(),
+    };
+    dirs.sort();
+    dirs
+}
+
+// cpuinfo_cur_freq is what the hardware reports but is only readable by root, scaling_cur_freq is always readable
+fn current_khz(dir: &Path) -> Option<u64> {
+    read_khz(dir, "scaling_cur_freq").or_else(|| read_khz(dir, "cpuinfo_cur_freq"))
+}
+
+fn read_khz(dir: &Path, file: &str) -> Option<u64> {
+    read_string(&dir.join(file))?.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LAPTOP: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/laptop/sys");
+
+    #[test]
+    fn cores_from_fixture() {
+        let frequency = CpuFrequency::with_root(LAPTOP);
+        assert_eq!(frequency.cores().len(), 2); // cpufreq/ and cpuidle/ aren't CPUs
+        assert_eq!(frequency.core(0), Some(&CoreFrequency {
+            cpu:      0,
+            current:  1_800_000_000,
+            min:      Some(400_000_000),
+            max:      Some(3_600_000_000),
+            governor: Some("powersave".to_string()),
+        }));
+        assert_eq!(frequency.core(1), Some(&CoreFrequency { // only cpuinfo_cur_freq and the scaling limits
+            cpu:      1,
+            current:  3_600_000_000,
+            min:      Some(800_000_000),
+            max:      Some(3_000_000_000),
+            governor: Some("performance".to_string()),
+        }));
+    }
+
+    #[test]
+    fn averages_from_fixture() {
+        let frequency = CpuFrequency::with_root(LAPTOP);
+        assert_eq!(frequency.average(), Some(2_700_000_000));
+        assert_eq!(frequency.core(1).unwrap().scale(), Some(1.0)); // turbo above the scaling maximum
+        assert_eq!(frequency.average_scale(), Some(0.75));
+    }
+
+    #[test]
+    fn availability_under_a_root() {
+        assert!(CpuFrequency::is_available_at(LAPTOP));
+        assert!(!CpuFrequency::is_available_at(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/vm/proc")));
+    }
+}
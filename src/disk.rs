@@ -0,0 +1,245 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+use super::percent_usage::PercentUsage;
+use super::util::PROC;
+
+const SECTOR_SIZE: u64 = 512; // /proc/diskstats always counts 512 byte sectors, whatever the device's real sector size
+
+/// The counters of one block device since boot, from /proc/diskstats
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DiskCounters {
+    pub reads:         u64, // completed requests
+    pub read_sectors:  u64,
+    pub read_ms:       u64, // time spent on reads, summed over all requests
+    pub writes:        u64,
+    pub write_sectors: u64,
+    pub write_ms:      u64,
+    pub busy_ms:       u64, // time with at least one request in flight
+}
+
+impl DiskCounters {
+    // did any counter go backwards? Happens when a device is removed and another one takes its name
+    fn regressed_since(&self, earlier: &DiskCounters) -> bool {
+        self.reads         < earlier.reads         ||
+        self.read_sectors  < earlier.read_sectors  ||
+        self.read_ms       < earlier.read_ms       ||
+        self.writes        < earlier.writes        ||
+        self.write_sectors < earlier.write_sectors ||
+        self.write_ms      < earlier.write_ms      ||
+        self.busy_ms       < earlier.busy_ms
+    }
+}
+
+/// The activity of one block device between the last two calls to `Disk::measure()`
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DiskRates {
+    pub name:                String,
+    pub read_bytes_per_sec:  f64,
+    pub write_bytes_per_sec: f64,
+    pub read_iops:           f64,
+    pub write_iops:          f64,
+    pub read_latency_ms:     Option<f64>, // average time per read request, `None` if there were no reads
+    pub write_latency_ms:    Option<f64>,
+    pub utilization:         f64,         // fraction of the interval (0.0 to 1.0) the device was busy
+}
+
+/// Keep the state of the block devices: the counters of every device at the last measurement, and the
+/// activity of each one over that measurement.
+pub struct Disk {
+    root:      PathBuf,
+    last_time: Instant,
+    counters:  BTreeMap<String, DiskCounters>,
+    rates:     Vec<DiskRates>,
+}
+
+impl Disk {
+    /// Is there a /proc/diskstats to read?
+    pub fn is_available() -> bool {
+        Disk::is_available_at(PROC)
+    }
+
+    /// Is there a diskstats to read under `root`, e.g. the host's /proc at `/host/proc`?
+    pub fn is_available_at<P: AsRef<Path>>(root: P) -> bool {
+        root.as_ref().join("diskstats").exists()
+    }
+
+    /// Instantiate a new Disk and take an initial measurement
+    pub fn new() -> Disk {
+        Disk::with_root(PROC)
+    }
+
+    /// Read `root`/diskstats instead of /proc/diskstats, e.g. the host's /proc mounted at `/host/proc`
+    pub fn with_root<P: Into<PathBuf>>(root: P) -> Disk {
+        let mut d = Disk {
+            root:      root.into(),
+            last_time: Instant::now(),
+            counters:  BTreeMap::new(),
+            rates:     Vec::new(),
+        };
+        let _ = d.measure(); // inital measurement, nothing to report if /proc/diskstats can't be read
+        return d
+    }
+
+    /// Re-read the device counters and compute the activity of each device since the last measurement.
+    /// A device that just appeared has no rates until the next measurement, one whose counters went backwards is
+    /// re-baselined and has no rates for this interval.
+    pub fn measure(&mut self) -> io::Result<()> {
+        self.measure_at(Instant::now())
+    }
+
+    // `measure()`, with the time of the reading passed in so tests don't depend on the wall clock
+    pub(crate) fn measure_at(&mut self, now: Instant) -> io::Result<()> {
+        let counters = parse_diskstats(&fs::read_to_string(self.root.join("diskstats"))?);
+        let elapsed = now.duration_since(self.last_time).as_secs_f64();
+
+        self.rates = counters.iter().filter_map(|(name, c)| {
+            let last = self.counters.get(name)?;
+            if c.regressed_since(last) || elapsed <= 0.0 {
+                return None
+            }
+            let reads  = c.reads - last.reads;
+            let writes = c.writes - last.writes;
+            Some(DiskRates {
+                name:                name.clone(),
+                read_bytes_per_sec:  ((c.read_sectors - last.read_sectors) * SECTOR_SIZE) as f64 / elapsed,
+                write_bytes_per_sec: ((c.write_sectors - last.write_sectors) * SECTOR_SIZE) as f64 / elapsed,
+                read_iops:           (reads as f64) / elapsed,
+                write_iops:          (writes as f64) / elapsed,
+                read_latency_ms:     latency(c.read_ms - last.read_ms, reads),
+                write_latency_ms:    latency(c.write_ms - last.write_ms, writes),
+                utilization:         (((c.busy_ms - last.busy_ms) as f64) / (elapsed * 1000.0)).min(1.0),
+            })
+        }).collect();
+        self.counters = counters;
+        self.last_time = now;
+        Ok(())
+    }
+
+    /// The names of the devices seen at the last measurement, sorted. Includes partitions.
+    pub fn devices(&self) -> Vec<&str> {
+        self.counters.keys().map(|k| k.as_str()).collect()
+    }
+
+    /// The raw counters of a device, as of the last measurement
+    pub fn counters(&self, name: &str) -> Option<&DiskCounters> {
+        self.counters.get(name)
+    }
+
+    /// The activity of every device between the last two calls to `Disk::measure()`, sorted by name
+    pub fn rates(&self) -> &[DiskRates] {
+        &self.rates
+    }
+
+    /// The activity of one device between the last two calls to `Disk::measure()`
+    pub fn rate(&self, name: &str) -> Option<&DiskRates> {
+        self.rates.iter().find(|r| r.name == name)
+    }
+}
+
+impl PercentUsage for Disk {
+    /// The utilization of the busiest device. A single saturated disk is enough to make the machine I/O bound.
+    fn percent_usage(&mut self) -> f64 {
+        let _ = self.measure();
+        self.rates.iter().map(|r| r.utilization).fold(0.0, f64::max)
+    }
+}
+
+// average milliseconds per request
+fn latency(ms: u64, requests: u64) -> Option<f64> {
+    if requests == 0 {
+        return None
+    }
+    Some((ms as f64) / (requests as f64))
+}
+
+/// Parse the contents of /proc/diskstats. Malformed lines are skipped.
+pub(crate) fn parse_diskstats(contents: &str) -> BTreeMap<String, DiskCounters> {
+    //    8       0 sda 51383 13553 3861450 28155 120345 82467 6428154 171204 0 95796 213416 0 0 0 0
+    contents.lines().filter_map(|line| {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        if fields.len() < 14 {
+            return None
+        }
+        let n: Vec<u64> = fields[3..14].iter().map(|f| f.parse()).collect::<Result<_, _>>().ok()?;
+        Some((fields[2].to_string(), DiskCounters {
+            reads:         n[0],
+            read_sectors:  n[2],
+            read_ms:       n[3],
+            writes:        n[4],
+            write_sectors: n[6],
+            write_ms:      n[7],
+            busy_ms:       n[9],
+        }))
+    }).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+    use super::*;
+
+    const VM: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/vm/proc");
+
+    #[test]
+    fn devices_from_fixture() {
+        let disk = Disk::with_root(VM);
+        assert_eq!(disk.devices().len(), 11);
+        assert_eq!(disk.counters("vda"), Some(&DiskCounters {
+            reads:         6243,
+            read_sectors:  2070922,
+            read_ms:       9146,
+            writes:        4938,
+            write_sectors: 3187168,
+            write_ms:      6789,
+            busy_ms:       4136,
+        }));
+        assert_eq!(disk.counters("vdb").unwrap().reads, 6);
+    }
+
+    #[test]
+    fn idle_devices_are_not_utilized() {
+        let mut disk = Disk::with_root(VM);
+        let start = disk.last_time;
+        disk.measure_at(start + Duration::from_secs(1)).unwrap();
+        let vda = disk.rate("vda").unwrap();
+        assert_eq!(vda.utilization, 0.0);
+        assert_eq!(vda.read_latency_ms, None); // no reads in between
+    }
+
+    #[test]
+    fn rates_over_the_interval() {
+        let mut disk = Disk::with_root(VM);
+        let start = disk.last_time;
+        { // vda did 10 reads of 8 sectors each since, taking 50ms, and was busy half the time
+            let last = disk.counters.get_mut("vda").unwrap();
+            last.reads -= 10;
+            last.read_sectors -= 80;
+            last.read_ms -= 50;
+            last.busy_ms -= 1000;
+        }
+        disk.measure_at(start + Duration::from_secs(2)).unwrap();
+        let vda = disk.rate("vda").unwrap();
+        assert_eq!(vda.read_iops, 5.0);
+        assert_eq!(vda.read_bytes_per_sec, 20480.0);
+        assert_eq!(vda.read_latency_ms, Some(5.0));
+        assert_eq!(vda.write_latency_ms, None);
+        assert_eq!(vda.utilization, 0.5);
+    }
+
+    #[test]
+    fn no_rates_without_time_passing() {
+        let mut disk = Disk::with_root(VM);
+        let start = disk.last_time;
+        disk.measure_at(start).unwrap();
+        assert!(disk.rates().is_empty());
+    }
+
+    #[test]
+    fn availability_under_a_root() {
+        assert!(Disk::is_available_at(VM));
+        assert!(!Disk::is_available_at(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/missing")));
+    }
+}
@@ -0,0 +1,209 @@
+use std::sync::Arc;
+use std::mem;
+use std::ffi::CString;
+use std::path::Path;
+use super::gtop;
+use super::sysdeps;
+use super::GLibTopHandle;
+use super::procfs::ProcFs;
+use super::percent_usage::PercentUsage;
+use super::flags::{FsUsageFlags, FsUsageField};
+
+// filesystems that don't live on a storage device
+const PSEUDO_FS_TYPES: &[&str] = &[
+    "proc", "sysfs", "devtmpfs", "devpts", "tmpfs", "ramfs", "overlay", "squashfs", "cgroup", "cgroup2", "securityfs",
+    "pstore", "debugfs", "tracefs", "configfs", "fusectl", "mqueue", "hugetlbfs", "bpf", "autofs", "binfmt_misc",
+    "efivarfs", "nsfs", "rpc_pipefs", "selinuxfs",
+];
+
+/// A mounted filesystem, as listed by `Filesystem::mounts()`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Mount {
+    pub device:    String, // e.g. /dev/sda1
+    pub mount_dir: String, // e.g. /var
+    pub fs_type:   String, // e.g. ext4
+}
+
+impl Mount {
+    /// Is this a pseudo filesystem (proc, sysfs, tmpfs, overlay...) rather than one backed by a storage device?
+    pub fn is_pseudo(&self) -> bool {
+        PSEUDO_FS_TYPES.contains(&self.fs_type.as_str())
+    }
+}
+
+/// The usage of one mounted filesystem: a handle to a `glibtop_fsusage` struct and an `Option<Arc<GLibTopHandle>>`
+/// to determine when `glibtop_init()` and `glibtop_close()` should be called
+pub struct Filesystem {
+    mount: Mount,
+    mount_dir: CString,
+    gtop_fsusage: gtop::glibtop_fsusage,
+    handle: Option<Arc<GLibTopHandle>>
+}
+
+impl Filesystem {
+    /// Always returns true, every platform libgtop supports has filesystems
+    pub fn is_available() -> bool {
+        true
+    }
+
+    /// List the mounted filesystems. Pseudo filesystems (proc, sysfs, tmpfs, overlay...) are left out
+    /// unless `include_pseudo` is true. Call this while holding a `GLibTopHandle` or another glibtop user.
+    pub fn mounts(include_pseudo: bool) -> Vec<Mount> {
+        sysdeps::mounts().into_iter().filter(|m| include_pseudo || !m.is_pseudo()).collect()
+    }
+
+    /// Like `Filesystem::mounts()`, but the mounts of pid 1 under `root` instead of our own, e.g. the host's
+    /// when its /proc is mounted at `/host/proc` inside a container
+    pub fn mounts_with_root<P: AsRef<Path>>(root: P, include_pseudo: bool) -> Vec<Mount> {
+        let mounts = ProcFs::with_root(root.as_ref()).mounts("1");
+        mounts.into_iter().filter(|m| include_pseudo || !m.is_pseudo()).collect()
+    }
+
+    /// Instantate a new Filesystem for a mount, call `glibtop_init()`. `glibtop_close()` will be called when this
+    /// object is dropped
+    pub fn new(mount: Mount) -> Filesystem {
+        sysdeps::init();
+        let mount_dir = mount.mount_dir.clone();
+        Filesystem::init(mount, &mount_dir, None)
+    }
+
+    /// Instantate a new Filesystem for a mount listed by `Filesystem::mounts_with_root()`, call `glibtop_init()`.
+    /// The usage is read through `root`/1/root, where pid 1 sees its filesystems, which takes the same privileges as
+    /// reading /proc/1/root. `glibtop_close()` will be called when this object is dropped
+    pub fn with_root<P: AsRef<Path>>(mount: Mount, root: P) -> Filesystem {
+        sysdeps::init();
+        let seen_by_init = root.as_ref().join("1/root").join(mount.mount_dir.trim_start_matches('/'));
+        Filesystem::init(mount, &seen_by_init.to_string_lossy(), None)
+    }
+
+    /// Instantate a new Filesystem for a mount, does not call `glibtop_init()`. `glibtop_close()` will be called when all
+    /// references to this handle are dropped.
+    pub fn with_handle(mount: Mount, h: Arc<GLibTopHandle>) -> Filesystem {
+        let mount_dir = mount.mount_dir.clone();
+        Filesystem::init(mount, &mount_dir, Some(h))
+    }
+
+    // `path` is where to statvfs() the mount, its mount_dir unless it belongs to another root
+    fn init(mount: Mount, path: &str, handle: Option<Arc<GLibTopHandle>>) -> Filesystem {
+        let mut f = Filesystem {
+            mount_dir: CString::new(path).unwrap_or_default(), // mount points can't contain NUL
+            mount,
+            gtop_fsusage: unsafe { mem::zeroed() },
+            handle,
+        };
+        f.measure();
+        return f
+    }
+
+    /// Update the values from glibtop
+    pub fn measure(&mut self) {
+        sysdeps::get_fsusage(&mut self.gtop_fsusage, &self.mount_dir);
+    }
+
+    /// The mount this filesystem was created for
+    pub fn mount(&self) -> &Mount {
+        &self.mount
+    }
+
+    /// Which fields of the glibtop_fsusage struct libgtop filled in on this platform.
+    pub fn flags(&self) -> FsUsageFlags {
+        FsUsageFlags::from_bits(self.gtop_fsusage.flags)
+    }
+
+    /// Total size, in bytes. Not updated unless `Filesystem::measure()` is called
+    pub fn size(&self) -> Option<u64> {
+        self.flags().get(FsUsageField::Blocks, self.blocks_to_bytes(self.gtop_fsusage.blocks))
+    }
+
+    /// Bytes in use. Not updated unless `Filesystem::measure()` is called
+    pub fn used(&self) -> Option<u64> {
+        let f = self.gtop_fsusage;
+        self.flags().get(FsUsageField::Bfree, self.blocks_to_bytes(f.blocks.saturating_sub(f.bfree)))
+    }
+
+    /// Bytes available to unprivileged users. Usually less than the free space, part of which is reserved for root.
+    /// Not updated unless `Filesystem::measure()` is called
+    pub fn available(&self) -> Option<u64> {
+        self.flags().get(FsUsageField::Bavail, self.blocks_to_bytes(self.gtop_fsusage.bavail))
+    }
+
+    /// Total number of inodes. Not updated unless `Filesystem::measure()` is called
+    pub fn inodes(&self) -> Option<u64> {
+        self.flags().get(FsUsageField::Files, self.gtop_fsusage.files)
+    }
+
+    /// Number of inodes in use. Not updated unless `Filesystem::measure()` is called
+    pub fn inodes_used(&self) -> Option<u64> {
+        let f = self.gtop_fsusage;
+        self.flags().get(FsUsageField::Ffree, f.files.saturating_sub(f.ffree))
+    }
+
+    /// The fraction of inodes (0.0 to 1.0) in use. Running out of inodes fails writes just like running out of space.
+    pub fn inode_usage(&self) -> Option<f64> {
+        match (self.inodes(), self.inodes_used()) {
+            (Some(total), Some(used)) if total > 0 => Some((used as f64) / (total as f64)),
+            _                                      => None,
+        }
+    }
+
+    fn blocks_to_bytes(&self, blocks: u64) -> u64 {
+        blocks * (self.gtop_fsusage.block_size as u64)
+    }
+}
+
+impl PercentUsage for Filesystem {
+    /// Used space as a fraction of the space unprivileged users can use, the same number `df` reports
+    fn percent_usage(&mut self) -> f64 {
+        self.measure();
+        let f = self.gtop_fsusage;
+        let used = f.blocks.saturating_sub(f.bfree);
+        if used + f.bavail == 0 {
+            return 0.0 // empty pseudo filesystem
+        }
+        (used as f64) / ((used + f.bavail) as f64)
+    }
+}
+
+impl Drop for Filesystem {
+    fn drop(&mut self) {
+        if self.handle.is_none() { // manual glibtop init and close
+            sysdeps::close()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VM: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/vm/proc");
+
+    fn mount(device: &str, mount_dir: &str, fs_type: &str) -> Mount {
+        Mount { device: device.to_string(), mount_dir: mount_dir.to_string(), fs_type: fs_type.to_string() }
+    }
+
+    #[test]
+    fn mounts_from_fixture() {
+        assert_eq!(Filesystem::mounts_with_root(VM, false), vec![mount("/dev/vda", "/", "ext4")]);
+        let all = Filesystem::mounts_with_root(VM, true);
+        assert_eq!(all.len(), 19);
+        assert_eq!(all[0], mount("proc", "/proc", "proc"));
+    }
+
+    #[test]
+    fn usage_through_the_root() {
+        // the fixture's 1/root is a directory in this repository, so it reports the usage of whatever holds the repo
+        let mut root = Filesystem::with_root(mount("/dev/vda", "/", "ext4"), VM);
+        assert!(root.size().unwrap() > 0);
+        assert!(root.used().unwrap() <= root.size().unwrap());
+        assert!(root.percent_usage() <= 1.0);
+        let boot = Filesystem::with_root(mount("/dev/vda1", "/boot", "ext4"), VM);
+        assert_eq!(boot.size(), None); // not there under 1/root
+    }
+
+    #[test]
+    fn missing_root_has_no_mounts() {
+        let missing = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/missing");
+        assert!(Filesystem::mounts_with_root(missing, true).is_empty());
+    }
+}
@@ -0,0 +1,69 @@
+//! Helpers for tests that play out changes to a /proc fixture: processes exiting, CPU time passing
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub const VM: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/vm/proc");
+
+/// A scratch copy of the VM fixture, unique to `name` and this test run
+pub fn scratch_copy(name: &str) -> PathBuf {
+    fn copy(from: &Path, to: &Path) {
+        fs::create_dir_all(to).unwrap();
+        for e in fs::read_dir(from).unwrap() {
+            let e = e.unwrap();
+            if e.file_type().unwrap().is_dir() {
+                copy(&e.path(), &to.join(e.file_name()));
+            } else {
+                fs::copy(e.path(), to.join(e.file_name())).unwrap();
+            }
+        }
+    }
+    let root = env::temp_dir().join(format!("cpuviz-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&root);
+    copy(Path::new(VM), &root);
+    root
+}
+
+/// Rewrite the state and parent in /proc/<pid>/stat
+pub fn set_stat(root: &Path, pid: u32, state: char, ppid: u32) {
+    set_stat_fields(root, pid, &[(0, state.to_string()), (1, ppid.to_string())]);
+}
+
+/// Rewrite utime and stime, fields 14 and 15 of /proc/<pid>/stat
+pub fn set_times(root: &Path, pid: u32, utime: u64, stime: u64) {
+    set_stat_fields(root, pid, &[(11, utime.to_string()), (12, stime.to_string())]);
+}
+
+/// Rewrite the start time, field 22 of /proc/<pid>/stat, as if a new process had taken the pid
+pub fn set_start_time(root: &Path, pid: u32, start_time: u64) {
+    set_stat_fields(root, pid, &[(19, start_time.to_string())]);
+}
+
+// fields are numbered from the state, which is field 3 in proc(5)
+fn set_stat_fields(root: &Path, pid: u32, values: &[(usize, String)]) {
+    let path = root.join(pid.to_string()).join("stat");
+    let stat = fs::read_to_string(&path).unwrap();
+    let close = stat.rfind(')').unwrap();
+    let mut fields: Vec<String> = stat[close + 2..].split(' ').map(String::from).collect();
+    for (i, value) in values {
+        fields[*i] = value.clone();
+    }
+    fs::write(&path, format!("{} {}", &stat[..=close], fields.join(" "))).unwrap();
+}
+
+/// Let `busy` user jiffies and `idle` idle jiffies pass on every cpu line of /proc/stat
+pub fn add_cpu_time(root: &Path, busy: u64, idle: u64) {
+    let path = root.join("stat");
+    let stat = fs::read_to_string(&path).unwrap();
+    let lines: Vec<String> = stat.lines().map(|line| {
+        if !line.starts_with("cpu") {
+            return line.to_string()
+        }
+        let mut fields: Vec<String> = line.split_whitespace().map(String::from).collect();
+        fields[1] = (fields[1].parse::<u64>().unwrap() + busy).to_string();
+        fields[4] = (fields[4].parse::<u64>().unwrap() + idle).to_string();
+        fields.join(" ")
+    }).collect();
+    fs::write(&path, lines.join("\n") + "\n").unwrap();
+}
@@ -0,0 +1,227 @@
+use std::fmt;
+use std::marker::PhantomData;
+use super::gtop;
+
+/// A field of a glibtop struct that has a bit in the struct's `flags` validity bitmask
+pub trait Field: Copy {
+    /// The bit index, one of the `GLIBTOP_*` constants
+    fn bit(self) -> u32;
+}
+
+/// The fields of a glibtop struct that libgtop actually filled in on this platform.
+/// Fields missing from the set read as zero in the raw struct, but that zero means "not available".
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Flags<F: Field> {
+    bits:  u64,
+    field: PhantomData<F>,
+}
+
+impl<F: Field> Flags<F> {
+    pub fn from_bits(bits: u64) -> Flags<F> {
+        Flags { bits, field: PhantomData }
+    }
+
+    /// Every field, for backends that fill in everything
+    pub fn all() -> Flags<F> {
+        Flags::from_bits(!0)
+    }
+
+    /// The raw bitmask
+    pub fn bits(&self) -> u64 {
+        self.bits
+    }
+
+    /// Was this field filled in by libgtop?
+    pub fn contains(&self, f: F) -> bool {
+        self.bits & (1 << f.bit()) != 0
+    }
+
+    /// Return `Some(value)` if the field was filled in, `None` otherwise
+    pub fn get<T>(&self, f: F, value: T) -> Option<T> {
+        if self.contains(f) { Some(value) } else { None }
+    }
+}
+
+impl<F: Field> Default for Flags<F> {
+    /// No fields at all
+    fn default() -> Flags<F> {
+        Flags::from_bits(0)
+    }
+}
+
+impl<F: Field> fmt::Debug for Flags<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Flags({:#x})", self.bits)
+    }
+}
+
+/// The fields of `glibtop_cpu`, see `GLIBTOP_CPU_*` and `GLIBTOP_XCPU_*`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CpuField {
+    Total,
+    User,
+    Nice,
+    Sys,
+    Idle,
+    Frequency,
+    XcpuTotal,
+    XcpuUser,
+    XcpuNice,
+    XcpuSys,
+    XcpuIdle,
+    XcpuFlags,
+    Iowait,
+    Irq,
+    Softirq,
+    XcpuIowait,
+    XcpuIrq,
+    XcpuSoftirq,
+}
+
+impl Field for CpuField {
+    fn bit(self) -> u32 {
+        match self {
+            CpuField::Total       => gtop::GLIBTOP_CPU_TOTAL,
+            CpuField::User        => gtop::GLIBTOP_CPU_USER,
+            CpuField::Nice        => gtop::GLIBTOP_CPU_NICE,
+            CpuField::Sys         => gtop::GLIBTOP_CPU_SYS,
+            CpuField::Idle        => gtop::GLIBTOP_CPU_IDLE,
+            CpuField::Frequency   => gtop::GLIBTOP_CPU_FREQUENCY,
+            CpuField::XcpuTotal   => gtop::GLIBTOP_XCPU_TOTAL,
+            CpuField::XcpuUser    => gtop::GLIBTOP_XCPU_USER,
+            CpuField::XcpuNice    => gtop::GLIBTOP_XCPU_NICE,
+            CpuField::XcpuSys     => gtop::GLIBTOP_XCPU_SYS,
+            CpuField::XcpuIdle    => gtop::GLIBTOP_XCPU_IDLE,
+            CpuField::XcpuFlags   => gtop::GLIBTOP_XCPU_FLAGS,
+            CpuField::Iowait      => gtop::GLIBTOP_CPU_IOWAIT,
+            CpuField::Irq         => gtop::GLIBTOP_CPU_IRQ,
+            CpuField::Softirq     => gtop::GLIBTOP_CPU_SOFTIRQ,
+            CpuField::XcpuIowait  => gtop::GLIBTOP_XCPU_IOWAIT,
+            CpuField::XcpuIrq     => gtop::GLIBTOP_XCPU_IRQ,
+            CpuField::XcpuSoftirq => gtop::GLIBTOP_XCPU_SOFTIRQ,
+        }
+    }
+}
+
+/// The fields of `glibtop_mem`, see `GLIBTOP_MEM_*`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MemoryField {
+    Total,
+    Used,
+    Free,
+    Shared,
+    Buffer,
+    Cached,
+    User,
+    Locked,
+}
+
+impl Field for MemoryField {
+    fn bit(self) -> u32 {
+        match self {
+            MemoryField::Total  => gtop::GLIBTOP_MEM_TOTAL,
+            MemoryField::Used   => gtop::GLIBTOP_MEM_USED,
+            MemoryField::Free   => gtop::GLIBTOP_MEM_FREE,
+            MemoryField::Shared => gtop::GLIBTOP_MEM_SHARED,
+            MemoryField::Buffer => gtop::GLIBTOP_MEM_BUFFER,
+            MemoryField::Cached => gtop::GLIBTOP_MEM_CACHED,
+            MemoryField::User   => gtop::GLIBTOP_MEM_USER,
+            MemoryField::Locked => gtop::GLIBTOP_MEM_LOCKED,
+        }
+    }
+}
+
+/// The fields of `glibtop_swap`, see `GLIBTOP_SWAP_*`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SwapField {
+    Total,
+    Used,
+    Free,
+    Pagein,
+    Pageout,
+}
+
+impl Field for SwapField {
+    fn bit(self) -> u32 {
+        match self {
+            SwapField::Total   => gtop::GLIBTOP_SWAP_TOTAL,
+            SwapField::Used    => gtop::GLIBTOP_SWAP_USED,
+            SwapField::Free    => gtop::GLIBTOP_SWAP_FREE,
+            SwapField::Pagein  => gtop::GLIBTOP_SWAP_PAGEIN,
+            SwapField::Pageout => gtop::GLIBTOP_SWAP_PAGEOUT,
+        }
+    }
+}
+
+/// The fields of `glibtop_loadavg`, see `GLIBTOP_LOADAVG_*`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LoadAvgField {
+    LoadAvg,
+    NrRunning,
+    NrTasks,
+    LastPid,
+}
+
+impl Field for LoadAvgField {
+    fn bit(self) -> u32 {
+        match self {
+            LoadAvgField::LoadAvg   => gtop::GLIBTOP_LOADAVG_LOADAVG,
+            LoadAvgField::NrRunning => gtop::GLIBTOP_LOADAVG_NR_RUNNING,
+            LoadAvgField::NrTasks   => gtop::GLIBTOP_LOADAVG_NR_TASKS,
+            LoadAvgField::LastPid   => gtop::GLIBTOP_LOADAVG_LAST_PID,
+        }
+    }
+}
+
+/// The fields of `glibtop_uptime`, see `GLIBTOP_UPTIME_*`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UptimeField {
+    Uptime,
+    IdleTime,
+    BootTime,
+}
+
+impl Field for UptimeField {
+    fn bit(self) -> u32 {
+        match self {
+            UptimeField::Uptime   => gtop::GLIBTOP_UPTIME_UPTIME,
+            UptimeField::IdleTime => gtop::GLIBTOP_UPTIME_IDLETIME,
+            UptimeField::BootTime => gtop::GLIBTOP_UPTIME_BOOT_TIME,
+        }
+    }
+}
+
+/// The fields of `glibtop_fsusage`, see `GLIBTOP_FSUSAGE_*`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FsUsageField {
+    Blocks,
+    Bfree,
+    Bavail,
+    Files,
+    Ffree,
+    BlockSize,
+    Read,
+    Write,
+}
+
+impl Field for FsUsageField {
+    fn bit(self) -> u32 {
+        match self {
+            FsUsageField::Blocks    => gtop::GLIBTOP_FSUSAGE_BLOCKS,
+            FsUsageField::Bfree     => gtop::GLIBTOP_FSUSAGE_BFREE,
+            FsUsageField::Bavail    => gtop::GLIBTOP_FSUSAGE_BAVAIL,
+            FsUsageField::Files     => gtop::GLIBTOP_FSUSAGE_FILES,
+            FsUsageField::Ffree     => gtop::GLIBTOP_FSUSAGE_FFREE,
+            FsUsageField::BlockSize => gtop::GLIBTOP_FSUSAGE_BLOCK_SIZE,
+            FsUsageField::Read      => gtop::GLIBTOP_FSUSAGE_READ,
+            FsUsageField::Write     => gtop::GLIBTOP_FSUSAGE_WRITE,
+        }
+    }
+}
+
+pub type CpuFlags     = Flags<CpuField>;
+pub type MemoryFlags  = Flags<MemoryField>;
+pub type SwapFlags    = Flags<SwapField>;
+pub type LoadAvgFlags = Flags<LoadAvgField>;
+pub type UptimeFlags  = Flags<UptimeField>;
+pub type FsUsageFlags = Flags<FsUsageField>;
@@ -1,23 +1,32 @@
-use super::gtop;
+use super::sysdeps;
+use super::backend::{Backend, CpuCounters, MemoryCounters};
 
+/// Keeps libgtop initialized while any `Arc` to it is alive, and reads the CPU and memory counters through it.
+/// With the procfs feature there is nothing to initialize and the counters come from /proc, like `ProcFs`.
 pub struct GLibTopHandle();
 impl GLibTopHandle {
-    /// calls `glibtop_init`
+    /// calls `glibtop_init`, does nothing with the procfs backend
     pub fn new() -> GLibTopHandle {
         // println!("glibtop_init");
-        unsafe {
-            gtop::glibtop_init();
-        }
+        sysdeps::init();
         GLibTopHandle {}
     }
 }
 
+impl Backend for GLibTopHandle {
+    fn cpu(&self) -> CpuCounters {
+        sysdeps::cpu()
+    }
+
+    fn memory(&self) -> MemoryCounters {
+        sysdeps::memory()
+    }
+}
+
 impl Drop for GLibTopHandle {
-    /// calls `glibtop_close`
+    /// calls `glibtop_close`, does nothing with the procfs backend
     fn drop(&mut self) {
         // println!("glibtop_close");
-        unsafe {
-            gtop::glibtop_close();
-        }
+        sysdeps::close();
     }
 }
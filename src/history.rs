@@ -0,0 +1,144 @@
+use std::collections::VecDeque;
+use super::cpu::CounterRegression;
+use super::snapshot::{CpuSnapshot, CpuDelta};
+
+/// A bounded history of CPU snapshots. Once full, pushing a new snapshot drops the oldest one.
+pub struct CpuHistory {
+    snapshots: VecDeque<CpuSnapshot>,
+    capacity:  usize,
+}
+
+impl CpuHistory {
+    /// Keep at most `capacity` snapshots, e.g. 3600 for an hour of 1-second samples
+    pub fn with_capacity(capacity: usize) -> CpuHistory {
+        CpuHistory {
+            snapshots: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    /// Add a snapshot, dropping the oldest one if the history is full
+    pub fn push(&mut self, snapshot: CpuSnapshot) {
+        if self.capacity == 0 {
+            return
+        }
+        if self.snapshots.len() == self.capacity {
+            self.snapshots.pop_front();
+        }
+        self.snapshots.push_back(snapshot);
+    }
+
+    pub fn len(&self) -> usize {
+        self.snapshots.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.snapshots.is_empty()
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn oldest(&self) -> Option<&CpuSnapshot> {
+        self.snapshots.front()
+    }
+
+    pub fn latest(&self) -> Option<&CpuSnapshot> {
+        self.snapshots.back()
+    }
+
+    /// Iterate from the oldest to the latest snapshot
+    pub fn iter(&self) -> impl Iterator<Item = &CpuSnapshot> {
+        self.snapshots.iter()
+    }
+
+    /// The usage between each pair of consecutive snapshots, oldest first
+    pub fn deltas(&self) -> impl Iterator<Item = Result<CpuDelta, CounterRegression>> + '_ {
+        self.snapshots.iter().zip(self.snapshots.iter().skip(1)).map(|(earlier, later)| later.delta(earlier))
+    }
+
+    /// The usage over the whole window, from the oldest to the latest snapshot. `None` with fewer than two snapshots.
+    pub fn delta(&self) -> Option<Result<CpuDelta, CounterRegression>> {
+        match (self.oldest(), self.latest()) {
+            (Some(oldest), Some(latest)) if self.len() >= 2 => Some(latest.delta(oldest)),
+            _                                                => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::time::Duration;
+    use super::*;
+    use super::super::backend::CpuCounters;
+    use super::super::cpu::Cpu;
+    use super::super::cpu_breakdown::CpuTimes;
+    use super::super::scripted::Scripted;
+
+    // snapshots one second apart of a CPU whose (busy, total) jiffies since boot are `counters`
+    fn snapshots(counters: &[(u64, u64)]) -> Vec<CpuSnapshot> {
+        let script = Arc::new(Scripted::new());
+        let mut cpu = Cpu::with_handle(script.clone());
+        counters.iter().map(|&(busy, total)| {
+            script.push_cpu(CpuCounters::from_cores(&[CpuTimes { total, user: busy, idle: total - busy, ..CpuTimes::default() }]));
+            script.advance(Duration::from_secs(1));
+            cpu.snapshot()
+        }).collect()
+    }
+
+    fn history(capacity: usize, snapshots: &[CpuSnapshot]) -> CpuHistory {
+        let mut h = CpuHistory::with_capacity(capacity);
+        for s in snapshots {
+            h.push(s.clone());
+        }
+        h
+    }
+
+    #[test]
+    fn push_drops_the_oldest_at_capacity() {
+        let s = snapshots(&[(0, 0), (10, 100), (30, 200), (60, 300), (100, 400)]);
+        let h = history(3, &s);
+        assert_eq!((h.len(), h.capacity()), (3, 3));
+        assert_eq!(h.oldest(), Some(&s[2]));
+        assert_eq!(h.latest(), Some(&s[4]));
+        assert_eq!(h.iter().cloned().collect::<Vec<_>>(), &s[2..]);
+    }
+
+    #[test]
+    fn zero_capacity_keeps_nothing() {
+        let h = history(0, &snapshots(&[(0, 0), (10, 100)]));
+        assert!(h.is_empty());
+        assert_eq!(h.latest(), None);
+        assert_eq!(h.deltas().count(), 0);
+        assert!(h.delta().is_none());
+    }
+
+    #[test]
+    fn deltas_between_consecutive_snapshots() {
+        let h = history(10, &snapshots(&[(0, 0), (10, 100), (30, 200), (60, 300)]));
+        let deltas: Vec<CpuDelta> = h.deltas().map(|d| d.unwrap()).collect();
+        assert_eq!(deltas.iter().map(|d| d.usage()).collect::<Vec<_>>(), vec![0.1, 0.2, 0.3]);
+        assert!(deltas.iter().all(|d| d.elapsed() == Duration::from_secs(1)));
+    }
+
+    #[test]
+    fn delta_over_the_whole_window() {
+        let s = snapshots(&[(0, 0), (10, 100), (30, 200), (60, 300)]);
+        let d = history(10, &s).delta().unwrap().unwrap();
+        assert_eq!((d.busy(), d.total()), (60, 300));
+        assert_eq!(d.elapsed(), Duration::from_secs(3));
+        assert!(history(10, &s[..1]).delta().is_none()); // a single snapshot has no window
+    }
+
+    #[test]
+    fn a_regression_fails_only_its_own_delta() {
+        let h = history(10, &snapshots(&[(0, 0), (50, 100), (10, 200), (20, 300)])); // busy went backwards
+        let deltas: Vec<_> = h.deltas().collect();
+        assert!(deltas[0].is_ok());
+        assert_eq!(deltas[1], Err(CounterRegression));
+        assert_eq!(deltas[2].as_ref().map(|d| d.usage()), Ok(0.1));
+        assert_eq!(h.delta().unwrap().map(|d| d.total()), Ok(300));
+    }
+}
@@ -0,0 +1,86 @@
+//! System monitoring samplers: CPU, memory, swap, load, disks, network, processes, sensors and batteries.
+//! Counters come from libgtop, or straight from /proc with the `procfs` feature.
+
+// constructors end in `return x`, and new() always measures, so it shouldn't be hidden behind Default
+#![allow(clippy::needless_return, clippy::new_without_default)]
+
+#[cfg(feature = "procfs")]
+extern crate libc;
+
+mod cpu;
+mod cpu_breakdown;
+mod cpu_frequency;
+mod memory;
+mod memory_stats;
+mod swap;
+mod loadavg;
+mod uptime;
+mod network;
+mod disk;
+mod filesystem;
+mod process;
+mod process_watch;
+mod sensors;
+mod power;
+mod flags;
+mod snapshot;
+mod history;
+mod glibtop_handle;
+mod percent_usage;
+mod util;
+
+mod backend;
+mod procfs; // the /proc readers behind ProcFs and every with_root(), in either build
+#[cfg(any(test, feature = "testing"))]
+mod scripted;
+#[cfg(test)]
+mod fixture;
+
+// where the glibtop structs get filled in: libgtop, or /proc directly with the procfs feature
+#[cfg(not(feature = "procfs"))]
+mod libgtop;
+#[cfg(not(feature = "procfs"))]
+use libgtop as sysdeps;
+#[cfg(feature = "procfs")]
+mod procfs_sysdeps;
+#[cfg(feature = "procfs")]
+use procfs_sysdeps as sysdeps;
+pub use cpu::{Cpu, CounterRegression};
+pub use cpu_breakdown::{CpuTimes, CpuBreakdown};
+pub use cpu_frequency::{CpuFrequency, CoreFrequency};
+pub use memory::Memory;
+pub use memory_stats::MemoryStats;
+pub use swap::Swap;
+pub use loadavg::LoadAvg;
+pub use uptime::Uptime;
+pub use network::{Network, InterfaceCounters, InterfaceRates};
+pub use disk::{Disk, DiskCounters, DiskRates};
+pub use filesystem::{Filesystem, Mount};
+pub use process::{Processes, ProcessInfo};
+pub use process_watch::{ProcessWatch, WatchSample, WatchStatus};
+pub use sensors::{Sensors, Temperature, Fan};
+pub use power::{Power, Battery, ChargeState};
+pub use snapshot::{CpuSnapshot, CoreTimes, CpuDelta, MemorySnapshot, MemoryDelta};
+pub use history::CpuHistory;
+pub use flags::{Flags, Field, CpuFlags, CpuField, MemoryFlags, MemoryField, SwapFlags, SwapField,
+                LoadAvgFlags, LoadAvgField, UptimeFlags, UptimeField, FsUsageFlags, FsUsageField};
+pub use glibtop_handle::GLibTopHandle;
+pub use backend::{Backend, CpuCounters, MemoryCounters};
+pub use procfs::ProcFs;
+#[cfg(any(test, feature = "testing"))]
+pub use scripted::Scripted;
+pub use percent_usage::PercentUsage;
+
+mod gtop {
+    #![allow(non_upper_case_globals)]
+    #![allow(non_camel_case_types)]
+    #![allow(non_snake_case)]
+    #![allow(warnings)]
+    #![allow(clippy::approx_constant)] // M_PI and friends, not covered by allow(warnings)
+
+    #[cfg(feature = "generate_bindings")]
+    include!(concat!(env!("OUT_DIR"), "/bindings.rs")); // use build.rs generated
+
+    #[cfg(not(feature = "generate_bindings"))]
+    include!("./bindings.rs"); // use manually generated
+}
@@ -0,0 +1,125 @@
+use std::mem;
+use std::slice;
+use std::ffi::CStr;
+use std::os::raw::c_char;
+use super::gtop;
+use super::backend::{CpuCounters, MemoryCounters};
+use super::cpu_breakdown::CpuTimes;
+use super::snapshot::CoreTimes;
+use super::flags::{CpuFlags, CpuField, MemoryFlags};
+use super::procfs::ProcFs;
+use super::filesystem::Mount;
+
+// The default backend: every glibtop struct is filled in by libgtop. See procfs_sysdeps.rs for the native one.
+
+/// Calls `glibtop_init()`
+pub(crate) fn init() {
+    unsafe { gtop::glibtop_init(); }
+}
+
+/// Calls `glibtop_close()`
+pub(crate) fn close() {
+    unsafe { gtop::glibtop_close(); }
+}
+
+/// The counters from `glibtop_get_cpu()`. Offline CPUs are skipped by libgtop and left zeroed, so they are left out.
+pub(crate) fn cpu() -> CpuCounters {
+    let mut c: Box<gtop::glibtop_cpu> = Box::new(unsafe { mem::zeroed() }); // 64 KB
+    unsafe { gtop::glibtop_get_cpu(&mut *c); }
+    let flags = CpuFlags::from_bits(c.flags);
+    let n_cores = if flags.contains(CpuField::XcpuTotal) { cpu_slots() } else { 0 }; // no per-cpu counters on this platform
+
+    CpuCounters {
+        flags,
+        aggregate: CpuTimes {
+            total:   c.total,
+            user:    c.user,
+            nice:    c.nice,
+            sys:     c.sys,
+            idle:    c.idle,
+            iowait:  c.iowait,
+            irq:     c.irq,
+            softirq: c.softirq,
+            steal:   None, // glibtop_cpu has no steal counter
+        },
+        cores:     (0..n_cores).filter(|&i| c.xcpu_total[i] != 0).map(|i| CoreTimes {
+            cpu:   i as u32,
+            times: CpuTimes {
+                total:   c.xcpu_total[i],
+                user:    c.xcpu_user[i],
+                nice:    c.xcpu_nice[i],
+                sys:     c.xcpu_sys[i],
+                idle:    c.xcpu_idle[i],
+                iowait:  c.xcpu_iowait[i],
+                irq:     c.xcpu_irq[i],
+                softirq: c.xcpu_softirq[i],
+                steal:   None,
+            },
+        }).collect(),
+        cpu_count: cpu_slots() as u32,
+        frequency: c.frequency,
+    }
+}
+
+// the number of xcpu_* entries libgtop fills in. `ncpu` is the highest CPU index, not a count.
+fn cpu_slots() -> usize {
+    let ncpu = unsafe { (*gtop::glibtop_global_server).ncpu };
+    ((ncpu + 1) as usize).min(gtop::GLIBTOP_NCPU as usize)
+}
+
+/// The counters from `glibtop_get_mem()`, plus the kernel's `MemAvailable` estimate which libgtop doesn't report
+pub(crate) fn memory() -> MemoryCounters {
+    let mut m: gtop::glibtop_mem = unsafe { mem::zeroed() };
+    unsafe { gtop::glibtop_get_mem(&mut m); }
+    MemoryCounters {
+        flags:     MemoryFlags::from_bits(m.flags),
+        total:     m.total,
+        used:      m.used,
+        free:      m.free,
+        shared:    m.shared,
+        buffer:    m.buffer,
+        cached:    m.cached,
+        user:      m.user,
+        locked:    m.locked,
+        available: ProcFs::new().meminfo().get("MemAvailable").cloned(),
+    }
+}
+
+pub(crate) fn get_swap(buf: &mut gtop::glibtop_swap) {
+    unsafe { gtop::glibtop_get_swap(buf); }
+}
+
+pub(crate) fn get_loadavg(buf: &mut gtop::glibtop_loadavg) {
+    unsafe { gtop::glibtop_get_loadavg(buf); }
+}
+
+pub(crate) fn get_uptime(buf: &mut gtop::glibtop_uptime) {
+    unsafe { gtop::glibtop_get_uptime(buf); }
+}
+
+/// Every mounted filesystem, pseudo filesystems included
+pub(crate) fn mounts() -> Vec<Mount> {
+    let mut list: gtop::glibtop_mountlist = unsafe { mem::zeroed() };
+    unsafe {
+        let entries = gtop::glibtop_get_mountlist(&mut list, 1); // all filesystems, the caller does the filtering
+        if entries.is_null() {
+            return Vec::new()
+        }
+        let mounts = slice::from_raw_parts(entries, list.number as usize).iter().map(|e| Mount {
+            device:    c_string(&e.devname),
+            mount_dir: c_string(&e.mountdir),
+            fs_type:   c_string(&e.type_),
+        }).collect();
+        gtop::g_free(entries as gtop::gpointer);
+        mounts
+    }
+}
+
+pub(crate) fn get_fsusage(buf: &mut gtop::glibtop_fsusage, mount_dir: &CStr) {
+    unsafe { gtop::glibtop_get_fsusage(buf, mount_dir.as_ptr()); }
+}
+
+// a NUL terminated fixed size C string
+fn c_string(s: &[c_char]) -> String {
+    unsafe { CStr::from_ptr(s.as_ptr()) }.to_string_lossy().into_owned()
+}
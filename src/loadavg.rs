@@ -0,0 +1,149 @@
+use std::sync::Arc;
+use std::path::PathBuf;
+use std::mem;
+use super::gtop;
+use super::sysdeps;
+use super::GLibTopHandle;
+use super::procfs::ProcFs;
+use super::backend::Backend;
+use super::flags::{LoadAvgFlags, LoadAvgField};
+
+/// A handle to a `glibtop_loadavg` struct, the number of online CPUs at the last measurement, an
+/// `Option<Arc<GLibTopHandle>>` to determine
+/// when `glibtop_init()` and `glibtop_close()` should be called and the /proc to read instead of asking libgtop, if any
+pub struct LoadAvg {
+    gtop_loadavg: gtop::glibtop_loadavg,
+    cpu_count: u32,
+    handle: Option<Arc<GLibTopHandle>>,
+    procfs: Option<ProcFs>,
+}
+
+impl LoadAvg {
+    /// Always returns true, every platform libgtop supports has a load average
+    pub fn is_available() -> bool {
+        true
+    }
+
+    /// Instantate a new LoadAvg struct, call `glibtop_init()`. `glibtop_close()` will be called when this
+    /// object is dropped
+    pub fn new() -> LoadAvg {
+        sysdeps::init();
+        LoadAvg::init(None, None)
+    }
+
+    /// Instantate a new LoadAvg struct, does not call `glibtop_init()`. `glibtop_close()` will be called when all
+    /// references to this handle are dropped.
+    pub fn with_handle(h: Arc<GLibTopHandle>) -> LoadAvg {
+        LoadAvg::init(Some(h), None)
+    }
+
+    /// Instantate a new LoadAvg struct reading `root`/loadavg and `root`/stat instead of asking libgtop, e.g. the
+    /// host's /proc mounted at `/host/proc` inside a container. Does not call `glibtop_init()`.
+    pub fn with_root<P: Into<PathBuf>>(root: P) -> LoadAvg {
+        LoadAvg::init(None, Some(ProcFs::with_root(root)))
+    }
+
+    fn init(handle: Option<Arc<GLibTopHandle>>, procfs: Option<ProcFs>) -> LoadAvg {
+        let mut l = LoadAvg {
+            handle,
+            procfs,
+            gtop_loadavg: unsafe { mem::zeroed() },
+            cpu_count: 1,
+        };
+        l.measure();
+        return l
+    }
+
+    /// Update the values from glibtop, or the /proc given to `LoadAvg::with_root()`, and recount the online CPUs
+    /// there, so a CPU hot-plugged in or out is reflected in `LoadAvg::normalized()`
+    pub fn measure(&mut self) {
+        self.cpu_count = match self.procfs {
+            Some(ref procfs) => { procfs.loadavg(&mut self.gtop_loadavg); procfs.cpu().online_cpus() },
+            None             => { sysdeps::get_loadavg(&mut self.gtop_loadavg); sysdeps::cpu().online_cpus() },
+        };
+    }
+
+    /// The number of online logical CPUs, as of the last call to `LoadAvg::measure()`
+    pub fn cpu_count(&self) -> u32 {
+        self.cpu_count
+    }
+
+    /// The 1, 5 and 15 minute load averages divided by the number of online CPUs. 1.0 means every CPU had, on average,
+    /// exactly one task running or waiting to run, so the same threshold works on machines of any size.
+    pub fn normalized(&self) -> Option<[f64; 3]> {
+        let n = self.cpu_count as f64;
+        self.loadavg().map(|l| [l[0] / n, l[1] / n, l[2] / n])
+    }
+
+    /// Which fields of the glibtop_loadavg struct libgtop filled in on this platform.
+    pub fn flags(&self) -> LoadAvgFlags {
+        LoadAvgFlags::from_bits(self.gtop_loadavg.flags)
+    }
+
+    /// Expose fields from the glibtop_loadavg struct, or `None` if libgtop does not provide them on this platform.
+    /// `loadavg` holds the 1, 5 and 15 minute load averages. Not updated unless `LoadAvg::measure()` is called
+    pub fn loadavg(&self) -> Option<[f64; 3]> {
+        self.flags().get(LoadAvgField::LoadAvg, self.gtop_loadavg.loadavg)
+    }
+    pub fn nr_running(&self) -> Option<u64> {
+        self.flags().get(LoadAvgField::NrRunning, self.gtop_loadavg.nr_running)
+    }
+    pub fn nr_tasks(&self) -> Option<u64> {
+        self.flags().get(LoadAvgField::NrTasks, self.gtop_loadavg.nr_tasks)
+    }
+    pub fn last_pid(&self) -> Option<u64> {
+        self.flags().get(LoadAvgField::LastPid, self.gtop_loadavg.last_pid)
+    }
+}
+
+impl Drop for LoadAvg {
+    fn drop(&mut self) {
+        if self.handle.is_none() && self.procfs.is_none() { // manual glibtop init and close
+            sysdeps::close()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs;
+    use super::*;
+
+    const VM: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/vm/proc");
+
+    #[test]
+    fn loadavg_from_fixture() {
+        let loadavg = LoadAvg::with_root(VM);
+        assert_eq!(loadavg.loadavg(), Some([0.17, 0.30, 0.26]));
+        assert_eq!(loadavg.nr_running(), Some(1));
+        assert_eq!(loadavg.nr_tasks(), Some(74));
+        assert_eq!(loadavg.last_pid(), Some(9758));
+        assert_eq!(loadavg.cpu_count(), 1); // counted from the fixture's stat, not this machine's
+        assert_eq!(loadavg.normalized(), loadavg.loadavg());
+    }
+
+    #[test]
+    fn cpus_are_recounted_on_every_measurement() {
+        let root = env::temp_dir().join(format!("cpuviz-hotplug-{}", std::process::id()));
+        fs::create_dir_all(&root).unwrap();
+        fs::write(root.join("loadavg"), "1.00 0.50 0.25 1/10 100\n").unwrap();
+        fs::write(root.join("stat"), "cpu  10 0 10 80 0 0 0 0 0 0\ncpu0 10 0 10 80 0 0 0 0 0 0\n").unwrap();
+        let mut loadavg = LoadAvg::with_root(&root);
+        assert_eq!(loadavg.cpu_count(), 1);
+
+        // a second CPU is brought online
+        fs::write(root.join("stat"), "cpu  20 0 20 160 0 0 0 0 0 0\ncpu0 10 0 10 80 0 0 0 0 0 0\ncpu1 10 0 10 80 0 0 0 0 0 0\n").unwrap();
+        loadavg.measure();
+        assert_eq!(loadavg.cpu_count(), 2);
+        assert_eq!(loadavg.normalized(), Some([0.5, 0.25, 0.125]));
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn missing_root_reads_nothing() {
+        let loadavg = LoadAvg::with_root(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/missing"));
+        assert_eq!(loadavg.loadavg(), None);
+        assert_eq!(loadavg.cpu_count(), 1);
+    }
+}
@@ -1,14 +1,17 @@
 use std::sync::Arc;
-use std::mem;
-use super::gtop;
+use std::path::PathBuf;
 use super::GLibTopHandle;
+use super::procfs::ProcFs;
+use super::backend::{Backend, MemoryCounters};
 use super::percent_usage::PercentUsage;
+use super::flags::{MemoryFlags, MemoryField};
+use super::snapshot::MemorySnapshot;
+use super::memory_stats::MemoryStats;
 
-/// A handle to a `glibtop_mem` struct and an `Option<Arc<GLibTopHandle>>` to determine when `glibtop_init()`
-/// and `glibtop_close()` should be called
+/// The memory counters from the last measurement, and the `Backend` they are read from
 pub struct Memory {
-    gtop_memory: gtop::glibtop_mem,
-    handle: Option<Arc<GLibTopHandle>>
+    counters: MemoryCounters,
+    backend: Arc<dyn Backend>
 }
 
 impl Memory {
@@ -17,72 +20,77 @@ impl Memory {
         true // we always have RAM
     }
 
-    /// Instantate a new Memory struct, call `glibtop_init()`. `glibtop_close()` will be called when this
-    /// object is dropped
+    /// Instantate a new Memory struct with its own `GLibTopHandle`, calls `glibtop_init()`. `glibtop_close()` will be
+    /// called when this object is dropped
     pub fn new() -> Memory {
-        let mut m = Memory {
-            handle: None,
-            gtop_memory: unsafe { mem::zeroed() }
-        };
-        unsafe { gtop::glibtop_init(); }
-        m.measure();
-        return m
+        Memory::with_handle(Arc::new(GLibTopHandle::new()))
+    }
+
+    /// Instantate a new Memory struct reading `root`/meminfo, e.g. the host's /proc mounted at `/host/proc` inside a
+    /// container. Shorthand for `Memory::with_handle(Arc::new(ProcFs::with_root(root)))`
+    pub fn with_root<P: Into<PathBuf>>(root: P) -> Memory {
+        Memory::with_handle(Arc::new(ProcFs::with_root(root)))
     }
 
-    /// Instantate a new Memory struct, does not call `glibtop_init()`. `glibtop_close()` will be called when all
-    /// references to this handle are dropped.
-    pub fn with_handle(h: Arc<GLibTopHandle>) -> Memory {
+    /// Instantate a new Memory struct reading from any `Backend`, e.g. a shared `GLibTopHandle` or a `ProcFs`.
+    /// For a `GLibTopHandle`, `glibtop_close()` will be called when all references to the handle are dropped.
+    pub fn with_handle(h: Arc<dyn Backend>) -> Memory {
         let mut m = Memory {
-            handle: Some(h),
-            gtop_memory: unsafe { mem::zeroed() }
+            counters: MemoryCounters::default(),
+            backend: h
         };
         m.measure();
         return m
     }
 
-    /// Update the values from glibtop
+    /// Update the values from the backend and return them as a snapshot, stamped with the current time.
+    /// Two snapshots can be compared with `MemorySnapshot::delta()`.
+    pub fn snapshot(&mut self) -> MemorySnapshot {
+        self.measure();
+        MemorySnapshot::from_counters(self.backend.now(), self.counters)
+    }
+
+    /// Update the values from the backend
     pub fn measure(&mut self) {
-        unsafe {
-            gtop::glibtop_get_mem(&mut self.gtop_memory);
-        }
+        self.counters = self.backend.memory();
+    }
+
+    /// Used, free, cached... memory, in bytes and as a fraction of total memory. See `MemoryStats` for how "used" is defined.
+    /// Not updated unless `Memory::measure()` is called
+    pub fn stats(&self) -> MemoryStats {
+        MemoryStats::from_counters(&self.counters)
     }
 
-    /// Expose fields from the glibtop_mem struct. Not updated unless `Memory::measure()` is called
-    pub fn flags(&self) -> u64 {
-        // pub flags: guint64,
-        self.gtop_memory.flags
+    /// Which counters the backend filled in on this platform.
+    pub fn flags(&self) -> MemoryFlags {
+        self.counters.flags
     }
-    pub fn total(&self) -> u64 {
-        // pub total: guint64,
-        self.gtop_memory.total
+
+    /// Expose the raw counters, or `None` if the backend does not provide them on this platform.
+    /// Not updated unless `Memory::measure()` is called
+    pub fn total(&self) -> Option<u64> {
+        self.flags().get(MemoryField::Total, self.counters.total)
     }
-    pub fn used(&self) -> u64 {
-        // pub used: guint64,
-        self.gtop_memory.used
+    pub fn used(&self) -> Option<u64> {
+        self.flags().get(MemoryField::Used, self.counters.used)
     }
-    pub fn free(&self) -> u64 {
-        // pub free: guint64,
-        self.gtop_memory.free
+    pub fn free(&self) -> Option<u64> {
+        self.flags().get(MemoryField::Free, self.counters.free)
     }
-    pub fn shared(&self) -> u64 {
-        // pub shared: guint64,
-        self.gtop_memory.shared
+    pub fn shared(&self) -> Option<u64> {
+        self.flags().get(MemoryField::Shared, self.counters.shared)
     }
-    pub fn buffer(&self) -> u64 {
-        // pub buffer: guint64,
-        self.gtop_memory.buffer
+    pub fn buffer(&self) -> Option<u64> {
+        self.flags().get(MemoryField::Buffer, self.counters.buffer)
     }
-    pub fn cached(&self) -> u64 {
-        // pub cached: guint64,
-        self.gtop_memory.cached
+    pub fn cached(&self) -> Option<u64> {
+        self.flags().get(MemoryField::Cached, self.counters.cached)
     }
-    pub fn user(&self) -> u64 {
-        // pub user: guint64,
-        self.gtop_memory.user
+    pub fn user(&self) -> Option<u64> {
+        self.flags().get(MemoryField::User, self.counters.user)
     }
-    pub fn locked(&self) -> u64 {
-        // pub locked: guint64,
-        self.gtop_memory.locked
+    pub fn locked(&self) -> Option<u64> {
+        self.flags().get(MemoryField::Locked, self.counters.locked)
     }
 
 }
@@ -90,18 +98,63 @@ impl Memory {
 impl PercentUsage for Memory {
     fn percent_usage(&mut self) -> f64 {
         self.measure();
-        let m     = self.gtop_memory;
-        let total = m.total as f64;
-        let user  = m.user as f64;
-        user/total // Same number that gnome-system-monitor reports. However, they round to the 0.001 place.
-                   // I might be missing something somewhere
+        self.stats().used_fraction() // total - available, the same number `free` reports
     }
 }
 
-impl Drop for Memory {
-    fn drop(&mut self) {
-        if self.handle.is_none() { // manual glibtop init and close
-            unsafe { gtop::glibtop_close() }
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::time::Duration;
+    use super::*;
+    use super::super::scripted::Scripted;
+
+    const GIB: u64 = 1 << 30;
+
+    fn counters(total: u64, free: u64, buffer: u64, cached: u64, available: Option<u64>) -> MemoryCounters {
+        MemoryCounters {
+            flags: MemoryFlags::all(),
+            total,
+            used: total - free,
+            free,
+            buffer,
+            cached,
+            available,
+            ..MemoryCounters::default()
         }
     }
+
+    #[test]
+    fn used_is_total_minus_available() {
+        let script = Arc::new(Scripted::new());
+        script.push_memory(counters(8 * GIB, GIB, GIB, 2 * GIB, Some(6 * GIB)));
+        let mut memory = Memory::with_handle(script);
+        assert_eq!(memory.stats().used, 2 * GIB);
+        assert_eq!(memory.percent_usage(), 0.25);
+    }
+
+    #[test]
+    fn available_falls_back_to_free_buffers_and_cached() {
+        let script = Arc::new(Scripted::new());
+        script.push_memory(counters(8 * GIB, GIB, GIB, 2 * GIB, None)); // a kernel older than 3.14
+        let memory = Memory::with_handle(script);
+        assert_eq!(memory.stats().available, 4 * GIB);
+        assert_eq!(memory.stats().used_fraction(), 0.5);
+    }
+
+    #[test]
+    fn snapshot_delta() {
+        let script = Arc::new(Scripted::new());
+        script.push_memory(counters(8 * GIB, 4 * GIB, 0, 0, Some(6 * GIB)));
+        let mut memory = Memory::with_handle(script.clone());
+        let before = memory.snapshot();
+        script.push_memory(counters(8 * GIB, 2 * GIB, 0, 0, Some(4 * GIB)));
+        script.advance(Duration::from_secs(5));
+        let after = memory.snapshot();
+
+        let delta = after.delta(&before);
+        assert_eq!(delta.elapsed(), Duration::from_secs(5));
+        assert_eq!(delta.used_change(), 2 * GIB as i64);
+        assert_eq!(delta.average_usage(), 0.375);
+    }
 }
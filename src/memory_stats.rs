@@ -0,0 +1,66 @@
+use super::backend::MemoryCounters;
+
+/// A summary of memory use, in bytes, produced by `Memory::stats()`.
+///
+/// "Used" is defined the way `free` from procps-ng (3.3.10 and later) defines it: `total - available`, where "available"
+/// is the kernel's `MemAvailable` estimate of how much memory can be handed to new programs without swapping.
+/// Unlike `total - free`, this does not count the page cache and reclaimable buffers as used.
+/// On kernels older than 3.14, which don't report `MemAvailable`, "available" falls back to `free + buffers + cached`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MemoryStats {
+    pub total:     u64,
+    pub used:      u64,
+    pub free:      u64, // completely unused, usually small on a healthy machine
+    pub buffers:   u64,
+    pub cached:    u64,
+    pub shared:    u64,
+    pub locked:    u64,
+    pub available: u64,
+}
+
+impl MemoryStats {
+    /// Summarize the raw counters a `Backend` supplied
+    pub(crate) fn from_counters(m: &MemoryCounters) -> MemoryStats {
+        let available = m.available.unwrap_or(m.free + m.buffer + m.cached).min(m.total);
+        MemoryStats {
+            total:     m.total,
+            used:      m.total - available,
+            free:      m.free,
+            buffers:   m.buffer,
+            cached:    m.cached,
+            shared:    m.shared,
+            locked:    m.locked,
+            available,
+        }
+    }
+
+    /// `bytes` as a fraction of total memory, between 0.0 and 1.0
+    pub fn fraction(&self, bytes: u64) -> f64 {
+        if self.total == 0 {
+            return 0.0
+        }
+        (bytes as f64) / (self.total as f64)
+    }
+
+    pub fn used_fraction(&self) -> f64 {
+        self.fraction(self.used)
+    }
+    pub fn free_fraction(&self) -> f64 {
+        self.fraction(self.free)
+    }
+    pub fn buffers_fraction(&self) -> f64 {
+        self.fraction(self.buffers)
+    }
+    pub fn cached_fraction(&self) -> f64 {
+        self.fraction(self.cached)
+    }
+    pub fn shared_fraction(&self) -> f64 {
+        self.fraction(self.shared)
+    }
+    pub fn locked_fraction(&self) -> f64 {
+        self.fraction(self.locked)
+    }
+    pub fn available_fraction(&self) -> f64 {
+        self.fraction(self.available)
+    }
+}
@@ -0,0 +1,219 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+use super::util::PROC;
+
+/// The counters of one network interface since it came up, from /proc/net/dev
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct InterfaceCounters {
+    pub rx_bytes:   u64,
+    pub rx_packets: u64,
+    pub rx_errors:  u64,
+    pub rx_drops:   u64,
+    pub tx_bytes:   u64,
+    pub tx_packets: u64,
+    pub tx_errors:  u64,
+    pub tx_drops:   u64,
+}
+
+impl InterfaceCounters {
+    // did any counter go backwards? Happens when an interface is deleted and recreated under the same name
+    fn regressed_since(&self, earlier: &InterfaceCounters) -> bool {
+        self.rx_bytes   < earlier.rx_bytes   ||
+        self.rx_packets < earlier.rx_packets ||
+        self.rx_errors  < earlier.rx_errors  ||
+        self.rx_drops   < earlier.rx_drops   ||
+        self.tx_bytes   < earlier.tx_bytes   ||
+        self.tx_packets < earlier.tx_packets ||
+        self.tx_errors  < earlier.tx_errors  ||
+        self.tx_drops   < earlier.tx_drops
+    }
+}
+
+/// The traffic on one network interface between the last two calls to `Network::measure()`
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct InterfaceRates {
+    pub name:               String,
+    pub rx_bytes_per_sec:   f64,
+    pub rx_packets_per_sec: f64,
+    pub tx_bytes_per_sec:   f64,
+    pub tx_packets_per_sec: f64,
+    pub rx_errors:          u64, // errors and drops are rare, so they're counted over the interval rather than per second
+    pub rx_drops:           u64,
+    pub tx_errors:          u64,
+    pub tx_drops:           u64,
+}
+
+/// Keep the state of the network interfaces: the counters of every interface at the last measurement, and the
+/// throughput of each one over that measurement.
+pub struct Network {
+    root:      PathBuf,
+    last_time: Instant,
+    counters:  BTreeMap<String, InterfaceCounters>,
+    rates:     Vec<InterfaceRates>,
+}
+
+impl Network {
+    /// Is there a /proc/net/dev to read?
+    pub fn is_available() -> bool {
+        Network::is_available_at(PROC)
+    }
+
+    /// Is there a net/dev to read under `root`, e.g. the host's /proc at `/host/proc/1`?
+    pub fn is_available_at<P: AsRef<Path>>(root: P) -> bool {
+        root.as_ref().join("net/dev").exists()
+    }
+
+    /// Instantiate a new Network and take an initial measurement
+    pub fn new() -> Network {
+        Network::with_root(PROC)
+    }
+
+    /// Read `root`/net/dev instead of /proc/net/dev. /proc/net is the network namespace of whoever reads it, so to see
+    /// the host's interfaces from a container that has the host's /proc at `/host/proc`, use `/host/proc/1`.
+    pub fn with_root<P: Into<PathBuf>>(root: P) -> Network {
+        let mut n = Network {
+            root:      root.into(),
+            last_time: Instant::now(),
+            counters:  BTreeMap::new(),
+            rates:     Vec::new(),
+        };
+        let _ = n.measure(); // inital measurement, nothing to report if /proc/net/dev can't be read
+        return n
+    }
+
+    /// Re-read the interface counters and compute the throughput of each interface since the last measurement.
+    ///
+    /// Interfaces come and go (VPNs, containers' veth pairs...): an interface that just appeared has no rates until the
+    /// next measurement, one that disappeared is dropped, and one whose counters went backwards because it was recreated
+    /// under the same name is re-baselined and has no rates for this interval.
+    pub fn measure(&mut self) -> io::Result<()> {
+        self.measure_at(Instant::now())
+    }
+
+    // `measure()`, with the time of the reading passed in so tests don't depend on the wall clock
+    pub(crate) fn measure_at(&mut self, now: Instant) -> io::Result<()> {
+        let counters = parse_net_dev(&fs::read_to_string(self.root.join("net/dev"))?);
+        let elapsed = now.duration_since(self.last_time).as_secs_f64();
+
+        self.rates = counters.iter().filter_map(|(name, c)| {
+            let last = self.counters.get(name)?;
+            if c.regressed_since(last) || elapsed <= 0.0 {
+                return None
+            }
+            Some(InterfaceRates {
+                name:               name.clone(),
+                rx_bytes_per_sec:   ((c.rx_bytes - last.rx_bytes) as f64) / elapsed,
+                rx_packets_per_sec: ((c.rx_packets - last.rx_packets) as f64) / elapsed,
+                tx_bytes_per_sec:   ((c.tx_bytes - last.tx_bytes) as f64) / elapsed,
+                tx_packets_per_sec: ((c.tx_packets - last.tx_packets) as f64) / elapsed,
+                rx_errors:          c.rx_errors - last.rx_errors,
+                rx_drops:           c.rx_drops - last.rx_drops,
+                tx_errors:          c.tx_errors - last.tx_errors,
+                tx_drops:           c.tx_drops - last.tx_drops,
+            })
+        }).collect();
+        self.counters = counters;
+        self.last_time = now;
+        Ok(())
+    }
+
+    /// The names of the interfaces seen at the last measurement, sorted
+    pub fn interfaces(&self) -> Vec<&str> {
+        self.counters.keys().map(|k| k.as_str()).collect()
+    }
+
+    /// The raw counters of an interface, as of the last measurement
+    pub fn counters(&self, name: &str) -> Option<&InterfaceCounters> {
+        self.counters.get(name)
+    }
+
+    /// The throughput of every interface between the last two calls to `Network::measure()`, sorted by name
+    pub fn rates(&self) -> &[InterfaceRates] {
+        &self.rates
+    }
+
+    /// The throughput of one interface between the last two calls to `Network::measure()`
+    pub fn rate(&self, name: &str) -> Option<&InterfaceRates> {
+        self.rates.iter().find(|r| r.name == name)
+    }
+}
+
+/// Parse the contents of /proc/net/dev. Malformed lines are skipped.
+pub(crate) fn parse_net_dev(contents: &str) -> BTreeMap<String, InterfaceCounters> {
+    // Inter-|   Receive                                                |  Transmit
+    //  face |bytes    packets errs drop fifo frame compressed multicast|bytes    packets errs drop fifo colls carrier compressed
+    //     lo: 2776770   11307    0    0    0     0          0         0  2776770   11307    0    0    0     0       0          0
+    contents.lines().skip(2).filter_map(|line| {
+        let mut parts = line.splitn(2, ':');
+        let name = parts.next()?.trim();
+        let fields: Vec<u64> = parts.next()?.split_whitespace().map(|f| f.parse()).collect::<Result<_, _>>().ok()?;
+        if name.is_empty() || fields.len() < 12 {
+            return None
+        }
+        Some((name.to_string(), InterfaceCounters {
+            rx_bytes:   fields[0],
+            rx_packets: fields[1],
+            rx_errors:  fields[2],
+            rx_drops:   fields[3],
+            tx_bytes:   fields[8],
+            tx_packets: fields[9],
+            tx_errors:  fields[10],
+            tx_drops:   fields[11],
+        }))
+    }).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+    use super::*;
+
+    const VM: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/vm/proc");
+
+    #[test]
+    fn interfaces_from_fixture() {
+        let network = Network::with_root(VM);
+        assert_eq!(network.interfaces(), vec!["eth0", "ifb0", "ifb1", "lo"]);
+        let eth0 = network.counters("eth0").unwrap();
+        assert_eq!((eth0.rx_bytes, eth0.rx_packets), (942039, 190));
+        assert_eq!((eth0.tx_bytes, eth0.tx_packets), (17609, 189));
+        assert_eq!(network.counters("lo").unwrap().rx_bytes, 45839864);
+    }
+
+    #[test]
+    fn rates_over_the_interval() {
+        let mut network = Network::with_root(VM);
+        assert!(network.rates().is_empty()); // nothing to compare the first measurement against
+        let start = network.last_time;
+        network.counters.get_mut("eth0").unwrap().rx_bytes -= 1000; // eth0 received 1000 bytes since
+        network.measure_at(start + Duration::from_secs(2)).unwrap();
+        assert_eq!(network.rates().len(), 4);
+        assert_eq!(network.rate("eth0").unwrap().rx_bytes_per_sec, 500.0);
+        assert_eq!(network.rate("eth0").unwrap().tx_bytes_per_sec, 0.0);
+        assert_eq!(network.rate("lo").unwrap().rx_bytes_per_sec, 0.0);
+    }
+
+    #[test]
+    fn no_rates_without_time_passing() {
+        let mut network = Network::with_root(VM);
+        let start = network.last_time;
+        network.measure_at(start).unwrap();
+        assert!(network.rates().is_empty());
+    }
+
+    #[test]
+    fn missing_root_is_an_error() {
+        let mut network = Network::with_root(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/missing"));
+        assert!(network.interfaces().is_empty());
+        assert!(network.measure().is_err());
+    }
+
+    #[test]
+    fn availability_under_a_root() {
+        assert!(Network::is_available_at(VM));
+        assert!(!Network::is_available_at(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/missing")));
+    }
+}
@@ -0,0 +1,266 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use super::percent_usage::PercentUsage;
+use super::util::{SYSFS, read_string, read_number};
+
+/// What a battery is doing, from its `status` file
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChargeState {
+    Charging,
+    Discharging,
+    Full,
+    NotCharging, // plugged in but holding its charge, e.g. because of a charge limit
+    Unknown,
+}
+
+/// One battery, as of the last call to `Power::measure()`
+#[derive(Clone, Debug, PartialEq)]
+pub struct Battery {
+    pub name:        String,      // e.g. BAT0
+    pub state:       ChargeState,
+    pub capacity:    Option<f64>, // charge level as reported by the battery, 0.0 to 1.0
+    pub energy_now:  Option<f64>, // watt-hours
+    pub energy_full: Option<f64>, // watt-hours
+    pub power:       Option<f64>, // watts being drawn or charged, always positive
+}
+
+impl Battery {
+    /// The charge level, 0.0 to 1.0, from the energy counters when there are some, the reported capacity otherwise
+    pub fn charge(&self) -> Option<f64> {
+        match (self.energy_now, self.energy_full) {
+            (Some(now), Some(full)) if full > 0.0 => Some((now / full).min(1.0)),
+            _                                     => self.capacity,
+        }
+    }
+}
+
+/// The batteries and AC adapters under /sys/class/power_supply. The sysfs root can be moved, to read a fixture tree
+/// or the host's /sys from inside a container.
+pub struct Power {
+    root:      PathBuf,
+    batteries: Vec<Battery>,
+    on_ac:     Option<bool>, // None if there's no AC adapter to ask
+}
+
+impl Power {
+    /// Does this machine have a battery? Desktops and servers don't.
+    pub fn is_available() -> bool {
+        Power::is_available_at(SYSFS)
+    }
+
+    /// Does the machine whose /sys is at `root`, e.g. the host's at `/host/sys`, have a battery?
+    /// `Power::has_battery()` answers the same for a `Power` already reading it.
+    pub fn is_available_at<P: AsRef<Path>>(root: P) -> bool {
+        !battery_dirs(root.as_ref()).is_empty()
+    }
+
+    /// Read the power supplies under /sys
+    pub fn new() -> Power {
+        Power::with_root(SYSFS)
+    }
+
+    /// Read the power supplies under `root` instead of /sys, e.g. `/host/sys`
+    pub fn with_root<P: Into<PathBuf>>(root: P) -> Power {
+        let mut p = Power {
+            root:      root.into(),
+            batteries: Vec::new(),
+            on_ac:     None,
+        };
+        p.measure();
+        return p
+    }
+
+    /// Re-read every power supply
+    pub fn measure(&mut self) {
+        self.batteries = battery_dirs(&self.root).iter().map(|dir| read_battery(dir)).collect();
+        let mains: Vec<bool> = supply_dirs(&self.root, "Mains").iter()
+            .filter_map(|dir| read_number(&dir.join("online")).map(|o| o != 0))
+            .collect();
+        self.on_ac = if mains.is_empty() { None } else { Some(mains.contains(&true)) };
+    }
+
+    /// Were any batteries found at the last measurement?
+    pub fn has_battery(&self) -> bool {
+        !self.batteries.is_empty()
+    }
+
+    /// Every battery, sorted by name
+    pub fn batteries(&self) -> &[Battery] {
+        &self.batteries
+    }
+
+    /// Is the machine plugged in? `None` if there's no AC adapter to ask
+    pub fn on_ac(&self) -> Option<bool> {
+        self.on_ac
+    }
+
+    /// The charge level over all batteries, 0.0 to 1.0
+    pub fn charge(&self) -> Option<f64> {
+        let (now, full) = self.energy();
+        match (now, full) {
+            (Some(now), Some(full)) if full > 0.0 => Some((now / full).min(1.0)),
+            _ => { // no energy counters, average the reported capacities
+                let capacities: Vec<f64> = self.batteries.iter().filter_map(|b| b.capacity).collect();
+                if capacities.is_empty() { None } else { Some(capacities.iter().sum::<f64>() / (capacities.len() as f64)) }
+            }
+        }
+    }
+
+    /// Charging if any battery is charging, discharging if any is discharging, and so on
+    pub fn state(&self) -> ChargeState {
+        let states: Vec<ChargeState> = self.batteries.iter().map(|b| b.state).collect();
+        for s in &[ChargeState::Discharging, ChargeState::Charging, ChargeState::NotCharging, ChargeState::Full] {
+            if states.contains(s) {
+                return *s
+            }
+        }
+        ChargeState::Unknown
+    }
+
+    /// Watts drawn from, or charged into, all batteries
+    pub fn power(&self) -> Option<f64> {
+        let powers: Vec<f64> = self.batteries.iter().filter_map(|b| b.power).collect();
+        if powers.is_empty() { None } else { Some(powers.iter().sum()) }
+    }
+
+    /// How long until the batteries are empty when discharging, or full when charging, at the current power draw
+    pub fn time_remaining(&self) -> Option<Duration> {
+        let (now, full) = self.energy();
+        let power = self.power().filter(|&p| p > 0.0)?;
+        let watt_hours = match self.state() {
+            ChargeState::Discharging => now?,
+            ChargeState::Charging    => (full? - now?).max(0.0),
+            _                        => return None,
+        };
+        Some(Duration::from_secs_f64(watt_hours / power * 3600.0))
+    }
+
+    // (energy now, energy when full) summed over all batteries, in watt-hours
+    fn energy(&self) -> (Option<f64>, Option<f64>) {
+        let sum = |f: &dyn Fn(&Battery) -> Option<f64>| -> Option<f64> {
+            self.batteries.iter().map(f).sum() // None if any battery doesn't report it
+        };
+        if self.batteries.is_empty() {
+            return (None, None)
+        }
+        (sum(&|b| b.energy_now), sum(&|b| b.energy_full))
+    }
+}
+
+impl PercentUsage for Power {
+    /// The charge level over all batteries, 0.0 without a battery
+    fn percent_usage(&mut self) -> f64 {
+        self.measure();
+        self.charge().unwrap_or(0.0)
+    }
+}
+
+// the batteries powering the machine. Those of a wireless mouse or keyboard are batteries too, scoped to their device
+fn battery_dirs(root: &Path) -> Vec<PathBuf> {
+    supply_dirs(root, "Battery").into_iter()
+        .filter(|dir| read_string(&dir.join("scope")).as_deref() != Some("Device"))
+        .collect()
+}
+
+// the power supplies of a given type (Battery, Mains, USB...), sorted by name
+fn supply_dirs(root: &Path, kind: &str) -> Vec<PathBuf> {
+    let mut dirs: Vec<PathBuf> = match fs::read_dir(root.join("class/power_supply")) {
+        Ok(e)  => e.filter_map(|e| Some(e.ok()?.path())).filter(|dir| {
+            read_string(&dir.join("type")).map(|t| t == kind).unwrap_or(false)
+        }).collect(),
+        Err(_) => Vec::new(),
+    };
+    dirs.sort();
+    dirs
+}
+
+// batteries report either energy (µWh, µW) or charge (µAh, µA), the latter needs the voltage to become energy
+fn read_battery(dir: &Path) -> Battery {
+    let micro = |file: &str| read_number(&dir.join(file)).map(|v| (v as f64) / 1e6);
+    let volts = micro("voltage_now");
+    let (energy_now, energy_full) = match (micro("energy_now"), micro("energy_full")) {
+        (Some(now), Some(full)) => (Some(now), Some(full)),
+        _ => match (micro("charge_now"), micro("charge_full"), volts) {
+            (Some(now), Some(full), Some(v)) => (Some(now * v), Some(full * v)),
+            _                                => (None, None),
+        },
+    };
+    let power = micro("power_now").or_else(|| Some(micro("current_now")? * volts?)).map(f64::abs);
+
+    Battery {
+        name:     dir.file_name().map(|n| n.to_string_lossy().into_owned()).unwrap_or_default(),
+        state:    match read_string(&dir.join("status")).as_deref() {
+            Some("Charging")     => ChargeState::Charging,
+            Some("Discharging")  => ChargeState::Discharging,
+            Some("Full")         => ChargeState::Full,
+            Some("Not charging") => ChargeState::NotCharging,
+            _                    => ChargeState::Unknown,
+        },
+        capacity: read_number(&dir.join("capacity")).map(|c| (c as f64) / 100.0),
+        energy_now,
+        energy_full,
+        power,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LAPTOP: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/laptop/sys");
+
+    #[test]
+    fn batteries_from_fixture() {
+        let power = Power::with_root(LAPTOP);
+        assert_eq!(power.batteries(), &[ // not hidpp_battery_0, the mouse
+            Battery {
+                name:        "BAT0".to_string(),
+                state:       ChargeState::Discharging,
+                capacity:    Some(0.8),
+                energy_now:  Some(40.0),
+                energy_full: Some(50.0),
+                power:       Some(10.0),
+            },
+            Battery { // reports charge in µAh and current in µA, converted with voltage_now
+                name:        "BAT1".to_string(),
+                state:       ChargeState::Discharging,
+                capacity:    Some(0.5),
+                energy_now:  Some(22.0),
+                energy_full: Some(44.0),
+                power:       Some(11.0),
+            },
+        ]);
+        assert_eq!(power.on_ac(), Some(false));
+    }
+
+    #[test]
+    fn totals_from_fixture() {
+        let mut power = Power::with_root(LAPTOP);
+        assert_eq!(power.state(), ChargeState::Discharging);
+        assert_eq!(power.charge(), Some(62.0 / 94.0));
+        assert_eq!(power.power(), Some(21.0));
+        assert_eq!(power.time_remaining(), Some(Duration::from_secs_f64(62.0 / 21.0 * 3600.0)));
+        assert_eq!(power.percent_usage(), 62.0 / 94.0);
+    }
+
+    #[test]
+    fn peripheral_batteries_are_left_out() {
+        let power = Power::with_root(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/desktop/sys")); // a wireless mouse
+        assert!(!power.has_battery());
+        assert_eq!((power.on_ac(), power.charge(), power.time_remaining()), (Some(true), None, None));
+    }
+
+    #[test]
+    fn no_power_supply() {
+        let power = Power::with_root(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/vm/proc"));
+        assert!(!power.has_battery());
+        assert_eq!((power.on_ac(), power.charge(), power.time_remaining()), (None, None, None));
+    }
+
+    #[test]
+    fn availability_under_a_root() {
+        assert!(Power::is_available_at(LAPTOP));
+        assert!(!Power::is_available_at(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/desktop/sys")));
+    }
+}
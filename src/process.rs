@@ -0,0 +1,310 @@
+use std::cmp::{Ordering, Reverse};
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use super::backend::Backend;
+use super::procfs::ProcFs;
+use super::cpu::Cpu;
+use super::util::PROC;
+
+/// One process, as of the last call to `Processes::measure()`
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProcessInfo {
+    pub pid:       u32,
+    pub command:   String, // the executable name, truncated to 15 characters by the kernel
+    pub state:     char,   // R running, S sleeping, D waiting on I/O, Z zombie, T stopped...
+    pub user:      String, // the owner's name, or uid if it has none
+    pub cpu_usage: f64,    // fraction of all CPUs (0.0 to 1.0) used over the last interval. Multiply by the CPU count for top's %CPU
+    pub rss:       u64,    // resident memory, in bytes
+    pub vsize:     u64,    // virtual memory, in bytes
+}
+
+/// The fields we use from /proc/<pid>/stat
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub(crate) struct ProcStat {
+    pub pid:        u32,
+    pub command:    String,
+    pub state:      char,
+    pub ppid:       u32,
+    pub utime:      u64, // jiffies spent in user mode
+    pub stime:      u64, // jiffies spent in kernel mode
+    pub cutime:     u64, // utime of the children that exited and were waited for
+    pub cstime:     u64, // stime of the children that exited and were waited for
+    pub threads:    u64,
+    pub start_time: u64, // jiffies after boot, tells a process apart from an earlier one with the same pid
+}
+
+/// The fields we use from /proc/<pid>/status
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub(crate) struct ProcStatus {
+    pub uid:   u32,
+    pub rss:   u64, // bytes
+    pub vsize: u64, // bytes
+}
+
+/// Keep the state of every process: their CPU time at the last measurement and what they used over that measurement.
+/// Per-process CPU usage is measured against the machine's total jiffies, read through a `Cpu` that shares the
+/// same `Backend`.
+pub struct Processes {
+    root:      PathBuf,                  // /proc, or wherever the host's /proc is mounted
+    cpu:       Cpu,
+    last:      HashMap<u32, (u64, u64)>, // pid -> (start time, cpu jiffies)
+    processes: Vec<ProcessInfo>,
+    measured:  bool,                     // false until the first measurement, which has nothing to compare against
+}
+
+impl Processes {
+    /// Is there a /proc to read processes from?
+    pub fn is_available() -> bool {
+        Processes::is_available_at(PROC)
+    }
+
+    /// Are there processes to read under `root`, e.g. the host's /proc at `/host/proc`? Every pid namespace has a pid 1.
+    pub fn is_available_at<P: AsRef<Path>>(root: P) -> bool {
+        root.as_ref().join("1/stat").exists()
+    }
+
+    /// Instantiate a new Processes. Calls `glibtop_init()` and `glibtop_close()` when dropped
+    pub fn new() -> Processes {
+        Processes::init(Cpu::new(), PathBuf::from(PROC))
+    }
+
+    /// Create a new object reading the machine's CPU counters from any `Backend`, e.g. a shared `GLibTopHandle`.
+    /// `glibtop_close()` is called when all references to the handle are dropped accross the entire scope of the program.
+    pub fn with_handle(h: Arc<dyn Backend>) -> Processes {
+        Processes::init(Cpu::with_handle(h), PathBuf::from(PROC))
+    }
+
+    /// Read the processes, and the machine's CPU counters, from `root` instead of /proc, e.g. the host's /proc mounted
+    /// at `/host/proc` inside a container. User names still come from this machine's /etc/passwd.
+    pub fn with_root<P: Into<PathBuf>>(root: P) -> Processes {
+        let root = root.into();
+        Processes::init(Cpu::with_handle(Arc::new(ProcFs::with_root(root.clone()))), root)
+    }
+
+    fn init(cpu: Cpu, root: PathBuf) -> Processes {
+        let mut p = Processes {
+            root,
+            cpu,
+            last:      HashMap::new(),
+            processes: Vec::new(),
+            measured:  false,
+        };
+        let _ = p.measure(); // inital measurement
+        return p
+    }
+
+    /// Re-read every process and compute its CPU usage since the last measurement. Processes that started in between
+    /// are charged for all of their CPU time, processes that exited are dropped.
+    pub fn measure(&mut self) -> io::Result<()> {
+        let total = match self.cpu.measure() {
+            Ok((_, total)) => total,
+            Err(_)         => 0, // the machine's counters went backwards, no usage for this interval
+        };
+        let users = read_users();
+
+        let mut last = HashMap::new();
+        let mut processes = Vec::new();
+        for pid in list_pids(&self.root)? {
+            let (stat, status) = match (read_stat(&self.root, pid), read_status(&self.root, pid)) {
+                (Some(stat), Some(status)) => (stat, status),
+                _                          => continue, // exited while we were looking
+            };
+            let jiffies = stat.utime + stat.stime;
+            let earlier = match self.last.get(&pid) {
+                Some(&(start, j)) if start == stat.start_time => j,
+                _                                             => 0, // new process, or a new one reusing the pid
+            };
+            let cpu_usage = if self.measured && total > 0 {
+                (jiffies.saturating_sub(earlier) as f64) / (total as f64)
+            } else {
+                0.0
+            };
+            last.insert(pid, (stat.start_time, jiffies));
+            processes.push(ProcessInfo {
+                pid,
+                user:    users.get(&status.uid).cloned().unwrap_or_else(|| status.uid.to_string()),
+                command: stat.command,
+                state:   stat.state,
+                cpu_usage,
+                rss:     status.rss,
+                vsize:   status.vsize,
+            });
+        }
+        processes.sort_by_key(|p| p.pid);
+        self.last = last;
+        self.processes = processes;
+        self.measured = true;
+        Ok(())
+    }
+
+    /// Every process seen at the last measurement, sorted by pid
+    pub fn processes(&self) -> &[ProcessInfo] {
+        &self.processes
+    }
+
+    /// The `n` processes that used the most CPU over the last interval, busiest first
+    pub fn top_cpu(&self, n: usize) -> Vec<&ProcessInfo> {
+        let mut p: Vec<&ProcessInfo> = self.processes.iter().collect();
+        p.sort_by(|a, b| b.cpu_usage.partial_cmp(&a.cpu_usage).unwrap_or(Ordering::Equal));
+        p.truncate(n);
+        p
+    }
+
+    /// The `n` processes with the largest resident memory, largest first
+    pub fn top_memory(&self, n: usize) -> Vec<&ProcessInfo> {
+        let mut p: Vec<&ProcessInfo> = self.processes.iter().collect();
+        p.sort_by_key(|p| Reverse(p.rss));
+        p.truncate(n);
+        p
+    }
+
+    /// The number of online logical CPUs, to turn `ProcessInfo::cpu_usage` into a top-style percentage of one CPU
+    pub fn cpu_count(&self) -> u32 {
+        self.cpu.cpu_count()
+    }
+}
+
+/// The pids of every process in `root`, usually /proc
+pub(crate) fn list_pids(root: &Path) -> io::Result<Vec<u32>> {
+    Ok(fs::read_dir(root)?.filter_map(|e| e.ok()?.file_name().to_str()?.parse().ok()).collect())
+}
+
+pub(crate) fn read_stat(root: &Path, pid: u32) -> Option<ProcStat> {
+    parse_stat(&fs::read_to_string(root.join(pid.to_string()).join("stat")).ok()?)
+}
+
+pub(crate) fn read_status(root: &Path, pid: u32) -> Option<ProcStatus> {
+    parse_status(&fs::read_to_string(root.join(pid.to_string()).join("status")).ok()?)
+}
+
+/// Parse /proc/<pid>/stat
+pub(crate) fn parse_stat(contents: &str) -> Option<ProcStat> {
+    // 1234 (some command) S 1 1234 1234 0 -1 4194560 ...
+    // the command can contain spaces and parentheses, so split around the last ')'
+    let open  = contents.find('(')?;
+    let close = contents.rfind(')')?;
+    let fields: Vec<&str> = contents.get(close + 1..)?.split_whitespace().collect(); // fields[0] is field 3 in proc(5)
+    if fields.len() < 20 {
+        return None
+    }
+    Some(ProcStat {
+        pid:        contents[..open].trim().parse().ok()?,
+        command:    contents[open + 1..close].to_string(),
+        state:      fields[0].chars().next()?,
+        ppid:       fields[1].parse().ok()?,
+        utime:      fields[11].parse().ok()?,
+        stime:      fields[12].parse().ok()?,
+        cutime:     fields[13].parse().ok()?,
+        cstime:     fields[14].parse().ok()?,
+        threads:    fields[17].parse().ok()?,
+        start_time: fields[19].parse().ok()?,
+    })
+}
+
+/// Parse /proc/<pid>/status. Kernel threads have no memory lines, they use no memory of their own.
+pub(crate) fn parse_status(contents: &str) -> Option<ProcStatus> {
+    let mut status = ProcStatus::default();
+    let mut uid = None;
+    for line in contents.lines() {
+        let mut parts = line.split_whitespace();
+        match parts.next() {
+            Some("Uid:")    => uid = parts.next().and_then(|u| u.parse().ok()), // real uid
+            Some("VmRSS:")  => status.rss = kb(parts.next()),
+            Some("VmSize:") => status.vsize = kb(parts.next()),
+            _               => (),
+        }
+    }
+    status.uid = uid?;
+    Some(status)
+}
+
+// "123" kB -> bytes
+fn kb(value: Option<&str>) -> u64 {
+    value.and_then(|v| v.parse::<u64>().ok()).unwrap_or(0) * 1024
+}
+
+/// uid -> user name, from /etc/passwd. Users from LDAP and friends are not listed and show up as their uid.
+fn read_users() -> HashMap<u32, String> {
+    let passwd = fs::read_to_string("/etc/passwd").unwrap_or_default();
+    passwd.lines().filter_map(|line| {
+        let fields: Vec<&str> = line.split(':').collect(); // name:password:uid:gid:gecos:home:shell
+        Some((fields.get(2)?.parse().ok()?, fields[0].to_string()))
+    }).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::fixture::{VM, scratch_copy, set_times, set_start_time, add_cpu_time};
+
+    const KIB: u64 = 1024;
+
+    fn usage(processes: &Processes, pid: u32) -> f64 {
+        processes.processes().iter().find(|p| p.pid == pid).unwrap().cpu_usage
+    }
+
+    #[test]
+    fn processes_from_fixture() {
+        let processes = Processes::with_root(VM);
+        let pids: Vec<u32> = processes.processes().iter().map(|p| p.pid).collect();
+        assert_eq!(pids, vec![1, 9743, 9745, 9746]);
+        let sh = &processes.processes()[1];
+        assert_eq!((sh.command.as_str(), sh.state), ("sh", 'S'));
+        assert_eq!(sh.rss, 1636 * KIB);
+        assert_eq!(sh.vsize, 2592 * KIB);
+        assert_eq!(processes.top_memory(1)[0].pid, 1);
+        assert_eq!(processes.cpu_count(), 1); // from the fixture's /proc/stat, not this machine's
+    }
+
+    #[test]
+    fn stat_from_fixture() {
+        let stat = read_stat(Path::new(VM), 9745).unwrap();
+        assert_eq!((stat.command.as_str(), stat.ppid), ("sleep", 9743));
+        assert_eq!(stat.start_time, 337341);
+        assert_eq!(read_stat(Path::new(VM), 1).map(|s| (s.utime, s.stime)), Some((313, 526)));
+        assert_eq!(read_stat(Path::new(VM), 4242), None);
+    }
+
+    #[test]
+    fn cpu_usage_between_measurements() {
+        let root = scratch_copy("processes");
+        let mut processes = Processes::with_root(&root);
+        assert!(processes.processes().iter().all(|p| p.cpu_usage == 0.0)); // nothing to compare against yet
+
+        set_times(&root, 9745, 30, 10); // 40 and 10 jiffies out of the machine's 200
+        set_times(&root, 9746, 5, 5);
+        add_cpu_time(&root, 50, 150);
+        processes.measure().unwrap();
+        assert_eq!(usage(&processes, 9745), 0.2);
+        assert_eq!(usage(&processes, 9746), 0.05);
+        assert_eq!(usage(&processes, 1), 0.0);
+        let top: Vec<u32> = processes.top_cpu(2).iter().map(|p| p.pid).collect();
+        assert_eq!(top, vec![9745, 9746]);
+        assert_eq!(processes.top_cpu(10).len(), 4);
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn a_reused_pid_is_charged_from_zero() {
+        let root = scratch_copy("pid-reuse");
+        set_times(&root, 9746, 50, 0);
+        let mut processes = Processes::with_root(&root);
+
+        set_start_time(&root, 9746, 400000); // 9746 exited and a new process got its pid, 20 jiffies in
+        set_times(&root, 9746, 20, 0);
+        add_cpu_time(&root, 0, 100);
+        processes.measure().unwrap();
+        assert_eq!(usage(&processes, 9746), 0.2); // all of its 20, not 20 - 50 saturated to 0
+        assert_eq!(processes.top_cpu(1)[0].pid, 9746);
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn availability_under_a_root() {
+        assert!(Processes::is_available_at(VM));
+        assert!(!Processes::is_available_at(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/missing")));
+    }
+}
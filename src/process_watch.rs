@@ -0,0 +1,385 @@
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use super::backend::Backend;
+use super::cpu::Cpu;
+use super::procfs::ProcFs;
+use super::percent_usage::PercentUsage;
+use super::process::{ProcStat, list_pids, read_stat, read_status};
+use super::util::{PROC, USER_HZ};
+
+/// Is the watched process still around?
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WatchStatus {
+    Running,
+    Exited, // gone, or a zombie waiting to be reaped, and so are all the descendants being watched. The last sample
+            // holds their final numbers
+}
+
+/// What a watched process, and optionally its descendants, used
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct WatchSample {
+    pub processes:           usize,    // how many processes were counted
+    pub threads:             u64,
+    pub rss:                 u64,      // resident memory, in bytes
+    pub cpu_usage:           f64,      // fraction of all CPUs (0.0 to 1.0) used over the last interval
+    pub user_time:           Duration, // CPU time in user mode since the process started
+    pub system_time:         Duration, // CPU time in kernel mode since the process started
+    pub read_bytes:          u64,      // bytes read from storage by the processes still running
+    pub write_bytes:         u64,      // bytes written to storage by the processes still running
+    pub read_bytes_per_sec:  f64,
+    pub write_bytes_per_sec: f64,
+}
+
+/// Watch one process over time: its CPU usage, memory, threads and I/O. With `with_children`, the numbers cover
+/// the process and all of its descendants, including the CPU time of descendants that already exited.
+/// CPU usage is measured against the machine's total jiffies, read through a `Cpu` that can share a `Backend`.
+pub struct ProcessWatch {
+    proc_root:     PathBuf, // /proc, or wherever the host's /proc is mounted
+    pid:           u32,
+    start_time:    u64, // tells the process apart from a later one reusing its pid
+    with_children: bool,
+    members:       Vec<Member>, // every process counted at the last measurement
+    gone_user:     u64, // jiffies of members that exited without another member reaping them
+    gone_system:   u64,
+    cpu:           Cpu,
+    last_time:     Instant,
+    last_jiffies:  u64,
+    sample:        WatchSample,
+    status:        WatchStatus,
+}
+
+impl ProcessWatch {
+    /// Start watching `pid`. Calls `glibtop_init()` and `glibtop_close()` when dropped.
+    /// Fails with `NotFound` if there is no such process.
+    pub fn new(pid: u32, with_children: bool) -> io::Result<ProcessWatch> {
+        ProcessWatch::init(pid, with_children, Cpu::new(), PathBuf::from(PROC))
+    }
+
+    /// Start watching `pid`, reading the machine's CPU counters from any `Backend`, e.g. a shared `GLibTopHandle`.
+    /// `glibtop_close()` is called when all references to the handle are dropped accross the entire scope of the program.
+    pub fn with_handle(pid: u32, with_children: bool, h: Arc<dyn Backend>) -> io::Result<ProcessWatch> {
+        ProcessWatch::init(pid, with_children, Cpu::with_handle(h), PathBuf::from(PROC))
+    }
+
+    /// Start watching `pid` as seen in `root` instead of /proc, e.g. a host process through the host's /proc mounted
+    /// at `/host/proc` inside a container. The machine's CPU counters are read from there too.
+    pub fn with_root<P: Into<PathBuf>>(pid: u32, with_children: bool, root: P) -> io::Result<ProcessWatch> {
+        let root = root.into();
+        ProcessWatch::init(pid, with_children, Cpu::with_handle(Arc::new(ProcFs::with_root(root.clone()))), root)
+    }
+
+    fn init(pid: u32, with_children: bool, cpu: Cpu, proc_root: PathBuf) -> io::Result<ProcessWatch> {
+        let stat = match read_stat(&proc_root, pid) {
+            Some(s) => s,
+            None    => return Err(io::Error::new(io::ErrorKind::NotFound, format!("no process with pid {}", pid))),
+        };
+        let mut w = ProcessWatch {
+            proc_root,
+            pid,
+            start_time:    stat.start_time,
+            with_children,
+            members:       Vec::new(),
+            gone_user:     0,
+            gone_system:   0,
+            cpu,
+            last_time:     Instant::now(),
+            last_jiffies:  0,
+            sample:        WatchSample::default(),
+            status:        WatchStatus::Running,
+        };
+        w.measure(); // inital measurement
+        w.sample.cpu_usage = 0.0; // nothing to compare the first measurement against
+        Ok(w)
+    }
+
+    /// The pid being watched
+    pub fn pid(&self) -> u32 {
+        self.pid
+    }
+
+    /// Re-read the process (and its descendants) and compute its usage since the last measurement.
+    /// With `with_children`, descendants that outlive the process are still followed, until the whole tree has exited.
+    /// Once it has, this keeps returning `WatchStatus::Exited` and the sample stays frozen.
+    pub fn measure(&mut self) -> WatchStatus {
+        if self.status == WatchStatus::Exited {
+            return self.status
+        }
+        let total = match self.cpu.measure() {
+            Ok((_, total)) => total,
+            Err(_)         => 0, // the machine's counters went backwards, no usage for this interval
+        };
+        let now = Instant::now();
+
+        let root = read_stat(&self.proc_root, self.pid).filter(|s| s.start_time == self.start_time);
+        let members = match root {
+            Some(root) if !self.with_children => vec![root],
+            None if !self.with_children       => Vec::new(),
+            root                              => self.tree(root),
+        };
+        if members.is_empty() { // reaped before we could read the final numbers
+            self.status = WatchStatus::Exited;
+            return self.status
+        }
+        if members.iter().all(|p| p.state == 'Z') { // zombies: still readable, take their final numbers
+            self.status = WatchStatus::Exited;
+        }
+        self.update(members, total, now);
+        self.status
+    }
+
+    /// The usage as of the last call to `ProcessWatch::measure()`
+    pub fn sample(&self) -> &WatchSample {
+        &self.sample
+    }
+
+    pub fn status(&self) -> WatchStatus {
+        self.status
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.status == WatchStatus::Running
+    }
+
+    /// Has the process itself exited, or become a zombie? Descendants that outlive it are not waited for here, but
+    /// `ProcessWatch::measure()` keeps following them. Only reads one file, so it is cheap enough to poll between
+    /// measurements. A zombie's final numbers can still be read with one last `ProcessWatch::measure()`.
+    pub fn has_exited(&self) -> bool {
+        match read_stat(&self.proc_root, self.pid) {
+            Some(s) => s.start_time != self.start_time || s.state == 'Z',
+            None    => true,
+        }
+    }
+
+    // the root, if it's still there, the members of the last measurement that still are, and all of their descendants.
+    // A process's children are reparented as soon as it exits, so once the root is gone they're found through the
+    // earlier members.
+    fn tree(&self, root: Option<ProcStat>) -> Vec<ProcStat> {
+        let all: Vec<ProcStat> = list_pids(&self.proc_root).unwrap_or_default().into_iter()
+            .filter_map(|pid| read_stat(&self.proc_root, pid))
+            .collect();
+        let earlier = all.iter().filter(|p| self.members.iter().any(|m| m.is(p))).cloned();
+        descendants(&all, root.into_iter().chain(earlier).collect())
+    }
+
+    // recompute the sample from the processes that make up the watched tree
+    fn update(&mut self, members: Vec<ProcStat>, total_jiffies: u64, now: Instant) {
+        let elapsed = now.duration_since(self.last_time).as_secs_f64();
+        let (last_read, last_write) = (self.sample.read_bytes, self.sample.write_bytes);
+
+        let current: Vec<Member> = members.iter().map(|p| Member::new(p, self.with_children)).collect();
+        // a member reaped by another member has its times added to that one's children's times. One reaped from
+        // outside the tree, like the root by whoever started it or an orphan by init, takes them along: keep its last
+        for gone in self.members.iter().filter(|m| !members.iter().any(|p| m.is(p))) {
+            if !current.iter().any(|m| m.pid == gone.ppid) {
+                self.gone_user   += gone.user;
+                self.gone_system += gone.system;
+            }
+        }
+
+        let mut s = WatchSample::default();
+        let (mut user, mut system) = (self.gone_user, self.gone_system);
+        for (p, m) in members.iter().zip(&current) {
+            user   += m.user;
+            system += m.system;
+            s.threads += p.threads;
+            s.rss += read_status(&self.proc_root, p.pid).map(|st| st.rss).unwrap_or(0);
+            let (read, write) = read_io(&self.proc_root, p.pid).unwrap_or((0, 0)); // only readable for our own processes, or as root
+            s.read_bytes  += read;
+            s.write_bytes += write;
+        }
+        s.processes   = members.len();
+        self.members  = current;
+        s.user_time   = jiffies_to_duration(user);
+        s.system_time = jiffies_to_duration(system);
+        if total_jiffies > 0 {
+            s.cpu_usage = ((user + system).saturating_sub(self.last_jiffies) as f64) / (total_jiffies as f64);
+        }
+        if elapsed > 0.0 { // an exiting descendant takes its I/O counters with it, so these can go backwards
+            s.read_bytes_per_sec  = (s.read_bytes.saturating_sub(last_read) as f64) / elapsed;
+            s.write_bytes_per_sec = (s.write_bytes.saturating_sub(last_write) as f64) / elapsed;
+        }
+
+        self.last_jiffies = user + system;
+        self.last_time = now;
+        self.sample = s;
+    }
+}
+
+impl PercentUsage for ProcessWatch {
+    /// The fraction of all CPUs the watched process used since the last measurement, 0.0 once it has exited
+    fn percent_usage(&mut self) -> f64 {
+        match self.measure() {
+            WatchStatus::Running => self.sample.cpu_usage,
+            WatchStatus::Exited  => 0.0,
+        }
+    }
+}
+
+// a process counted in a ProcessWatch, as of the last measurement
+struct Member {
+    pid:        u32,
+    start_time: u64,
+    ppid:       u32,
+    user:       u64, // jiffies, with `with_children` including the children it reaped
+    system:     u64,
+}
+
+impl Member {
+    fn new(p: &ProcStat, with_children: bool) -> Member {
+        // the children's times only hold descendants that exited, which no other member accounts for
+        Member {
+            pid:        p.pid,
+            start_time: p.start_time,
+            ppid:       p.ppid,
+            user:       p.utime + if with_children { p.cutime } else { 0 },
+            system:     p.stime + if with_children { p.cstime } else { 0 },
+        }
+    }
+
+    // the same process, not a later one reusing its pid
+    fn is(&self, p: &ProcStat) -> bool {
+        self.pid == p.pid && self.start_time == p.start_time
+    }
+}
+
+// `roots` and every process descended from them
+fn descendants(all: &[ProcStat], roots: Vec<ProcStat>) -> Vec<ProcStat> {
+    let mut children: HashMap<u32, Vec<&ProcStat>> = HashMap::new();
+    for p in all {
+        children.entry(p.ppid).or_default().push(p);
+    }
+
+    let mut seen = HashSet::new();
+    let mut queue = Vec::new();
+    let mut members = Vec::new();
+    for root in roots {
+        if seen.insert(root.pid) {
+            queue.push(root.pid);
+            members.push(root);
+        }
+    }
+    while let Some(pid) = queue.pop() {
+        for child in children.get(&pid).map(|c| c.as_slice()).unwrap_or(&[]) {
+            if seen.insert(child.pid) {
+                queue.push(child.pid);
+                members.push((*child).clone());
+            }
+        }
+    }
+    members
+}
+
+// (read_bytes, write_bytes) from /proc/<pid>/io
+fn read_io(proc_root: &Path, pid: u32) -> Option<(u64, u64)> {
+    let contents = fs::read_to_string(proc_root.join(pid.to_string()).join("io")).ok()?;
+    let (mut read, mut write) = (None, None);
+    for line in contents.lines() {
+        let mut parts = line.split_whitespace();
+        match parts.next() {
+            Some("read_bytes:")  => read = parts.next().and_then(|v| v.parse().ok()),
+            Some("write_bytes:") => write = parts.next().and_then(|v| v.parse().ok()),
+            _                    => (),
+        }
+    }
+    Some((read?, write?))
+}
+
+fn jiffies_to_duration(jiffies: u64) -> Duration {
+    Duration::from_millis(jiffies * (1000 / USER_HZ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::fixture::{VM, scratch_copy, set_stat, set_times};
+
+    #[test]
+    fn watch_with_children_from_fixture() {
+        let watch = ProcessWatch::with_root(9743, true, VM).unwrap();
+        let sample = watch.sample();
+        assert_eq!(sample.processes, 3); // the shell and its two sleeps
+        assert_eq!(sample.threads, 3);
+        assert_eq!(sample.rss, (1636 + 1520 + 1528) * 1024);
+        assert!(watch.is_running());
+    }
+
+    #[test]
+    fn unreadable_io_counts_as_zero() {
+        let watch = ProcessWatch::with_root(1, false, VM).unwrap(); // /proc/1/io wasn't readable when captured
+        let sample = watch.sample();
+        assert_eq!(sample.processes, 1);
+        assert_eq!(sample.user_time, Duration::from_millis(3130));
+        assert_eq!(sample.system_time, Duration::from_millis(5260));
+        assert_eq!((sample.read_bytes, sample.write_bytes), (0, 0));
+    }
+
+    #[test]
+    fn missing_pid_is_not_found() {
+        let err = ProcessWatch::with_root(4242, false, VM).err().unwrap();
+        assert_eq!(err.kind(), io::ErrorKind::NotFound);
+    }
+
+    #[test]
+    fn children_are_followed_after_the_root_exits() {
+        let root = scratch_copy("orphans");
+        let mut watch = ProcessWatch::with_root(9743, true, &root).unwrap();
+        assert_eq!(watch.sample().processes, 3);
+
+        set_stat(&root, 9743, 'Z', 9738); // the shell exits, its sleeps are reparented to init
+        set_stat(&root, 9745, 'S', 1);
+        set_stat(&root, 9746, 'S', 1);
+        assert!(watch.has_exited()); // the shell has, its sleeps haven't
+        assert_eq!(watch.measure(), WatchStatus::Running);
+        assert_eq!(watch.sample().processes, 3);
+
+        fs::remove_dir_all(root.join("9743")).unwrap(); // the shell is reaped, one sleep exits and is reaped
+        fs::remove_dir_all(root.join("9745")).unwrap();
+        assert_eq!(watch.measure(), WatchStatus::Running);
+        assert_eq!(watch.sample().processes, 1);
+        assert_eq!(watch.sample().rss, 1528 * 1024);
+
+        set_stat(&root, 9746, 'Z', 1); // the last one exits
+        assert_eq!(watch.measure(), WatchStatus::Exited);
+        assert_eq!(watch.sample().processes, 1); // its final numbers
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn times_of_a_reaped_root_are_kept() {
+        let root = scratch_copy("reaped");
+        set_times(&root, 9743, 50, 10);
+        set_times(&root, 9745, 20, 5);
+        set_times(&root, 9746, 30, 5);
+        let mut watch = ProcessWatch::with_root(9743, true, &root).unwrap();
+        assert_eq!(watch.sample().user_time, Duration::from_millis(1000));
+        assert_eq!(watch.sample().system_time, Duration::from_millis(200));
+
+        set_stat(&root, 9745, 'S', 1); // the shell is reaped by its parent, outside the tree, its sleeps go to init
+        set_stat(&root, 9746, 'S', 1);
+        fs::remove_dir_all(root.join("9743")).unwrap();
+        assert_eq!(watch.measure(), WatchStatus::Running);
+        assert_eq!(watch.sample().processes, 2);
+        assert_eq!(watch.sample().user_time, Duration::from_millis(1000)); // not just the sleeps' 500
+        assert_eq!(watch.sample().system_time, Duration::from_millis(200));
+
+        set_times(&root, 9746, 40, 5);
+        watch.measure();
+        assert_eq!(watch.sample().user_time, Duration::from_millis(1100));
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn without_children_the_root_is_enough() {
+        let root = scratch_copy("root-only");
+        let mut watch = ProcessWatch::with_root(9743, false, &root).unwrap();
+        set_stat(&root, 9743, 'Z', 9738);
+        assert!(watch.has_exited()); // even though its sleeps are still running
+        assert_eq!(watch.measure(), WatchStatus::Exited);
+        assert_eq!(watch.sample().processes, 1);
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,290 @@
+use std::fs;
+use std::mem;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use super::backend::{Backend, CpuCounters, MemoryCounters};
+use super::cpu_breakdown::CpuTimes;
+use super::snapshot::CoreTimes;
+use super::flags::{CpuFlags, MemoryFlags};
+use super::filesystem::Mount;
+use super::gtop;
+use super::util::{PROC, USER_HZ};
+
+/// A `Backend` reading the CPU and memory counters straight from /proc/stat and /proc/meminfo, without libgtop.
+/// The fields and flags it fills in are the ones libgtop's Linux code fills in, so `Cpu` and `Memory` report the same
+/// numbers with either backend.
+pub struct ProcFs {
+    root: PathBuf,
+}
+
+impl ProcFs {
+    /// Is /proc mounted?
+    pub fn is_available() -> bool {
+        ProcFs::is_available_at(PROC)
+    }
+
+    /// Is there a /proc at `root`, e.g. the host's at `/host/proc`?
+    pub fn is_available_at<P: AsRef<Path>>(root: P) -> bool {
+        root.as_ref().join("stat").exists()
+    }
+
+    /// Read from /proc
+    pub fn new() -> ProcFs {
+        ProcFs::with_root(PROC)
+    }
+
+    /// Read from `root` instead of /proc, e.g. the host's /proc mounted at `/host/proc` inside a container
+    pub fn with_root<P: Into<PathBuf>>(root: P) -> ProcFs {
+        ProcFs { root: root.into() }
+    }
+
+    fn read(&self, file: &str) -> Option<String> {
+        fs::read_to_string(self.root.join(file)).ok()
+    }
+
+    /// /proc/meminfo in bytes, keyed by name without the colon
+    pub(crate) fn meminfo(&self) -> HashMap<String, u64> {
+        self.read("meminfo").map(|info| parse_meminfo(&info)).unwrap_or_default()
+    }
+
+    /// Fill `buf` from meminfo, and the page counters from vmstat
+    pub(crate) fn swap(&self, buf: &mut gtop::glibtop_swap) {
+        *buf = unsafe { mem::zeroed() };
+        let info = self.meminfo();
+        if let (Some(&total), Some(&free)) = (info.get("SwapTotal"), info.get("SwapFree")) {
+            buf.total  = total;
+            buf.free   = free;
+            buf.used   = total.saturating_sub(free);
+            buf.flags |= bits(&[gtop::GLIBTOP_SWAP_TOTAL, gtop::GLIBTOP_SWAP_USED, gtop::GLIBTOP_SWAP_FREE]);
+        }
+        let vmstat = self.read("vmstat").unwrap_or_default();
+        for line in vmstat.lines() {
+            let mut fields = line.split_whitespace();
+            let (key, value) = match (fields.next(), fields.next().and_then(|v| v.parse().ok())) {
+                (Some(k), Some(v)) => (k, v),
+                _                  => continue,
+            };
+            match key {
+                "pswpin"  => { buf.pagein = value;  buf.flags |= bits(&[gtop::GLIBTOP_SWAP_PAGEIN]) },
+                "pswpout" => { buf.pageout = value; buf.flags |= bits(&[gtop::GLIBTOP_SWAP_PAGEOUT]) },
+                _         => {},
+            }
+        }
+    }
+
+    /// Fill `buf` from loadavg, e.g. `0.52 0.58 0.59 2/1234 56789`
+    pub(crate) fn loadavg(&self, buf: &mut gtop::glibtop_loadavg) {
+        *buf = unsafe { mem::zeroed() };
+        let line = match self.read("loadavg") {
+            Some(l) => l,
+            None    => return,
+        };
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        if fields.len() < 5 {
+            return
+        }
+        let averages: Vec<f64> = fields[..3].iter().filter_map(|f| f.parse().ok()).collect();
+        if averages.len() == 3 {
+            buf.loadavg.copy_from_slice(&averages);
+            buf.flags |= bits(&[gtop::GLIBTOP_LOADAVG_LOADAVG]);
+        }
+        let mut tasks = fields[3].splitn(2, '/').map(|n| n.parse::<u64>().ok());
+        if let (Some(Some(running)), Some(Some(total))) = (tasks.next(), tasks.next()) {
+            buf.nr_running = running;
+            buf.nr_tasks   = total;
+            buf.flags     |= bits(&[gtop::GLIBTOP_LOADAVG_NR_RUNNING, gtop::GLIBTOP_LOADAVG_NR_TASKS]);
+        }
+        if let Ok(pid) = fields[4].parse() {
+            buf.last_pid = pid;
+            buf.flags   |= bits(&[gtop::GLIBTOP_LOADAVG_LAST_PID]);
+        }
+    }
+
+    /// Fill `buf` from uptime, and the boot time from the `btime` line of stat
+    pub(crate) fn uptime(&self, buf: &mut gtop::glibtop_uptime) {
+        *buf = unsafe { mem::zeroed() };
+        let line = self.read("uptime").unwrap_or_default();
+        let mut fields = line.split_whitespace().map(|f| f.parse::<f64>().ok());
+        if let (Some(Some(uptime)), Some(Some(idle))) = (fields.next(), fields.next()) {
+            buf.uptime   = uptime;
+            buf.idletime = idle;
+            buf.flags   |= bits(&[gtop::GLIBTOP_UPTIME_UPTIME, gtop::GLIBTOP_UPTIME_IDLETIME]);
+        }
+        let stat = self.read("stat").unwrap_or_default();
+        let btime = stat.lines().find(|l| l.starts_with("btime ")).and_then(|l| l[6..].trim().parse().ok());
+        if let Some(btime) = btime {
+            buf.boot_time = btime;
+            buf.flags    |= bits(&[gtop::GLIBTOP_UPTIME_BOOT_TIME]);
+        }
+    }
+
+    /// Every filesystem mounted in the mount namespace of `pid` ("self" for our own), pseudo filesystems included
+    pub(crate) fn mounts(&self, pid: &str) -> Vec<Mount> {
+        let mounts = self.read(&format!("{}/mounts", pid)).unwrap_or_default();
+        mounts.lines().filter_map(|line| {
+            let mut fields = line.split_whitespace();
+            Some(Mount {
+                device:    unescape(fields.next()?),
+                mount_dir: unescape(fields.next()?),
+                fs_type:   unescape(fields.next()?),
+            })
+        }).collect()
+    }
+}
+
+impl Backend for ProcFs {
+    /// The counters from /proc/stat. Like libgtop, the total is user + nice + sys + idle + iowait + irq + softirq:
+    /// steal and guest time are left out. Steal is reported on its own.
+    fn cpu(&self) -> CpuCounters {
+        self.read("stat").map(|stat| parse_stat(&stat)).unwrap_or_default()
+    }
+
+    /// The counters from /proc/meminfo. Like libgtop, "used" is total - free and "user" is used - buffers - cached.
+    fn memory(&self) -> MemoryCounters {
+        let info = self.meminfo();
+        let (total, free) = match (info.get("MemTotal"), info.get("MemFree")) {
+            (Some(&t), Some(&f)) => (t, f),
+            _                    => return MemoryCounters::default(),
+        };
+        let get = |key: &str| info.get(key).cloned().unwrap_or(0);
+        let used = total.saturating_sub(free);
+        MemoryCounters {
+            flags:     MemoryFlags::from_bits(bits(&[gtop::GLIBTOP_MEM_TOTAL, gtop::GLIBTOP_MEM_USED,
+                                                     gtop::GLIBTOP_MEM_FREE, gtop::GLIBTOP_MEM_SHARED,
+                                                     gtop::GLIBTOP_MEM_BUFFER, gtop::GLIBTOP_MEM_CACHED,
+                                                     gtop::GLIBTOP_MEM_USER])),
+            total,
+            used,
+            free,
+            shared:    get("Shmem"),
+            buffer:    get("Buffers"),
+            cached:    get("Cached"),
+            user:      used.saturating_sub(get("Buffers") + get("Cached")),
+            locked:    0,
+            available: info.get("MemAvailable").cloned(),
+        }
+    }
+}
+
+// the cpu and cpuN lines of /proc/stat
+fn parse_stat(stat: &str) -> CpuCounters {
+    let mut counters = CpuCounters::default();
+    let mut flags = 0;
+    for line in stat.lines() {
+        let mut fields = line.split_whitespace();
+        let name = match fields.next() {
+            Some(n) if n.starts_with("cpu") => n,
+            _                               => continue,
+        };
+        let t: Vec<u64> = fields.take(8).filter_map(|f| f.parse().ok()).collect();
+        if t.len() < 7 {
+            continue // a pre-2.6 kernel, which libgtop doesn't bother with either
+        }
+        let times = CpuTimes {
+            total:   t[..7].iter().sum(),
+            user:    t[0],
+            nice:    t[1],
+            sys:     t[2],
+            idle:    t[3],
+            iowait:  t[4],
+            irq:     t[5],
+            softirq: t[6],
+            steal:   t.get(7).cloned(), // since Linux 2.6.11
+        };
+        if name == "cpu" {
+            counters.aggregate = times;
+            flags |= bits(&[gtop::GLIBTOP_CPU_TOTAL, gtop::GLIBTOP_CPU_USER, gtop::GLIBTOP_CPU_NICE,
+                            gtop::GLIBTOP_CPU_SYS, gtop::GLIBTOP_CPU_IDLE, gtop::GLIBTOP_CPU_FREQUENCY,
+                            gtop::GLIBTOP_CPU_IOWAIT, gtop::GLIBTOP_CPU_IRQ, gtop::GLIBTOP_CPU_SOFTIRQ]);
+        } else if let Ok(cpu) = name[3..].parse() {
+            counters.cores.push(CoreTimes { cpu, times });
+            flags |= bits(&[gtop::GLIBTOP_XCPU_TOTAL, gtop::GLIBTOP_XCPU_USER, gtop::GLIBTOP_XCPU_NICE,
+                            gtop::GLIBTOP_XCPU_SYS, gtop::GLIBTOP_XCPU_IDLE, gtop::GLIBTOP_XCPU_IOWAIT,
+                            gtop::GLIBTOP_XCPU_IRQ, gtop::GLIBTOP_XCPU_SOFTIRQ]);
+        }
+    }
+    counters.cores.sort_by_key(|c| c.cpu);
+    counters.cores.retain(|c| c.times.total != 0); // libgtop treats an all-zero CPU as offline
+    counters.cpu_count = counters.cores.len() as u32;
+    counters.frequency = USER_HZ; // what libgtop reports as the CPU "frequency" on Linux
+    counters.flags = CpuFlags::from_bits(flags);
+    counters
+}
+
+// MemTotal:       16318712 kB
+fn parse_meminfo(info: &str) -> HashMap<String, u64> {
+    info.lines().filter_map(|line| {
+        let mut fields = line.split_whitespace();
+        let key = fields.next()?.trim_end_matches(':');
+        let value: u64 = fields.next()?.parse().ok()?;
+        let scale = if fields.next() == Some("kB") { 1024 } else { 1 }; // HugePages_* are plain counts
+        Some((key.to_string(), value * scale))
+    }).collect()
+}
+
+// /proc/<pid>/mounts escapes spaces, tabs, newlines and backslashes in paths as \040, \011, \012 and \134
+fn unescape(s: &str) -> String {
+    let bytes = s.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let octal = bytes.get(i + 1..i + 4).and_then(|o| u8::from_str_radix(&String::from_utf8_lossy(o), 8).ok());
+        match octal {
+            Some(c) if bytes[i] == b'\\' => { out.push(c); i += 4 },
+            _                            => { out.push(bytes[i]); i += 1 },
+        }
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+// the bitmask with the given GLIBTOP_* bits set
+pub(crate) fn bits(fields: &[u32]) -> u64 {
+    fields.iter().fold(0, |flags, &bit| flags | (1 << bit))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VM: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/vm/proc");
+    const KIB: u64 = 1024;
+
+    #[test]
+    fn cpu_counters_from_fixture() {
+        let cpu = ProcFs::with_root(VM).cpu();
+        assert_eq!(cpu.aggregate.total, 29067 + 13697 + 293614 + 286 + 5); // steal (1535) left out
+        assert_eq!(cpu.aggregate.user, 29067);
+        assert_eq!(cpu.aggregate.sys, 13697);
+        assert_eq!(cpu.aggregate.idle, 293614);
+        assert_eq!(cpu.aggregate.iowait, 286);
+        assert_eq!(cpu.aggregate.steal, Some(1535));
+        assert_eq!(cpu.cores.len(), 1);
+        assert_eq!(cpu.cores[0].cpu, 0);
+        assert_eq!(cpu.cores[0].times, cpu.aggregate);
+        assert_eq!(cpu.frequency, USER_HZ);
+    }
+
+    #[test]
+    fn memory_counters_from_fixture() {
+        let memory = ProcFs::with_root(VM).memory();
+        assert_eq!(memory.total, 6147400 * KIB);
+        assert_eq!(memory.free, 4165392 * KIB);
+        assert_eq!(memory.used, (6147400 - 4165392) * KIB);
+        assert_eq!(memory.user, (6147400 - 4165392 - 60404 - 1567172) * KIB);
+        assert_eq!(memory.shared, 9484 * KIB);
+        assert_eq!(memory.available, Some(5591460 * KIB));
+    }
+
+    #[test]
+    fn missing_root_reads_nothing() {
+        let procfs = ProcFs::with_root(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/missing"));
+        assert_eq!(procfs.cpu().cores.len(), 0);
+        assert_eq!(procfs.memory(), MemoryCounters::default());
+    }
+
+    #[test]
+    fn availability_under_a_root() {
+        assert!(ProcFs::is_available_at(VM));
+        assert!(!ProcFs::is_available_at(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/missing")));
+    }
+}
@@ -0,0 +1,59 @@
+//! Compiled in with the `procfs` feature instead of libgtop.rs: fills the glibtop structs the rest of cpuviz uses
+//! straight from /proc and statvfs(3), with the fields and flags libgtop's Linux code sets. libgtop and glib are not
+//! linked at all.
+
+use std::mem;
+use std::ffi::CStr;
+use libc;
+use super::gtop;
+use super::procfs::{ProcFs, bits};
+use super::backend::{Backend, CpuCounters, MemoryCounters};
+use super::filesystem::Mount;
+
+/// Nothing to set up
+pub(crate) fn init() {}
+
+/// Nothing to tear down
+pub(crate) fn close() {}
+
+pub(crate) fn cpu() -> CpuCounters {
+    ProcFs::new().cpu()
+}
+
+pub(crate) fn memory() -> MemoryCounters {
+    ProcFs::new().memory()
+}
+
+pub(crate) fn get_swap(buf: &mut gtop::glibtop_swap) {
+    ProcFs::new().swap(buf)
+}
+
+pub(crate) fn get_loadavg(buf: &mut gtop::glibtop_loadavg) {
+    ProcFs::new().loadavg(buf)
+}
+
+pub(crate) fn get_uptime(buf: &mut gtop::glibtop_uptime) {
+    ProcFs::new().uptime(buf)
+}
+
+pub(crate) fn mounts() -> Vec<Mount> {
+    ProcFs::new().mounts("self")
+}
+
+/// Fill `buf` from statvfs(3). The read and write counters are left out, as they are by libgtop on most filesystems.
+pub(crate) fn get_fsusage(buf: &mut gtop::glibtop_fsusage, mount_dir: &CStr) {
+    *buf = unsafe { mem::zeroed() };
+    let mut s: libc::statvfs = unsafe { mem::zeroed() };
+    if unsafe { libc::statvfs(mount_dir.as_ptr(), &mut s) } != 0 {
+        return
+    }
+    buf.blocks     = s.f_blocks as u64;
+    buf.bfree      = s.f_bfree as u64;
+    buf.bavail     = s.f_bavail as u64;
+    buf.files      = s.f_files as u64;
+    buf.ffree      = s.f_ffree as u64;
+    buf.block_size = (if s.f_frsize != 0 { s.f_frsize } else { s.f_bsize }) as u32; // the unit f_blocks is counted in
+    buf.flags      = bits(&[gtop::GLIBTOP_FSUSAGE_BLOCKS, gtop::GLIBTOP_FSUSAGE_BFREE, gtop::GLIBTOP_FSUSAGE_BAVAIL,
+                            gtop::GLIBTOP_FSUSAGE_FILES, gtop::GLIBTOP_FSUSAGE_FFREE,
+                            gtop::GLIBTOP_FSUSAGE_BLOCK_SIZE]);
+}
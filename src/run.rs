@@ -0,0 +1,208 @@
+use std::cmp;
+use std::process::{Command, ExitStatus};
+use std::os::unix::process::ExitStatusExt;
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+use cpuviz::{Cpu, Memory, GLibTopHandle, PercentUsage, ProcessWatch, WatchSample};
+
+const USAGE: &str = "usage: cpuviz run [--interval SECONDS] -- COMMAND [ARGS...]";
+const POLL: u64 = 50; // ms between checks for the command exiting
+
+// one row of the timeline
+struct Sample {
+    at:     Duration,    // since the command started
+    sample: WatchSample, // the command's process tree
+    cpu:    f64,         // the whole machine
+    memory: f64,
+}
+
+/// `cpuviz run`: spawn a command, sample its process tree and the machine until the command exits, print a summary
+/// to stderr and return the command's exit code. Descendants left running, e.g. daemons, are not waited for.
+/// `args` are the arguments after `run`.
+pub fn run(args: &[String]) -> i32 {
+    let (interval, command) = match parse_args(args) {
+        Some(a) => a,
+        None    => {
+            eprintln!("{}", USAGE);
+            return 2
+        }
+    };
+
+    let gtop_handle = Arc::new(GLibTopHandle::new());
+    let mut cpu    = Cpu::with_handle(gtop_handle.clone());
+    let mut memory = Memory::with_handle(gtop_handle.clone());
+
+    let start = Instant::now();
+    let mut child = match Command::new(&command[0]).args(&command[1..]).spawn() {
+        Ok(c)  => c,
+        Err(e) => {
+            eprintln!("cpuviz: couldn't run {}: {}", command[0], e);
+            return 127
+        }
+    };
+
+    let mut timeline = Vec::new();
+    if let Ok(mut watch) = ProcessWatch::with_handle(child.id(), true, gtop_handle.clone()) { // fails if it already exited
+        let mut next = start + interval;
+        while !watch.has_exited() {
+            let now = Instant::now();
+            if now >= next {
+                watch.measure();
+                timeline.push(Sample {
+                    at:     now - start,
+                    sample: *watch.sample(),
+                    cpu:    cpu.percent_usage(),
+                    memory: memory.percent_usage(),
+                });
+                next += interval;
+            }
+            thread::sleep(cmp::min(Duration::from_millis(POLL), next.saturating_duration_since(Instant::now())));
+        }
+        watch.measure(); // the command is a zombie until we wait for it, read the tree's final numbers while we still can
+        timeline.push(Sample {
+            at:     start.elapsed(),
+            sample: *watch.sample(),
+            cpu:    cpu.percent_usage(),
+            memory: memory.percent_usage(),
+        });
+    }
+
+    let status = child.wait();
+    let wall = start.elapsed();
+    print_summary(&command, wall, cpu.cpu_count(), &timeline);
+    match status {
+        Ok(s)  => exit_code(s),
+        Err(e) => {
+            eprintln!("cpuviz: couldn't wait for {}: {}", command[0], e);
+            1
+        }
+    }
+}
+
+// (interval, command and its arguments)
+fn parse_args(args: &[String]) -> Option<(Duration, Vec<String>)> {
+    let mut interval = Duration::from_secs(1);
+    let mut i = 0;
+    while i < args.len() {
+        match args[i].as_str() {
+            "--interval" => {
+                let secs: f64 = args.get(i + 1)?.parse().ok()?;
+                if !secs.is_finite() || secs <= 0.0 {
+                    return None
+                }
+                interval = Duration::from_secs_f64(secs);
+                i += 2;
+            }
+            "--"  => { i += 1; break }
+            _     => break,
+        }
+    }
+    let command = args[i..].to_vec();
+    if command.is_empty() {
+        return None
+    }
+    Some((interval, command))
+}
+
+// a shell-style exit code: the command's own, or 128 + the signal that killed it
+fn exit_code(status: ExitStatus) -> i32 {
+    match (status.code(), status.signal()) {
+        (Some(code), _)   => code,
+        (None, Some(sig)) => 128 + sig,
+        (None, None)      => 1,
+    }
+}
+
+fn print_summary(command: &[String], wall: Duration, cpu_count: u32, timeline: &[Sample]) {
+    let n = cpu_count as f64;
+    let last = timeline.last().map(|s| s.sample).unwrap_or_default();
+    let cpu_time = last.user_time + last.system_time;
+    let peak_rss = timeline.iter().map(|s| s.sample.rss).max().unwrap_or(0);
+    let peak_cpu = timeline.iter().map(|s| s.sample.cpu_usage).fold(0.0, f64::max);
+    let avg_cpu = if wall.as_secs_f64() > 0.0 { cpu_time.as_secs_f64() / wall.as_secs_f64() } else { 0.0 };
+
+    // CPU is reported like top and time do: 100% is one CPU fully busy
+    eprintln!();
+    eprintln!("cpuviz: {}", command.join(" "));
+    eprintln!("  wall time:   {:.2}s", wall.as_secs_f64());
+    eprintln!("  user time:   {:.2}s", last.user_time.as_secs_f64());
+    eprintln!("  system time: {:.2}s", last.system_time.as_secs_f64());
+    eprintln!("  average CPU: {:.1}%", avg_cpu * 100.0);
+    eprintln!("  peak CPU:    {:.1}%", peak_cpu * n * 100.0);
+    eprintln!("  peak RSS:    {:.1} MiB", mib(peak_rss));
+    eprintln!("  read:        {:.1} MiB", mib(last.read_bytes));
+    eprintln!("  written:     {:.1} MiB", mib(last.write_bytes));
+    eprintln!();
+    eprintln!("  {:>8} {:>6} {:>8} {:>8} {:>11} {:>11} {:>8} {:>8}",
+        "time", "procs", "CPU", "RSS MiB", "read KiB/s", "write KiB/s", "sys CPU", "sys mem");
+    for s in timeline {
+        eprintln!("  {:>7.1}s {:>6} {:>7.1}% {:>8.1} {:>11.1} {:>11.1} {:>7.1}% {:>7.1}%",
+            s.at.as_secs_f64(),
+            s.sample.processes,
+            s.sample.cpu_usage * n * 100.0,
+            mib(s.sample.rss),
+            s.sample.read_bytes_per_sec / 1024.0,
+            s.sample.write_bytes_per_sec / 1024.0,
+            s.cpu * 100.0,
+            s.memory * 100.0,
+        );
+    }
+}
+
+fn mib(bytes: u64) -> f64 {
+    (bytes as f64) / (1024.0 * 1024.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(a: &[&str]) -> Vec<String> {
+        a.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn interval_and_command() {
+        assert_eq!(parse_args(&args(&["--interval", "0.5", "--", "make", "-j4"])),
+                   Some((Duration::from_millis(500), args(&["make", "-j4"]))));
+        assert_eq!(parse_args(&args(&["--", "make"])), Some((Duration::from_secs(1), args(&["make"]))));
+    }
+
+    #[test]
+    fn the_separator_is_optional() {
+        assert_eq!(parse_args(&args(&["--interval", "2", "sleep", "1"])), Some((Duration::from_secs(2), args(&["sleep", "1"]))));
+        assert_eq!(parse_args(&args(&["sleep", "--interval"])), Some((Duration::from_secs(1), args(&["sleep", "--interval"]))));
+    }
+
+    #[test]
+    fn bad_arguments() {
+        assert_eq!(parse_args(&args(&[])), None);
+        assert_eq!(parse_args(&args(&["--"])), None);                              // no command
+        assert_eq!(parse_args(&args(&["--interval", "--", "sleep"])), None);       // no number
+        assert_eq!(parse_args(&args(&["--interval", "fast", "--", "sleep"])), None);
+        assert_eq!(parse_args(&args(&["--interval", "0", "--", "sleep"])), None);
+        assert_eq!(parse_args(&args(&["--interval", "-1", "--", "sleep"])), None);
+        assert_eq!(parse_args(&args(&["--interval", "NaN", "--", "sleep"])), None);
+        assert_eq!(parse_args(&args(&["--interval"])), None);
+    }
+
+    #[test]
+    fn exit_codes() {
+        // raw wait(2) statuses: the exit code in the second byte, or the signal in the low 7 bits
+        assert_eq!(exit_code(ExitStatus::from_raw(0)), 0);
+        assert_eq!(exit_code(ExitStatus::from_raw(3 << 8)), 3);
+        assert_eq!(exit_code(ExitStatus::from_raw(9)), 128 + 9);   // SIGKILL
+        assert_eq!(exit_code(ExitStatus::from_raw(15)), 128 + 15); // SIGTERM
+        assert_eq!(exit_code(ExitStatus::from_raw(0x137f)), 1);    // stopped by SIGSTOP, neither exited nor killed
+    }
+
+    #[test]
+    fn returns_when_the_command_exits_not_its_daemons() {
+        // the sleep outlives the shell that started it. Its output is redirected so it doesn't hold our pipes open
+        let start = Instant::now();
+        let code = run(&args(&["--interval", "0.1", "--", "sh", "-c", "sleep 10 >/dev/null 2>&1 & sleep 0.3; exit 3"]));
+        assert_eq!(code, 3);
+        assert!(start.elapsed() < Duration::from_secs(5));
+    }
+}
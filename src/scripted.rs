@@ -0,0 +1,130 @@
+use std::collections::VecDeque;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use super::backend::{Backend, CpuCounters, MemoryCounters};
+
+/// A `Backend` that replays counter values queued up front, on a clock that only moves when told to, so code built
+/// on `Cpu` and `Memory` can be tested with exact numbers. Other crates get it with the `testing` feature.
+///
+/// Every read takes the next queued value. Once the queue runs dry the last value is repeated, as if the machine had
+/// stopped. Keep an `Arc` to it to go on queueing values and moving the clock while a `Cpu` or `Memory` reads from it:
+///
+/// ```
+/// # extern crate cpuviz;
+/// # use std::sync::Arc;
+/// # use std::time::Duration;
+/// # use cpuviz::{Cpu, CpuCounters, CpuTimes, CounterRegression, Scripted};
+/// # fn main() -> Result<(), CounterRegression> {
+/// # let times = |busy, idle| CpuCounters::from_cores(&[CpuTimes { total: busy + idle, user: busy, idle, ..CpuTimes::default() }]);
+/// # let (before, after) = (times(0, 0), times(25, 75));
+/// let script = Arc::new(Scripted::new());
+/// script.push_cpu(before);
+/// let mut cpu = Cpu::with_handle(script.clone());
+/// script.push_cpu(after);
+/// script.advance(Duration::from_secs(1));
+/// assert_eq!(cpu.measure()?, (25, 100));
+/// # Ok(())
+/// # }
+/// ```
+pub struct Scripted {
+    script: Mutex<Script>,
+}
+
+struct Script {
+    cpu:         VecDeque<CpuCounters>,
+    memory:      VecDeque<MemoryCounters>,
+    last_cpu:    CpuCounters,    // repeated once `cpu` runs dry
+    last_memory: MemoryCounters,
+    start:       Instant,
+    elapsed:     Duration,       // how far the clock has been moved
+}
+
+impl Scripted {
+    /// A backend with nothing queued, reporting all-zero counters
+    pub fn new() -> Scripted {
+        Scripted {
+            script: Mutex::new(Script {
+                cpu:         VecDeque::new(),
+                memory:      VecDeque::new(),
+                last_cpu:    CpuCounters::default(),
+                last_memory: MemoryCounters::default(),
+                start:       Instant::now(),
+                elapsed:     Duration::from_secs(0),
+            }),
+        }
+    }
+
+    /// Queue CPU counters for a coming read
+    pub fn push_cpu(&self, counters: CpuCounters) {
+        self.script.lock().unwrap().cpu.push_back(counters);
+    }
+
+    /// Queue memory counters for a coming read
+    pub fn push_memory(&self, counters: MemoryCounters) {
+        self.script.lock().unwrap().memory.push_back(counters);
+    }
+
+    /// Move the clock forward
+    pub fn advance(&self, by: Duration) {
+        self.script.lock().unwrap().elapsed += by;
+    }
+
+    /// How far the clock has been moved since the backend was created
+    pub fn elapsed(&self) -> Duration {
+        self.script.lock().unwrap().elapsed
+    }
+}
+
+impl Backend for Scripted {
+    fn cpu(&self) -> CpuCounters {
+        let mut script = self.script.lock().unwrap();
+        if let Some(next) = script.cpu.pop_front() {
+            script.last_cpu = next;
+        }
+        script.last_cpu.clone()
+    }
+
+    fn memory(&self) -> MemoryCounters {
+        let mut script = self.script.lock().unwrap();
+        if let Some(next) = script.memory.pop_front() {
+            script.last_memory = next;
+        }
+        script.last_memory
+    }
+
+    fn now(&self) -> Instant {
+        let script = self.script.lock().unwrap();
+        script.start + script.elapsed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::cpu_breakdown::CpuTimes;
+
+    fn cpu(total: u64) -> CpuCounters {
+        CpuCounters::from_cores(&[CpuTimes { total, idle: total, ..CpuTimes::default() }])
+    }
+
+    #[test]
+    fn replays_in_order_then_repeats_the_last_value() {
+        let script = Scripted::new();
+        assert_eq!(script.cpu(), CpuCounters::default());
+        script.push_cpu(cpu(1));
+        script.push_cpu(cpu(2));
+        assert_eq!(script.cpu(), cpu(1));
+        assert_eq!(script.cpu(), cpu(2));
+        assert_eq!(script.cpu(), cpu(2));
+    }
+
+    #[test]
+    fn clock_only_moves_when_advanced() {
+        let script = Scripted::new();
+        let start = script.now();
+        assert_eq!(script.now(), start);
+        script.advance(Duration::from_millis(1500));
+        assert_eq!(script.now() - start, Duration::from_millis(1500));
+        assert_eq!(script.elapsed(), Duration::from_millis(1500));
+    }
+}
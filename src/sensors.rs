@@ -0,0 +1,266 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use super::util::{SYSFS, read_string, read_number};
+
+/// One temperature sensor, in degrees Celsius
+#[derive(Clone, Debug, PartialEq)]
+pub struct Temperature {
+    pub source:   String,      // the hwmon chip (e.g. coretemp) or thermal zone type (e.g. x86_pkg_temp)
+    pub label:    String,      // e.g. "Core 0", or "temp1" if the driver doesn't label it
+    pub celsius:  f64,
+    pub max:      Option<f64>, // hwmon's max, or the thermal zone's passive trip point, where the kernel starts throttling
+    pub critical: Option<f64>, // past this the machine shuts down
+}
+
+impl Temperature {
+    /// Is the sensor at or above its max? On a thermal zone this means the CPU is being throttled.
+    pub fn is_over_max(&self) -> bool {
+        self.max.map(|m| self.celsius >= m).unwrap_or(false)
+    }
+
+    pub fn is_critical(&self) -> bool {
+        self.critical.map(|c| self.celsius >= c).unwrap_or(false)
+    }
+}
+
+/// One fan, in revolutions per minute
+#[derive(Clone, Debug, PartialEq)]
+pub struct Fan {
+    pub source: String,
+    pub label:  String,
+    pub rpm:    u64,
+    pub min:    Option<u64>,
+    pub max:    Option<u64>,
+}
+
+/// The temperatures and fan speeds exposed by the kernel under /sys/class/hwmon and /sys/class/thermal.
+/// The sysfs root can be moved, to read a fixture tree or the host's /sys from inside a container.
+pub struct Sensors {
+    root:         PathBuf,
+    temperatures: Vec<Temperature>,
+    fans:         Vec<Fan>,
+}
+
+impl Sensors {
+    /// Does this machine expose any hwmon or thermal zone entries?
+    pub fn is_available() -> bool {
+        Sensors::is_available_at(SYSFS)
+    }
+
+    /// Does the /sys at `root` expose any, e.g. the host's at `/host/sys`?
+    pub fn is_available_at<P: AsRef<Path>>(root: P) -> bool {
+        let root = root.as_ref();
+        !hwmon_dirs(root).is_empty() || !thermal_zone_dirs(root).is_empty()
+    }
+
+    /// Read the sensors under /sys
+    pub fn new() -> Sensors {
+        Sensors::with_root(SYSFS)
+    }
+
+    /// Read the sensors under `root` instead of /sys, e.g. `/host/sys`
+    pub fn with_root<P: Into<PathBuf>>(root: P) -> Sensors {
+        let mut s = Sensors {
+            root:         root.into(),
+            temperatures: Vec::new(),
+            fans:         Vec::new(),
+        };
+        s.measure();
+        return s
+    }
+
+    /// Re-read every sensor. Sensors that can't be read are skipped.
+    pub fn measure(&mut self) {
+        self.temperatures.clear();
+        self.fans.clear();
+        for dir in hwmon_dirs(&self.root) {
+            self.read_hwmon(&dir);
+        }
+        for dir in thermal_zone_dirs(&self.root) {
+            self.read_thermal_zone(&dir);
+        }
+    }
+
+    /// Every temperature sensor, hwmon chips first, then thermal zones
+    pub fn temperatures(&self) -> &[Temperature] {
+        &self.temperatures
+    }
+
+    /// Every fan that reports its speed
+    pub fn fans(&self) -> &[Fan] {
+        &self.fans
+    }
+
+    /// The hottest sensor
+    pub fn hottest(&self) -> Option<&Temperature> {
+        self.temperatures.iter().fold(None, |hottest: Option<&Temperature>, t| match hottest {
+            Some(h) if h.celsius >= t.celsius => Some(h),
+            _                                 => Some(t),
+        })
+    }
+
+    // /sys/class/hwmon/hwmonN/{name, tempN_input, tempN_label, tempN_max, tempN_crit, fanN_input, ...}
+    fn read_hwmon(&mut self, dir: &Path) {
+        let source = read_string(&dir.join("name"))
+            .or_else(|| read_string(&dir.parent()?.join("name"))) // sensor files in device/, name one level up
+            .unwrap_or_else(|| file_name(dir));
+        for i in indices(dir, "temp", "_input") {
+            let input = |suffix: &str| read_number(&dir.join(format!("temp{}_{}", i, suffix))).map(millidegrees);
+            if let Some(celsius) = input("input") {
+                self.temperatures.push(Temperature {
+                    source:   source.clone(),
+                    label:    read_string(&dir.join(format!("temp{}_label", i))).unwrap_or_else(|| format!("temp{}", i)),
+                    celsius,
+                    max:      input("max"),
+                    critical: input("crit"),
+                });
+            }
+        }
+        for i in indices(dir, "fan", "_input") {
+            let input = |suffix: &str| read_number(&dir.join(format!("fan{}_{}", i, suffix))).map(|r| r.max(0) as u64);
+            if let Some(rpm) = input("input") {
+                self.fans.push(Fan {
+                    source: source.clone(),
+                    label:  read_string(&dir.join(format!("fan{}_label", i))).unwrap_or_else(|| format!("fan{}", i)),
+                    rpm,
+                    min:    input("min"),
+                    max:    input("max"),
+                });
+            }
+        }
+    }
+
+    // /sys/class/thermal/thermal_zoneN/{type, temp, trip_point_K_type, trip_point_K_temp}
+    fn read_thermal_zone(&mut self, dir: &Path) {
+        let celsius = match read_number(&dir.join("temp")) {
+            Some(t) => millidegrees(t),
+            None    => return, // disabled zones fail to read
+        };
+        let (mut passive, mut hot, mut critical) = (None, None, None);
+        for k in indices(dir, "trip_point_", "_temp") {
+            let temp = read_number(&dir.join(format!("trip_point_{}_temp", k))).map(millidegrees);
+            match read_string(&dir.join(format!("trip_point_{}_type", k))).as_deref() {
+                Some("passive")  => passive = min(passive, temp),
+                Some("hot")      => hot = min(hot, temp),
+                Some("critical") => critical = min(critical, temp),
+                _                => (),
+            }
+        }
+        self.temperatures.push(Temperature {
+            source:   read_string(&dir.join("type")).unwrap_or_else(|| "thermal".to_string()),
+            label:    file_name(dir),
+            celsius,
+            max:      passive.or(hot),
+            critical,
+        });
+    }
+}
+
+// /sys/class/hwmon/hwmon*, and their device/ subdirectory where older drivers put the sensor files
+fn hwmon_dirs(root: &Path) -> Vec<PathBuf> {
+    let mut dirs = Vec::new();
+    for dir in sorted_entries(&root.join("class/hwmon"), "hwmon") {
+        if !indices(&dir.join("device"), "temp", "_input").is_empty() || !indices(&dir.join("device"), "fan", "_input").is_empty() {
+            dirs.push(dir.join("device"));
+        }
+        dirs.push(dir);
+    }
+    dirs
+}
+
+fn thermal_zone_dirs(root: &Path) -> Vec<PathBuf> {
+    sorted_entries(&root.join("class/thermal"), "thermal_zone")
+}
+
+// the entries of `dir` named `prefix` followed by a number, sorted by that number
+fn sorted_entries(dir: &Path, prefix: &str) -> Vec<PathBuf> {
+    let mut entries: Vec<(u32, PathBuf)> = match fs::read_dir(dir) {
+        Ok(e)  => e.filter_map(|e| {
+            let path = e.ok()?.path();
+            let name = file_name(&path);
+            if !name.starts_with(prefix) {
+                return None
+            }
+            Some((name[prefix.len()..].parse().ok()?, path))
+        }).collect(),
+        Err(_) => Vec::new(),
+    };
+    entries.sort();
+    entries.into_iter().map(|(_, p)| p).collect()
+}
+
+// the N of every `{prefix}N{suffix}` file in `dir`, sorted
+fn indices(dir: &Path, prefix: &str, suffix: &str) -> Vec<u32> {
+    let mut indices: Vec<u32> = match fs::read_dir(dir) {
+        Ok(e)  => e.filter_map(|e| {
+            let name = e.ok()?.file_name().into_string().ok()?;
+            if !name.starts_with(prefix) || !name.ends_with(suffix) {
+                return None
+            }
+            name.get(prefix.len()..name.len() - suffix.len())?.parse().ok()
+        }).collect(),
+        Err(_) => Vec::new(),
+    };
+    indices.sort();
+    indices
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name().map(|n| n.to_string_lossy().into_owned()).unwrap_or_default()
+}
+
+fn millidegrees(t: i64) -> f64 {
+    (t as f64) / 1000.0
+}
+
+fn min(a: Option<f64>, b: Option<f64>) -> Option<f64> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(a.min(b)),
+        (a, b)             => a.or(b),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LAPTOP: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/laptop/sys");
+
+    fn labels(sensors: &Sensors) -> Vec<(&str, &str, f64)> {
+        sensors.temperatures().iter().map(|t| (t.source.as_str(), t.label.as_str(), t.celsius)).collect()
+    }
+
+    #[test]
+    fn hwmon_and_thermal_zones_from_fixture() {
+        let sensors = Sensors::with_root(LAPTOP);
+        assert_eq!(labels(&sensors), vec![
+            ("coretemp",     "Package id 0",  52.0),
+            ("coretemp",     "Core 0",        49.0),
+            ("thinkpad",     "temp1",         47.0), // in hwmon1/device/, named one level up
+            ("acpitz",       "thermal_zone0", 45.0),
+            ("x86_pkg_temp", "thermal_zone1", 52.0), // thermal_zone2 has no temp to read
+        ]);
+        assert_eq!(sensors.temperatures()[0].max, Some(100.0));
+        assert_eq!(sensors.temperatures()[3].critical, Some(99.0));
+        assert_eq!(sensors.temperatures()[4].max, Some(90.0)); // the lowest passive trip point
+        assert_eq!(sensors.hottest().map(|t| t.label.as_str()), Some("Package id 0"));
+    }
+
+    #[test]
+    fn fans_from_fixture() {
+        let sensors = Sensors::with_root(LAPTOP);
+        assert_eq!(sensors.fans(), &[Fan {
+            source: "thinkpad".to_string(),
+            label:  "fan1".to_string(),
+            rpm:    2650,
+            min:    None,
+            max:    None,
+        }]);
+    }
+
+    #[test]
+    fn availability_under_a_root() {
+        assert!(Sensors::is_available_at(LAPTOP));
+        assert!(!Sensors::is_available_at(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/vm/proc")));
+    }
+}
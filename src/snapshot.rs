@@ -0,0 +1,219 @@
+use std::time::{Instant, Duration};
+use super::backend::{CpuCounters, MemoryCounters};
+use super::cpu::CounterRegression;
+use super::cpu_breakdown::{CpuTimes, CpuBreakdown};
+use super::flags::{MemoryFlags, MemoryField};
+use super::memory_stats::MemoryStats;
+
+/// The counters of one logical CPU
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CoreTimes {
+    pub cpu:   u32, // the CPU number, as in /sys/devices/system/cpu/cpuN
+    pub times: CpuTimes,
+}
+
+/// The CPU counters at a single point in time, produced by `Cpu::snapshot()`.
+/// Compare two snapshots with `CpuSnapshot::delta()` to get the usage over any window.
+///
+/// Only the CPUs that are actually present are stored, so a snapshot costs a few dozen bytes per CPU
+/// rather than the 64 KB of a glibtop_cpu struct and can be kept around in bulk, e.g. in a `CpuHistory`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CpuSnapshot {
+    taken:     Instant,
+    aggregate: CpuTimes,
+    cores:     Vec<CoreTimes>, // sorted by CPU number
+}
+
+impl CpuSnapshot {
+    /// Copy the counters out of what a `Backend` supplied
+    pub(crate) fn from_counters(taken: Instant, c: &CpuCounters) -> CpuSnapshot {
+        CpuSnapshot {
+            taken,
+            aggregate: c.aggregate,
+            cores:     c.cores.clone(),
+        }
+    }
+
+    /// When the snapshot was taken
+    pub fn taken(&self) -> Instant {
+        self.taken
+    }
+
+    /// The counters summed over all CPUs
+    pub fn aggregate(&self) -> &CpuTimes {
+        &self.aggregate
+    }
+
+    /// The counters of each online logical CPU, sorted by CPU number.
+    /// Empty if the backend does not report per-CPU counters on this platform.
+    pub fn cores(&self) -> &[CoreTimes] {
+        &self.cores
+    }
+
+    /// The counters of logical CPU `cpu`, if it was online
+    pub fn core(&self, cpu: u32) -> Option<&CpuTimes> {
+        self.cores.binary_search_by_key(&cpu, |c| c.cpu).ok().map(|i| &self.cores[i].times)
+    }
+
+    /// The CPU usage between `earlier` and `self`. Fails if the counters went backwards in between.
+    pub fn delta(&self, earlier: &CpuSnapshot) -> Result<CpuDelta, CounterRegression> {
+        let breakdown = match self.aggregate.breakdown_since(&earlier.aggregate) {
+            Some(b) => b,
+            None    => return Err(CounterRegression),
+        };
+        let n_slots = self.cores.last().map(|c| c.cpu as usize + 1).unwrap_or(0);
+        let mut per_core = vec![CpuBreakdown::default(); n_slots]; // offline CPUs stay zeroed
+        for now in &self.cores {
+            if let Some(last) = earlier.core(now.cpu) { // a CPU that just came online has nothing to compare against
+                // a single CPU going backwards only zeroes that CPU
+                per_core[now.cpu as usize] = now.times.breakdown_since(last).unwrap_or_default();
+            }
+        }
+
+        Ok(CpuDelta {
+            elapsed:   self.taken.checked_duration_since(earlier.taken).unwrap_or_default(),
+            busy:      self.aggregate.busy().saturating_sub(earlier.aggregate.busy()),
+            total:     self.aggregate.total - earlier.aggregate.total,
+            breakdown,
+            per_core,
+        })
+    }
+}
+
+/// The CPU usage between two snapshots
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CpuDelta {
+    elapsed:   Duration,
+    busy:      u64,
+    total:     u64,
+    breakdown: CpuBreakdown,
+    per_core:  Vec<CpuBreakdown>,
+}
+
+impl CpuDelta {
+    /// Wall-clock time between the two snapshots
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Busy jiffies, summed over all CPUs
+    pub fn busy(&self) -> u64 {
+        self.busy
+    }
+
+    /// Elapsed jiffies, summed over all CPUs
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+
+    /// The fraction of time (0.0 to 1.0) the CPUs were busy. 0.0 if no time passed.
+    pub fn usage(&self) -> f64 {
+        if self.total == 0 {
+            return 0.0
+        }
+        (self.busy as f64) / (self.total as f64)
+    }
+
+    /// The fraction of time spent in each state, over all CPUs
+    pub fn breakdown(&self) -> CpuBreakdown {
+        self.breakdown
+    }
+
+    /// The fraction of time spent in each state, for each logical CPU. Index `i` holds CPU `i`,
+    /// CPUs that were offline at either end of the interval are all zeroes.
+    pub fn per_core(&self) -> &[CpuBreakdown] {
+        &self.per_core
+    }
+}
+
+/// The memory statistics at a single point in time, produced by `Memory::snapshot()`.
+#[derive(Clone, Copy, Debug)]
+pub struct MemorySnapshot {
+    taken:    Instant,
+    counters: MemoryCounters,
+}
+
+impl MemorySnapshot {
+    pub(crate) fn from_counters(taken: Instant, counters: MemoryCounters) -> MemorySnapshot {
+        MemorySnapshot {
+            taken,
+            counters,
+        }
+    }
+
+    /// When the snapshot was taken
+    pub fn taken(&self) -> Instant {
+        self.taken
+    }
+
+    /// Which fields the backend filled in on this platform
+    pub fn flags(&self) -> MemoryFlags {
+        self.counters.flags
+    }
+
+    /// Total memory, in bytes
+    pub fn total(&self) -> Option<u64> {
+        self.flags().get(MemoryField::Total, self.counters.total)
+    }
+
+    /// Memory used by programs, in bytes. Does not count buffers and the page cache.
+    pub fn user(&self) -> Option<u64> {
+        self.flags().get(MemoryField::User, self.counters.user)
+    }
+
+    /// Used, free, cached... memory at the time of the snapshot
+    pub fn stats(&self) -> MemoryStats {
+        MemoryStats::from_counters(&self.counters)
+    }
+
+    /// The fraction of memory (0.0 to 1.0) in use, the same number `Memory::percent_usage()` reports
+    pub fn usage(&self) -> f64 {
+        self.stats().used_fraction()
+    }
+
+    /// The change in memory use between `earlier` and `self`
+    pub fn delta(&self, earlier: &MemorySnapshot) -> MemoryDelta {
+        MemoryDelta {
+            elapsed:      self.taken.checked_duration_since(earlier.taken).unwrap_or_default(),
+            usage_before: earlier.usage(),
+            usage_after:  self.usage(),
+            used_change:  (self.stats().used as i64) - (earlier.stats().used as i64),
+        }
+    }
+}
+
+/// The change in memory use between two snapshots
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct MemoryDelta {
+    elapsed:      Duration,
+    usage_before: f64,
+    usage_after:  f64,
+    used_change:  i64,
+}
+
+impl MemoryDelta {
+    /// Wall-clock time between the two snapshots
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Memory usage (0.0 to 1.0) at the earlier snapshot
+    pub fn usage_before(&self) -> f64 {
+        self.usage_before
+    }
+
+    /// Memory usage (0.0 to 1.0) at the later snapshot
+    pub fn usage_after(&self) -> f64 {
+        self.usage_after
+    }
+
+    /// Average memory usage over the interval, estimated from its two end points
+    pub fn average_usage(&self) -> f64 {
+        (self.usage_before + self.usage_after) / 2.0
+    }
+
+    /// How many bytes more (or fewer, if negative) were in use at the later snapshot
+    pub fn used_change(&self) -> i64 {
+        self.used_change
+    }
+}
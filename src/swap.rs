@@ -0,0 +1,167 @@
+use std::sync::Arc;
+use std::path::PathBuf;
+use std::mem;
+use std::time::Instant;
+use super::gtop;
+use super::sysdeps;
+use super::GLibTopHandle;
+use super::procfs::ProcFs;
+use super::percent_usage::PercentUsage;
+use super::flags::{SwapFlags, SwapField};
+
+/// A handle to a `glibtop_swap` struct, the page-in/page-out rates over the last measurement, an
+/// `Option<Arc<GLibTopHandle>>` to determine when `glibtop_init()` and `glibtop_close()` should be called and
+/// the /proc to read instead of asking libgtop, if any
+pub struct Swap {
+    gtop_swap: gtop::glibtop_swap,
+    last_time: Instant,
+    pagein_rate: f64,
+    pageout_rate: f64,
+    handle: Option<Arc<GLibTopHandle>>,
+    procfs: Option<ProcFs>,
+}
+
+impl Swap {
+    /// Always returns true, libgtop supports swap on every platform. A machine without any swap space reports a total of 0.
+    pub fn is_available() -> bool {
+        true
+    }
+
+    /// Instantate a new Swap struct, call `glibtop_init()`. `glibtop_close()` will be called when this
+    /// object is dropped
+    pub fn new() -> Swap {
+        sysdeps::init();
+        Swap::init(None, None)
+    }
+
+    /// Instantate a new Swap struct, does not call `glibtop_init()`. `glibtop_close()` will be called when all
+    /// references to this handle are dropped.
+    pub fn with_handle(h: Arc<GLibTopHandle>) -> Swap {
+        Swap::init(Some(h), None)
+    }
+
+    /// Instantate a new Swap struct reading `root`/meminfo and `root`/vmstat instead of asking libgtop, e.g. the
+    /// host's /proc mounted at `/host/proc` inside a container. Does not call `glibtop_init()`.
+    pub fn with_root<P: Into<PathBuf>>(root: P) -> Swap {
+        Swap::init(None, Some(ProcFs::with_root(root)))
+    }
+
+    fn init(handle: Option<Arc<GLibTopHandle>>, procfs: Option<ProcFs>) -> Swap {
+        let mut s = Swap {
+            handle,
+            procfs,
+            gtop_swap: unsafe { mem::zeroed() },
+            last_time: Instant::now(),
+            pagein_rate: 0.0,
+            pageout_rate: 0.0,
+        };
+        s.read(); // the page counters are compared against the previous values
+        s.measure();
+        return s
+    }
+
+    /// Update the values from glibtop and the page-in/page-out rates since the last measurement
+    pub fn measure(&mut self) {
+        let now = Instant::now();
+        let (last_in, last_out) = (self.gtop_swap.pagein, self.gtop_swap.pageout);
+        self.read();
+        let elapsed = now.duration_since(self.last_time).as_secs_f64();
+        self.last_time = now;
+
+        // counters going backwards (a reset, suspend/resume) make the interval meaningless, report no paging
+        self.pagein_rate  = rate(self.gtop_swap.pagein.checked_sub(last_in), elapsed);
+        self.pageout_rate = rate(self.gtop_swap.pageout.checked_sub(last_out), elapsed);
+    }
+
+    fn read(&mut self) {
+        match self.procfs {
+            Some(ref procfs) => procfs.swap(&mut self.gtop_swap),
+            None             => sysdeps::get_swap(&mut self.gtop_swap),
+        }
+    }
+
+    /// Pages swapped in per second, between the last two calls to `Swap::measure()`
+    pub fn pagein_rate(&self) -> f64 {
+        self.pagein_rate
+    }
+
+    /// Pages swapped out per second, between the last two calls to `Swap::measure()`
+    pub fn pageout_rate(&self) -> f64 {
+        self.pageout_rate
+    }
+
+    /// Which fields of the glibtop_swap struct libgtop filled in on this platform.
+    pub fn flags(&self) -> SwapFlags {
+        SwapFlags::from_bits(self.gtop_swap.flags)
+    }
+
+    /// Expose fields from the glibtop_swap struct, or `None` if libgtop does not provide them on this platform.
+    /// Sizes are in bytes, `pagein` and `pageout` are page counts since boot. Not updated unless `Swap::measure()` is called
+    pub fn total(&self) -> Option<u64> {
+        self.flags().get(SwapField::Total, self.gtop_swap.total)
+    }
+    pub fn used(&self) -> Option<u64> {
+        self.flags().get(SwapField::Used, self.gtop_swap.used)
+    }
+    pub fn free(&self) -> Option<u64> {
+        self.flags().get(SwapField::Free, self.gtop_swap.free)
+    }
+    pub fn pagein(&self) -> Option<u64> {
+        self.flags().get(SwapField::Pagein, self.gtop_swap.pagein)
+    }
+    pub fn pageout(&self) -> Option<u64> {
+        self.flags().get(SwapField::Pageout, self.gtop_swap.pageout)
+    }
+}
+
+impl PercentUsage for Swap {
+    fn percent_usage(&mut self) -> f64 {
+        self.measure();
+        let s = self.gtop_swap;
+        if s.total == 0 {
+            return 0.0 // no swap space
+        }
+        (s.used as f64) / (s.total as f64)
+    }
+}
+
+impl Drop for Swap {
+    fn drop(&mut self) {
+        if self.handle.is_none() && self.procfs.is_none() { // manual glibtop init and close
+            sysdeps::close()
+        }
+    }
+}
+
+// events per second, 0 if the counter went backwards or no time passed
+fn rate(diff: Option<u64>, seconds: f64) -> f64 {
+    match diff {
+        Some(d) if seconds > 0.0 => (d as f64) / seconds,
+        _                        => 0.0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VM: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/vm/proc");
+
+    #[test]
+    fn swap_from_fixture() {
+        let mut swap = Swap::with_root(VM); // a VM without swap space
+        assert_eq!(swap.total(), Some(0));
+        assert_eq!(swap.used(), Some(0));
+        assert_eq!(swap.pagein(), Some(0));
+        assert_eq!(swap.pageout(), Some(0));
+        assert_eq!(swap.pagein_rate(), 0.0);
+        assert_eq!(swap.percent_usage(), 0.0);
+    }
+
+    #[test]
+    fn missing_root_reads_nothing() {
+        let swap = Swap::with_root(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/missing"));
+        assert_eq!(swap.total(), None);
+        assert_eq!(swap.pagein(), None);
+    }
+}
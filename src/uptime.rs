@@ -0,0 +1,112 @@
+use std::sync::Arc;
+use std::path::PathBuf;
+use std::mem;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use super::gtop;
+use super::sysdeps;
+use super::GLibTopHandle;
+use super::procfs::ProcFs;
+use super::flags::{UptimeFlags, UptimeField};
+
+/// A handle to a `glibtop_uptime` struct, an `Option<Arc<GLibTopHandle>>` to determine when `glibtop_init()`
+/// and `glibtop_close()` should be called and the /proc to read instead of asking libgtop, if any
+pub struct Uptime {
+    gtop_uptime: gtop::glibtop_uptime,
+    handle: Option<Arc<GLibTopHandle>>,
+    procfs: Option<ProcFs>,
+}
+
+impl Uptime {
+    /// Always returns true, every platform libgtop supports has an uptime
+    pub fn is_available() -> bool {
+        true
+    }
+
+    /// Instantate a new Uptime struct, call `glibtop_init()`. `glibtop_close()` will be called when this
+    /// object is dropped
+    pub fn new() -> Uptime {
+        sysdeps::init();
+        Uptime::init(None, None)
+    }
+
+    /// Instantate a new Uptime struct, does not call `glibtop_init()`. `glibtop_close()` will be called when all
+    /// references to this handle are dropped.
+    pub fn with_handle(h: Arc<GLibTopHandle>) -> Uptime {
+        Uptime::init(Some(h), None)
+    }
+
+    /// Instantate a new Uptime struct reading `root`/uptime and `root`/stat instead of asking libgtop, e.g. the
+    /// host's /proc mounted at `/host/proc` inside a container. Does not call `glibtop_init()`.
+    pub fn with_root<P: Into<PathBuf>>(root: P) -> Uptime {
+        Uptime::init(None, Some(ProcFs::with_root(root)))
+    }
+
+    fn init(handle: Option<Arc<GLibTopHandle>>, procfs: Option<ProcFs>) -> Uptime {
+        let mut u = Uptime {
+            handle,
+            procfs,
+            gtop_uptime: unsafe { mem::zeroed() },
+        };
+        u.measure();
+        return u
+    }
+
+    /// Update the values from glibtop, or the /proc given to `Uptime::with_root()`
+    pub fn measure(&mut self) {
+        match self.procfs {
+            Some(ref procfs) => procfs.uptime(&mut self.gtop_uptime),
+            None             => sysdeps::get_uptime(&mut self.gtop_uptime),
+        }
+    }
+
+    /// Which fields of the glibtop_uptime struct libgtop filled in on this platform.
+    pub fn flags(&self) -> UptimeFlags {
+        UptimeFlags::from_bits(self.gtop_uptime.flags)
+    }
+
+    /// Time since boot. Not updated unless `Uptime::measure()` is called
+    pub fn uptime(&self) -> Option<Duration> {
+        self.flags().get(UptimeField::Uptime, self.gtop_uptime.uptime).map(Duration::from_secs_f64)
+    }
+
+    /// Time all CPUs spent idle since boot. On Linux this is summed over all CPUs, so it can exceed the uptime.
+    /// Not updated unless `Uptime::measure()` is called
+    pub fn idle_time(&self) -> Option<Duration> {
+        self.flags().get(UptimeField::IdleTime, self.gtop_uptime.idletime).map(Duration::from_secs_f64)
+    }
+
+    /// When the machine booted. Not updated unless `Uptime::measure()` is called
+    pub fn boot_time(&self) -> Option<SystemTime> {
+        self.flags().get(UptimeField::BootTime, self.gtop_uptime.boot_time).map(|t| UNIX_EPOCH + Duration::from_secs(t))
+    }
+}
+
+impl Drop for Uptime {
+    fn drop(&mut self) {
+        if self.handle.is_none() && self.procfs.is_none() { // manual glibtop init and close
+            sysdeps::close()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VM: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/vm/proc");
+
+    #[test]
+    fn uptime_from_fixture() {
+        let uptime = Uptime::with_root(VM);
+        assert_eq!(uptime.uptime(), Some(Duration::from_secs_f64(3373.93)));
+        assert_eq!(uptime.idle_time(), Some(Duration::from_secs_f64(2936.14)));
+        assert_eq!(uptime.boot_time(), Some(UNIX_EPOCH + Duration::from_secs(1792312569)));
+    }
+
+    #[test]
+    fn missing_root_reads_nothing() {
+        let uptime = Uptime::with_root(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/missing"));
+        assert_eq!(uptime.uptime(), None);
+        assert_eq!(uptime.boot_time(), None);
+    }
+}
@@ -1,3 +1,10 @@
+use std::fs;
+use std::path::Path;
+
+pub(crate) const PROC: &str = "/proc";
+pub(crate) const SYSFS: &str = "/sys";
+pub(crate) const USER_HZ: u64 = 100; // the unit of the jiffy counters in /proc, on every Linux architecture
+
 #[allow(dead_code)]
 /// Round to a given number of decimal places
 /// Example: round_decimals(7.125, 2) -> 7.13
@@ -6,3 +13,15 @@ pub fn round_decimals(x: f64, n: u32) -> f64 {
     let mul  = base.pow(n) as f64;
     (x * mul).round() / mul
 }
+
+/// A sysfs attribute, trimmed. `None` if it can't be read or is empty.
+pub(crate) fn read_string(path: &Path) -> Option<String> {
+    let s = fs::read_to_string(path).ok()?;
+    let s = s.trim();
+    if s.is_empty() { None } else { Some(s.to_string()) }
+}
+
+/// A sysfs attribute holding a single integer
+pub(crate) fn read_number(path: &Path) -> Option<i64> {
+    read_string(path)?.parse().ok()
+}
@@ -4,3 +4,8 @@
 #include <glibtop.h>
 #include <glibtop/cpu.h>
 #include <glibtop/mem.h>
+#include <glibtop/swap.h>
+#include <glibtop/uptime.h>
+#include <glibtop/loadavg.h>
+#include <glibtop/mountlist.h>
+#include <glibtop/fsusage.h>